@@ -7,9 +7,33 @@ pub use crate::occur::Occur;
 use crate::query_grammar::parse_to_ast;
 pub use crate::user_input_ast::{UserInputAST, UserInputBound, UserInputLeaf, UserInputLiteral};
 
-pub struct Error;
+/// Error returned when a query cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Error {
+    /// Byte offset within the query at which the parser stopped.
+    pub offset: usize,
+}
 
 pub fn parse_query(query: &str) -> Result<UserInputAST, Error> {
-    let (user_input_ast, _remaining) = parse_to_ast().parse(query).map_err(|_| Error)?;
+    let mut remaining = query;
+    let (user_input_ast, _) = parse_to_ast()
+        .parse_stream(&mut remaining)
+        .into_result()
+        .map_err(|_| Error {
+            offset: query.len() - remaining.len(),
+        })?;
     Ok(user_input_ast)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_query, Error};
+
+    #[test]
+    fn test_parse_query_error_offset() {
+        assert!(parse_query("title:abc").is_ok());
+        assert_eq!(parse_query("title:").unwrap_err(), Error { offset: 6 });
+        assert_eq!(parse_query("a b (").unwrap_err(), Error { offset: 5 });
+        assert_eq!(parse_query("a:b:c").unwrap_err(), Error { offset: 4 });
+    }
+}
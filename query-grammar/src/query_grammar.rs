@@ -10,7 +10,9 @@ use combine::{
 fn field<'a>() -> impl Parser<&'a str, Output = String> {
     (
         letter(),
-        many(satisfy(|c: char| c.is_alphanumeric() || c == '_')),
+        many(satisfy(|c: char| {
+            c.is_alphanumeric() || c == '_' || c == '.'
+        })),
     )
         .skip(char(':'))
         .map(|(s1, s2): (char, String)| format!("{}{}", s1, s2))
@@ -451,6 +453,12 @@ mod test {
         test_parse_query_to_ast_helper("abc", "\"abc\"");
     }
 
+    #[test]
+    fn test_parse_query_dotted_field() {
+        test_parse_query_to_ast_helper("attributes.color:red", "attributes.color:\"red\"");
+        test_parse_query_to_ast_helper("a.b.c:1.5", "a.b.c:\"1.5\"");
+    }
+
     #[test]
     fn test_parse_query_default_clause() {
         test_parse_query_to_ast_helper("a b", "(*\"a\" *\"b\")");
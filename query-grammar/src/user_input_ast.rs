@@ -4,6 +4,7 @@ use std::fmt::{Debug, Formatter};
 use crate::Occur;
 
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UserInputLeaf {
     Literal(UserInputLiteral),
     All,
@@ -37,6 +38,7 @@ impl Debug for UserInputLeaf {
 }
 
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserInputLiteral {
     pub field_name: Option<String>,
     pub phrase: String,
@@ -52,6 +54,7 @@ impl fmt::Debug for UserInputLiteral {
 }
 
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UserInputBound {
     Inclusive(String),
     Exclusive(String),
@@ -84,6 +87,7 @@ impl UserInputBound {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UserInputAST {
     Clause(Vec<(Option<Occur>, UserInputAST)>),
     Leaf(Box<UserInputLeaf>),
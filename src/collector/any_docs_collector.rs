@@ -0,0 +1,158 @@
+use super::Collector;
+use crate::collector::SegmentCollector;
+use crate::DocAddress;
+use crate::DocId;
+use crate::Score;
+use crate::SegmentLocalId;
+use crate::SegmentReader;
+
+/// `AnyDocs` collects up to `limit` documents matching the query,
+/// without scoring nor sorting them.
+///
+/// The search of a segment stops as soon as `limit` documents were found,
+/// which makes it suitable for checking whether a query matches anything, or for
+/// displaying a few examples of matching documents.
+///
+/// The documents are returned in the order of their `DocAddress`, but which
+/// of the matching documents are returned is not specified.
+///
+/// ```rust
+/// use tantivy::collector::AnyDocs;
+/// use tantivy::query::QueryParser;
+/// use tantivy::schema::{Schema, TEXT};
+/// use tantivy::{doc, Index};
+///
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let schema = schema_builder.build();
+/// let index = Index::create_in_ram(schema);
+///
+/// let mut index_writer = index.writer(3_000_000).unwrap();
+/// index_writer.add_document(doc!(title => "The Name of the Wind"));
+/// index_writer.add_document(doc!(title => "The Diary of Muadib"));
+/// index_writer.add_document(doc!(title => "A Dairy Cow"));
+/// index_writer.add_document(doc!(title => "The Diary of a Young Girl"));
+/// assert!(index_writer.commit().is_ok());
+///
+/// let reader = index.reader().unwrap();
+/// let searcher = reader.searcher();
+///
+/// let query_parser = QueryParser::for_index(&index, vec![title]);
+/// let query = query_parser.parse_query("diary").unwrap();
+/// let doc_addresses = searcher.search(&query, &AnyDocs::with_limit(1)).unwrap();
+///
+/// assert_eq!(doc_addresses.len(), 1);
+/// ```
+pub struct AnyDocs {
+    limit: usize,
+}
+
+impl AnyDocs {
+    /// Creates an `AnyDocs` collector returning at most `limit` documents.
+    pub fn with_limit(limit: usize) -> AnyDocs {
+        AnyDocs { limit }
+    }
+}
+
+impl Collector for AnyDocs {
+    type Fruit = Vec<DocAddress>;
+
+    type Child = SegmentAnyDocsCollector;
+
+    fn for_segment(
+        &self,
+        segment_local_id: SegmentLocalId,
+        _: &SegmentReader,
+    ) -> crate::Result<SegmentAnyDocsCollector> {
+        Ok(SegmentAnyDocsCollector {
+            segment_local_id,
+            docs: Vec::new(),
+            limit: self.limit,
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        false
+    }
+
+    fn merge_fruits(&self, segment_docs: Vec<Vec<DocAddress>>) -> crate::Result<Vec<DocAddress>> {
+        let mut doc_addresses: Vec<DocAddress> = segment_docs.into_iter().flatten().collect();
+        doc_addresses.sort();
+        doc_addresses.truncate(self.limit);
+        Ok(doc_addresses)
+    }
+}
+
+pub struct SegmentAnyDocsCollector {
+    segment_local_id: SegmentLocalId,
+    docs: Vec<DocAddress>,
+    limit: usize,
+}
+
+impl SegmentCollector for SegmentAnyDocsCollector {
+    type Fruit = Vec<DocAddress>;
+
+    fn collect(&mut self, doc: DocId, _: Score) {
+        if self.docs.len() < self.limit {
+            self.docs.push(DocAddress(self.segment_local_id, doc));
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.docs.len() >= self.limit
+    }
+
+    fn harvest(self) -> Vec<DocAddress> {
+        self.docs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnyDocs;
+    use crate::collector::Count;
+    use crate::query::{AllQuery, Query, TermQuery};
+    use crate::schema::{IndexRecordOption, Schema, TEXT};
+    use crate::{DocAddress, Index, Term};
+
+    #[test]
+    fn test_any_docs() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        for i in 0..10 {
+            index_writer.add_document(doc!(text => if i % 2 == 0 { "a" } else { "b" }));
+        }
+        index_writer.commit()?;
+        for i in 0..10 {
+            index_writer.add_document(doc!(text => if i % 2 == 0 { "a" } else { "b" }));
+        }
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let query = TermQuery::new(Term::from_field_text(text, "b"), IndexRecordOption::Basic);
+        let doc_addresses = searcher.search(&query, &AnyDocs::with_limit(3))?;
+        assert_eq!(doc_addresses.len(), 3);
+        for &DocAddress(segment_ord, doc) in &doc_addresses {
+            let segment_reader = searcher.segment_reader(segment_ord);
+            assert!(query
+                .explain(&searcher, DocAddress(segment_ord, doc))
+                .is_ok());
+            assert!(doc < segment_reader.max_doc());
+        }
+        assert_eq!(
+            searcher.search(&query, &AnyDocs::with_limit(100))?.len(),
+            10
+        );
+        assert!(searcher
+            .search(&AllQuery, &AnyDocs::with_limit(0))?
+            .is_empty());
+        // Combined with a collector that is never done, the search goes through
+        // all of the documents.
+        let (doc_addresses, count) =
+            searcher.search(&AllQuery, &(AnyDocs::with_limit(1), Count))?;
+        assert_eq!(doc_addresses.len(), 1);
+        assert_eq!(count, 20);
+        Ok(())
+    }
+}
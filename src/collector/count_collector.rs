@@ -1,5 +1,6 @@
 use super::Collector;
 use crate::collector::SegmentCollector;
+use crate::query::Weight;
 use crate::DocId;
 use crate::Score;
 use crate::SegmentLocalId;
@@ -58,6 +59,90 @@ impl Collector for Count {
     fn merge_fruits(&self, segment_counts: Vec<usize>) -> crate::Result<usize> {
         Ok(segment_counts.into_iter().sum())
     }
+
+    /// Counting documents does not require to go through
+    /// the segment collector: the `Weight` is directly asked for
+    /// its count, which spares the computation of scores, and
+    /// makes it possible for some weights to skip the iteration over
+    /// the matching documents altogether.
+    fn collect_segment(
+        &self,
+        weight: &dyn Weight,
+        _segment_ord: u32,
+        reader: &SegmentReader,
+    ) -> crate::Result<usize> {
+        Ok(weight.count(reader)? as usize)
+    }
+}
+
+/// `LimitedCount` counts the documents matching the query,
+/// up to a given limit.
+///
+/// The search of a segment stops as soon as the limit is reached, which
+/// makes it much cheaper than `Count` when the exact number of matching
+/// documents is not needed beyond some point (e.g. "more than 1000 results").
+///
+/// ```rust
+/// use tantivy::collector::LimitedCount;
+/// use tantivy::query::QueryParser;
+/// use tantivy::schema::{Schema, TEXT};
+/// use tantivy::{doc, Index};
+///
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let schema = schema_builder.build();
+/// let index = Index::create_in_ram(schema);
+///
+/// let mut index_writer = index.writer(3_000_000).unwrap();
+/// index_writer.add_document(doc!(title => "The Name of the Wind"));
+/// index_writer.add_document(doc!(title => "The Diary of Muadib"));
+/// index_writer.add_document(doc!(title => "A Dairy Cow"));
+/// index_writer.add_document(doc!(title => "The Diary of a Young Girl"));
+/// assert!(index_writer.commit().is_ok());
+///
+/// let reader = index.reader().unwrap();
+/// let searcher = reader.searcher();
+///
+/// let query_parser = QueryParser::for_index(&index, vec![title]);
+/// let query = query_parser.parse_query("the").unwrap();
+/// let count = searcher.search(&query, &LimitedCount::new(2)).unwrap();
+///
+/// assert_eq!(count, 2);
+/// ```
+pub struct LimitedCount {
+    limit: usize,
+}
+
+impl LimitedCount {
+    /// Creates a `LimitedCount` counting up to `limit` documents.
+    pub fn new(limit: usize) -> LimitedCount {
+        LimitedCount { limit }
+    }
+}
+
+impl Collector for LimitedCount {
+    type Fruit = usize;
+
+    type Child = SegmentLimitedCountCollector;
+
+    fn for_segment(
+        &self,
+        _: SegmentLocalId,
+        _: &SegmentReader,
+    ) -> crate::Result<SegmentLimitedCountCollector> {
+        Ok(SegmentLimitedCountCollector {
+            count: 0,
+            limit: self.limit,
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        false
+    }
+
+    fn merge_fruits(&self, segment_counts: Vec<usize>) -> crate::Result<usize> {
+        Ok(segment_counts.into_iter().sum::<usize>().min(self.limit))
+    }
 }
 
 #[derive(Default)]
@@ -77,11 +162,37 @@ impl SegmentCollector for SegmentCountCollector {
     }
 }
 
+pub struct SegmentLimitedCountCollector {
+    count: usize,
+    limit: usize,
+}
+
+impl SegmentCollector for SegmentLimitedCountCollector {
+    type Fruit = usize;
+
+    fn collect(&mut self, _: DocId, _: Score) {
+        if self.count < self.limit {
+            self.count += 1;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.count >= self.limit
+    }
+
+    fn harvest(self) -> usize {
+        self.count
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Count, SegmentCountCollector};
+    use super::{Count, LimitedCount, SegmentCountCollector};
     use crate::collector::Collector;
     use crate::collector::SegmentCollector;
+    use crate::query::{AllQuery, Query, TermQuery};
+    use crate::schema::{IndexRecordOption, Schema, TEXT};
+    use crate::{Index, Term};
 
     #[test]
     fn test_count_collect_does_not_requires_scoring() {
@@ -111,4 +222,68 @@ mod tests {
             assert_eq!(count_collector.harvest(), 2);
         }
     }
+
+    #[test]
+    fn test_count_with_deletes() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(text=>"a b"));
+        index_writer.add_document(doc!(text=>"a c"));
+        index_writer.add_document(doc!(text=>"c"));
+        index_writer.commit()?;
+        let term_query = TermQuery::new(Term::from_field_text(text, "a"), IndexRecordOption::Basic);
+        let reader = index.reader()?;
+        assert_eq!(reader.searcher().search(&term_query, &Count)?, 2);
+        assert_eq!(reader.searcher().search(&AllQuery, &Count)?, 3);
+        index_writer.delete_term(Term::from_field_text(text, "b"));
+        index_writer.commit()?;
+        reader.reload()?;
+        assert_eq!(reader.searcher().search(&term_query, &Count)?, 1);
+        assert_eq!(reader.searcher().search(&AllQuery, &Count)?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_limited_count() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        for _ in 0..2 {
+            for _ in 0..5 {
+                index_writer.add_document(doc!(text=>"a"));
+            }
+            index_writer.commit()?;
+        }
+        let searcher = index.reader()?.searcher();
+        assert_eq!(searcher.segment_readers().len(), 2);
+        assert_eq!(searcher.search(&AllQuery, &LimitedCount::new(3))?, 3);
+        assert_eq!(searcher.search(&AllQuery, &LimitedCount::new(7))?, 7);
+        assert_eq!(searcher.search(&AllQuery, &LimitedCount::new(100))?, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_each_until_stops_the_search() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        for _ in 0..10 {
+            index_writer.add_document(doc!(text=>"a"));
+        }
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let term_query = TermQuery::new(Term::from_field_text(text, "a"), IndexRecordOption::Basic);
+        let weight = term_query.weight(&searcher, false)?;
+        let mut num_visited = 0;
+        weight.for_each_until(searcher.segment_reader(0), &mut |_, _| {
+            num_visited += 1;
+            num_visited == 4
+        })?;
+        assert_eq!(num_visited, 4);
+        Ok(())
+    }
 }
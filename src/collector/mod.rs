@@ -7,6 +7,7 @@ In tantivy jargon, we call this information your search "fruit".
 
 Your fruit could for instance be :
 - [the count of matching documents](./struct.Count.html)
+- [any few documents matching the query](./struct.AnyDocs.html)
 - [the top 10 documents, by relevancy or by a fast field](./struct.TopDocs.html)
 - [facet counts](./struct.FacetCollector.html)
 
@@ -91,7 +92,10 @@ use crate::SegmentReader;
 use downcast_rs::impl_downcast;
 
 mod count_collector;
-pub use self::count_collector::Count;
+pub use self::count_collector::{Count, LimitedCount};
+
+mod any_docs_collector;
+pub use self::any_docs_collector::AnyDocs;
 
 mod multi_collector;
 pub use self::multi_collector::MultiCollector;
@@ -99,7 +103,7 @@ pub use self::multi_collector::MultiCollector;
 mod top_collector;
 
 mod top_score_collector;
-pub use self::top_score_collector::TopDocs;
+pub use self::top_score_collector::{FastFieldSortKey, Order, TopDocs};
 
 mod custom_score_top_collector;
 pub use self::custom_score_top_collector::{CustomScorer, CustomSegmentScorer};
@@ -109,6 +113,11 @@ pub use self::tweak_score_top_collector::{ScoreSegmentTweaker, ScoreTweaker};
 
 mod facet_collector;
 pub use self::facet_collector::FacetCollector;
+
+mod time_limit_collector;
+pub use self::time_limit_collector::{
+    CancellationToken, TimeLimitCollector, TimeLimitSegmentCollector, TimeLimitedFruit,
+};
 use crate::query::Weight;
 
 /// `Fruit` is the type for the result of our collection.
@@ -166,14 +175,16 @@ pub trait Collector: Sync {
         let mut segment_collector = self.for_segment(segment_ord as u32, reader)?;
 
         if let Some(delete_bitset) = reader.delete_bitset() {
-            weight.for_each(reader, &mut |doc, score| {
+            weight.for_each_until(reader, &mut |doc, score| {
                 if delete_bitset.is_alive(doc) {
                     segment_collector.collect(doc, score);
                 }
+                segment_collector.is_done()
             })?;
         } else {
-            weight.for_each(reader, &mut |doc, score| {
+            weight.for_each_until(reader, &mut |doc, score| {
                 segment_collector.collect(doc, score);
+                segment_collector.is_done()
             })?;
         }
         Ok(segment_collector.harvest())
@@ -193,6 +204,18 @@ pub trait SegmentCollector: 'static {
     /// The query pushes the scored document to the collector via this method.
     fn collect(&mut self, doc: DocId, score: Score);
 
+    /// Returns true once the segment collector does not need any more documents.
+    ///
+    /// The search of the segment then stops right away, instead of going through
+    /// all of the matching documents. By default, segment collectors are never done.
+    ///
+    /// `collect` may still be called afterwards, e.g. if the segment collector
+    /// is combined with others that are not done, in which case the documents
+    /// should be ignored.
+    fn is_done(&self) -> bool {
+        false
+    }
+
     /// Extract the fruit of the collection from the `SegmentCollector`.
     fn harvest(self) -> Self::Fruit;
 }
@@ -251,6 +274,10 @@ where
         self.1.collect(doc, score);
     }
 
+    fn is_done(&self) -> bool {
+        self.0.is_done() && self.1.is_done()
+    }
+
     fn harvest(self) -> <Self as SegmentCollector>::Fruit {
         (self.0.harvest(), self.1.harvest())
     }
@@ -313,6 +340,10 @@ where
         self.2.collect(doc, score);
     }
 
+    fn is_done(&self) -> bool {
+        self.0.is_done() && self.1.is_done() && self.2.is_done()
+    }
+
     fn harvest(self) -> <Self as SegmentCollector>::Fruit {
         (self.0.harvest(), self.1.harvest(), self.2.harvest())
     }
@@ -385,6 +416,10 @@ where
         self.3.collect(doc, score);
     }
 
+    fn is_done(&self) -> bool {
+        self.0.is_done() && self.1.is_done() && self.2.is_done() && self.3.is_done()
+    }
+
     fn harvest(self) -> <Self as SegmentCollector>::Fruit {
         (
             self.0.harvest(),
@@ -0,0 +1,309 @@
+use super::Collector;
+use crate::collector::SegmentCollector;
+use crate::docset::{DocSet, TERMINATED};
+use crate::query::Weight;
+use crate::DocId;
+use crate::Score;
+use crate::SegmentLocalId;
+use crate::SegmentReader;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Number of documents visited between two checks of the deadline
+/// and of the cancellation token.
+const CHECK_INTERVAL: u32 = 1_024;
+
+/// Token used to cancel a search running with a `TimeLimitCollector`
+/// from another thread.
+///
+/// Clones of a token share the same state: cancelling one of them
+/// cancels all of them.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new token, that is not cancelled.
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Cancels the searches using this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Fruit of a `TimeLimitCollector`.
+#[derive(Debug)]
+pub struct TimeLimitedFruit<TFruit> {
+    /// Fruit of the wrapped collector.
+    ///
+    /// If the search timed out, it only accounts for the documents
+    /// collected before the interruption.
+    pub fruit: TFruit,
+    /// True if the search was interrupted, either because the
+    /// time limit was exceeded or because it was cancelled.
+    pub timed_out: bool,
+}
+
+/// `TimeLimitCollector` wraps a collector, and interrupts the search
+/// once a time budget is exhausted, or once a `CancellationToken` is cancelled.
+///
+/// The search then returns the partial results of the wrapped collector,
+/// together with a `timed_out` flag.
+///
+/// The time budget starts when the `TimeLimitCollector` is created.
+/// The deadline and the token are checked while building the `Scorer`
+/// of each segment (see `Weight::interruptible_scorer`), e.g. while expanding
+/// the terms matched by a `FuzzyTermQuery` or a `RegexQuery`,
+/// and then every 1024 matching documents. Building the `Weight` is not interrupted.
+///
+/// The documents are pushed one by one to the wrapped collector, so
+/// optimizations specific to the wrapped collector (e.g. the pruning
+/// of `TopDocs` or the counting of `Count`) are not used.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tantivy::collector::{Count, TimeLimitCollector};
+/// use tantivy::query::QueryParser;
+/// use tantivy::schema::{Schema, TEXT};
+/// use tantivy::{doc, Index};
+///
+/// # fn main() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let schema = schema_builder.build();
+/// let index = Index::create_in_ram(schema);
+///
+/// let mut index_writer = index.writer(3_000_000)?;
+/// index_writer.add_document(doc!(title => "The Name of the Wind"));
+/// index_writer.add_document(doc!(title => "The Diary of Muadib"));
+/// index_writer.add_document(doc!(title => "A Dairy Cow"));
+/// index_writer.add_document(doc!(title => "The Diary of a Young Girl"));
+/// index_writer.commit()?;
+///
+/// let reader = index.reader()?;
+/// let searcher = reader.searcher();
+///
+/// let query_parser = QueryParser::for_index(&index, vec![title]);
+/// let query = query_parser.parse_query("diary")?;
+/// let collector = TimeLimitCollector::new(Count, Duration::from_secs(1));
+/// let count = searcher.search(&query, &collector)?;
+///
+/// assert!(!count.timed_out);
+/// assert_eq!(count.fruit, 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct TimeLimitCollector<TCollector> {
+    collector: TCollector,
+    deadline: Instant,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl<TCollector: Collector> TimeLimitCollector<TCollector> {
+    /// Creates a `TimeLimitCollector` interrupting the search
+    /// once `time_limit` has elapsed.
+    pub fn new(collector: TCollector, time_limit: Duration) -> TimeLimitCollector<TCollector> {
+        TimeLimitCollector {
+            collector,
+            deadline: Instant::now() + time_limit,
+            cancellation_token: None,
+        }
+    }
+
+    /// Also interrupts the search once `cancellation_token` is cancelled.
+    pub fn with_cancellation_token(
+        self,
+        cancellation_token: CancellationToken,
+    ) -> TimeLimitCollector<TCollector> {
+        TimeLimitCollector {
+            cancellation_token: Some(cancellation_token),
+            ..self
+        }
+    }
+
+    fn is_interrupted(&self) -> bool {
+        self.cancellation_token
+            .as_ref()
+            .map(CancellationToken::is_cancelled)
+            .unwrap_or(false)
+            || Instant::now() >= self.deadline
+    }
+}
+
+impl<TCollector: Collector> Collector for TimeLimitCollector<TCollector> {
+    type Fruit = TimeLimitedFruit<TCollector::Fruit>;
+
+    type Child = TimeLimitSegmentCollector<TCollector::Child>;
+
+    fn for_segment(
+        &self,
+        segment_local_id: SegmentLocalId,
+        segment: &SegmentReader,
+    ) -> crate::Result<Self::Child> {
+        let collector = self.collector.for_segment(segment_local_id, segment)?;
+        Ok(TimeLimitSegmentCollector {
+            collector,
+            timed_out: false,
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        self.collector.requires_scoring()
+    }
+
+    fn merge_fruits(
+        &self,
+        segment_fruits: Vec<TimeLimitedFruit<TCollector::Fruit>>,
+    ) -> crate::Result<TimeLimitedFruit<TCollector::Fruit>> {
+        let timed_out = segment_fruits
+            .iter()
+            .any(|segment_fruit| segment_fruit.timed_out);
+        let fruits = segment_fruits
+            .into_iter()
+            .map(|segment_fruit| segment_fruit.fruit)
+            .collect();
+        Ok(TimeLimitedFruit {
+            fruit: self.collector.merge_fruits(fruits)?,
+            timed_out,
+        })
+    }
+
+    fn collect_segment(
+        &self,
+        weight: &dyn Weight,
+        segment_ord: u32,
+        reader: &SegmentReader,
+    ) -> crate::Result<TimeLimitedFruit<TCollector::Fruit>> {
+        let mut segment_collector = self.for_segment(segment_ord, reader)?;
+        let mut scorer =
+            match weight.interruptible_scorer(reader, 1.0f32, &|| self.is_interrupted())? {
+                Some(scorer) => scorer,
+                None => {
+                    segment_collector.timed_out = true;
+                    return Ok(segment_collector.harvest());
+                }
+            };
+        let requires_scoring = self.requires_scoring();
+        let delete_bitset_opt = reader.delete_bitset();
+        let mut num_visited = 0u32;
+        let mut doc = scorer.doc();
+        while doc != TERMINATED {
+            if delete_bitset_opt.map_or(true, |delete_bitset| delete_bitset.is_alive(doc)) {
+                let score = if requires_scoring {
+                    scorer.score()
+                } else {
+                    0f32
+                };
+                segment_collector.collect(doc, score);
+            }
+            num_visited += 1;
+            if segment_collector.is_done() {
+                break;
+            }
+            if num_visited % CHECK_INTERVAL == 0 && self.is_interrupted() {
+                segment_collector.timed_out = true;
+                break;
+            }
+            doc = scorer.advance();
+        }
+        Ok(segment_collector.harvest())
+    }
+}
+
+/// Segment collector of a `TimeLimitCollector`.
+pub struct TimeLimitSegmentCollector<TSegmentCollector> {
+    collector: TSegmentCollector,
+    timed_out: bool,
+}
+
+impl<TSegmentCollector: SegmentCollector> SegmentCollector
+    for TimeLimitSegmentCollector<TSegmentCollector>
+{
+    type Fruit = TimeLimitedFruit<TSegmentCollector::Fruit>;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        self.collector.collect(doc, score);
+    }
+
+    fn is_done(&self) -> bool {
+        self.collector.is_done()
+    }
+
+    fn harvest(self) -> Self::Fruit {
+        TimeLimitedFruit {
+            fruit: self.collector.harvest(),
+            timed_out: self.timed_out,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CancellationToken, TimeLimitCollector};
+    use crate::collector::{Count, TopDocs};
+    use crate::query::AllQuery;
+    use crate::schema::{Schema, TEXT};
+    use crate::Index;
+    use std::time::Duration;
+
+    fn create_index(num_segments: usize, num_docs_per_segment: usize) -> Index {
+        let mut schema_builder = Schema::builder();
+        let text_field = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for _ in 0..num_segments {
+            for _ in 0..num_docs_per_segment {
+                index_writer.add_document(doc!(text_field => "a"));
+            }
+            index_writer.commit().unwrap();
+        }
+        index
+    }
+
+    #[test]
+    fn test_time_limit_collector_not_timed_out() {
+        let index = create_index(2, 3_000);
+        let searcher = index.reader().unwrap().searcher();
+        let collector =
+            TimeLimitCollector::new((Count, TopDocs::with_limit(2)), Duration::from_secs(3_600));
+        let result = searcher.search(&AllQuery, &collector).unwrap();
+        assert!(!result.timed_out);
+        let (count, top_docs) = result.fruit;
+        assert_eq!(count, 6_000);
+        assert_eq!(top_docs.len(), 2);
+    }
+
+    #[test]
+    fn test_time_limit_collector_timed_out() {
+        let index = create_index(2, 3_000);
+        let searcher = index.reader().unwrap().searcher();
+        let collector = TimeLimitCollector::new(Count, Duration::from_secs(0));
+        let result = searcher.search(&AllQuery, &collector).unwrap();
+        assert!(result.timed_out);
+        assert_eq!(result.fruit, 0);
+    }
+
+    #[test]
+    fn test_time_limit_collector_cancelled() {
+        let index = create_index(1, 3_000);
+        let searcher = index.reader().unwrap().searcher();
+        let cancellation_token = CancellationToken::new();
+        let collector = TimeLimitCollector::new(Count, Duration::from_secs(3_600))
+            .with_cancellation_token(cancellation_token.clone());
+        assert!(!searcher.search(&AllQuery, &collector).unwrap().timed_out);
+        cancellation_token.cancel();
+        let result = searcher.search(&AllQuery, &collector).unwrap();
+        assert!(result.timed_out);
+        assert_eq!(result.fruit, 0);
+    }
+}
@@ -6,16 +6,18 @@ use crate::collector::tweak_score_top_collector::TweakedScoreTopCollector;
 use crate::collector::{
     CustomScorer, CustomSegmentScorer, ScoreSegmentTweaker, ScoreTweaker, SegmentCollector,
 };
-use crate::fastfield::FastFieldReader;
+use crate::fastfield::{FastFieldReader, FastValue};
 use crate::query::Weight;
-use crate::schema::Field;
+use crate::schema::{Cardinality, Field};
 use crate::DocAddress;
 use crate::DocId;
 use crate::Score;
 use crate::SegmentLocalId;
 use crate::SegmentReader;
+use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::fmt;
+use std::marker::PhantomData;
 
 /// The `TopDocs` collector keeps track of the top `K` documents
 /// sorted by their score.
@@ -25,7 +27,8 @@ use std::fmt;
 /// is `O(n log K)`.
 ///
 /// This collector guarantees a stable sorting in case of a tie on the
-/// document score. As such, it is suitable to implement pagination.
+/// document score. As such, it is suitable to implement pagination,
+/// either with `.and_offset(...)` or with `.search_after(...)`.
 ///
 /// ```rust
 /// use tantivy::collector::TopDocs;
@@ -55,14 +58,17 @@ use std::fmt;
 /// assert_eq!(&top_docs[0], &(0.7261542, DocAddress(0, 1)));
 /// assert_eq!(&top_docs[1], &(0.6099695, DocAddress(0, 3)));
 /// ```
-pub struct TopDocs(TopCollector<Score>);
+pub struct TopDocs {
+    collector: TopCollector<Score>,
+    search_after: Option<(Score, DocAddress)>,
+}
 
 impl fmt::Debug for TopDocs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
             "TopDocs(limit={}, offset={})",
-            self.0.limit, self.0.offset
+            self.collector.limit, self.collector.offset
         )
     }
 }
@@ -98,13 +104,106 @@ impl CustomScorer<u64> for ScorerByField {
     }
 }
 
+/// Order in which `TopDocs::order_by_fast_field` sorts the documents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    /// Smallest values first.
+    Asc,
+    /// Largest values first.
+    Desc,
+}
+
+/// Sort key of the documents collected by `TopDocs::order_by_fast_field`.
+///
+/// Documents are sorted by their fast field value, following the requested
+/// `Order`. Ties are broken by decreasing score.
+#[derive(Clone, Copy, Debug)]
+pub struct FastFieldSortKey<TFastValue> {
+    /// Fast field value of the document.
+    pub value: TFastValue,
+    /// Score of the document.
+    pub score: Score,
+    order: Order,
+}
+
+impl<TFastValue: PartialOrd> PartialOrd for FastFieldSortKey<TFastValue> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let by_value = match self.order {
+            Order::Asc => other.value.partial_cmp(&self.value)?,
+            Order::Desc => self.value.partial_cmp(&other.value)?,
+        };
+        Some(by_value.then(self.score.partial_cmp(&other.score)?))
+    }
+}
+
+impl<TFastValue: PartialOrd> PartialEq for FastFieldSortKey<TFastValue> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+struct FastFieldSortKeySegmentTweaker<TFastValue> {
+    ff_reader: FastFieldReader<u64>,
+    order: Order,
+    _phantom: PhantomData<TFastValue>,
+}
+
+impl<TFastValue: FastValue + 'static> ScoreSegmentTweaker<FastFieldSortKey<TFastValue>>
+    for FastFieldSortKeySegmentTweaker<TFastValue>
+{
+    fn score(&mut self, doc: DocId, score: Score) -> FastFieldSortKey<TFastValue> {
+        FastFieldSortKey {
+            value: TFastValue::from_u64(self.ff_reader.get(doc)),
+            score,
+            order: self.order,
+        }
+    }
+}
+
+struct FastFieldSortKeyTweaker<TFastValue> {
+    field: Field,
+    order: Order,
+    _phantom: PhantomData<TFastValue>,
+}
+
+impl<TFastValue: FastValue + 'static> ScoreTweaker<FastFieldSortKey<TFastValue>>
+    for FastFieldSortKeyTweaker<TFastValue>
+{
+    type Child = FastFieldSortKeySegmentTweaker<TFastValue>;
+
+    fn segment_tweaker(&self, segment_reader: &SegmentReader) -> crate::Result<Self::Child> {
+        let field_entry = segment_reader.schema().get_field_entry(self.field);
+        let ff_reader_opt = if TFastValue::fast_field_cardinality(field_entry.field_type())
+            == Some(Cardinality::SingleValue)
+        {
+            segment_reader.fast_fields().u64_lenient(self.field)
+        } else {
+            None
+        };
+        let ff_reader = ff_reader_opt.ok_or_else(|| {
+            crate::TantivyError::SchemaError(format!(
+                "Field {:?} is not a single-valued fast field of the requested type.",
+                field_entry.name()
+            ))
+        })?;
+        Ok(FastFieldSortKeySegmentTweaker {
+            ff_reader,
+            order: self.order,
+            _phantom: PhantomData,
+        })
+    }
+}
+
 impl TopDocs {
     /// Creates a top score collector, with a number of documents equal to "limit".
     ///
     /// # Panics
     /// The method panics if limit is 0
     pub fn with_limit(limit: usize) -> TopDocs {
-        TopDocs(TopCollector::with_limit(limit))
+        TopDocs {
+            collector: TopCollector::with_limit(limit),
+            search_after: None,
+        }
     }
 
     /// Skip the first "offset" documents when collecting.
@@ -143,7 +242,64 @@ impl TopDocs {
     /// assert_eq!(&top_docs[1], &(0.4793185, DocAddress(0, 3)));
     /// ```
     pub fn and_offset(self, offset: usize) -> TopDocs {
-        TopDocs(self.0.and_offset(offset))
+        TopDocs {
+            collector: self.collector.and_offset(offset),
+            search_after: self.search_after,
+        }
+    }
+
+    /// Only collect the documents ranked after a given hit, typically
+    /// the last hit of the previous page.
+    ///
+    /// Unlike `.and_offset(...)`, the cost of fetching a page does not
+    /// grow with its depth: the collector only keeps track of `limit` documents.
+    ///
+    /// Documents are ranked by decreasing score, and then by increasing
+    /// `DocAddress`. Pages are consistent as long as the searcher is the same.
+    ///
+    /// `search_after` only applies to the ranking by score. It is ignored
+    /// by `.order_by_u64_field(...)`, `.order_by_fast_field(...)`,
+    /// `.tweak_score(...)` and `.custom_score(...)`.
+    ///
+    /// ```rust
+    /// use tantivy::collector::TopDocs;
+    /// use tantivy::query::QueryParser;
+    /// use tantivy::schema::{Schema, TEXT};
+    /// use tantivy::{doc, DocAddress, Index};
+    ///
+    /// let mut schema_builder = Schema::builder();
+    /// let title = schema_builder.add_text_field("title", TEXT);
+    /// let schema = schema_builder.build();
+    /// let index = Index::create_in_ram(schema);
+    ///
+    /// let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+    /// index_writer.add_document(doc!(title => "The Name of the Wind"));
+    /// index_writer.add_document(doc!(title => "The Diary of Muadib"));
+    /// index_writer.add_document(doc!(title => "A Dairy Cow"));
+    /// index_writer.add_document(doc!(title => "The Diary of a Young Girl"));
+    /// index_writer.add_document(doc!(title => "The Diary of Lena Mukhina"));
+    /// assert!(index_writer.commit().is_ok());
+    ///
+    /// let reader = index.reader().unwrap();
+    /// let searcher = reader.searcher();
+    ///
+    /// let query_parser = QueryParser::for_index(&index, vec![title]);
+    /// let query = query_parser.parse_query("diary").unwrap();
+    /// let first_page = searcher.search(&query, &TopDocs::with_limit(1)).unwrap();
+    /// let last_hit = first_page[0];
+    /// let second_page = searcher
+    ///     .search(&query, &TopDocs::with_limit(2).search_after(last_hit))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(second_page.len(), 2);
+    /// assert_eq!(&second_page[0], &(0.5204813, DocAddress(0, 4)));
+    /// assert_eq!(&second_page[1], &(0.4793185, DocAddress(0, 3)));
+    /// ```
+    pub fn search_after(self, last_hit: (Score, DocAddress)) -> TopDocs {
+        TopDocs {
+            collector: self.collector,
+            search_after: Some(last_hit),
+        }
     }
 
     /// Set top-K to rank documents by a given fast field.
@@ -222,6 +378,65 @@ impl TopDocs {
         self.custom_score(ScorerByField { field })
     }
 
+    /// Set top-K to rank documents by a given fast field, in ascending or
+    /// descending order.
+    ///
+    /// Unlike `order_by_u64_field`, this works for any single-valued fast
+    /// field (`u64`, `i64`, `f64`, dates or bools), and documents having
+    /// the same value are ranked by decreasing score. Documents without any
+    /// value for the field are ranked as if they had the default value.
+    ///
+    /// ```rust
+    /// use tantivy::collector::{Order, TopDocs};
+    /// use tantivy::query::QueryParser;
+    /// use tantivy::schema::{Schema, FAST, TEXT};
+    /// use tantivy::{doc, DocAddress, Index};
+    ///
+    /// # fn main() -> tantivy::Result<()> {
+    /// let mut schema_builder = Schema::builder();
+    /// let title = schema_builder.add_text_field("title", TEXT);
+    /// let price = schema_builder.add_f64_field("price", FAST);
+    /// let schema = schema_builder.build();
+    /// let index = Index::create_in_ram(schema);
+    ///
+    /// let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+    /// index_writer.add_document(doc!(title => "The Diary of Muadib", price => 12.5f64));
+    /// index_writer.add_document(doc!(title => "A Dairy Cow", price => 4.0f64));
+    /// index_writer.add_document(doc!(title => "The Diary of a Young Girl", price => 7.9f64));
+    /// index_writer.commit()?;
+    ///
+    /// let searcher = index.reader()?.searcher();
+    /// let query = QueryParser::for_index(&index, vec![title]).parse_query("diary")?;
+    /// let cheapest_first = TopDocs::with_limit(10).order_by_fast_field::<f64>(price, Order::Asc);
+    /// let top_docs = searcher.search(&query, &cheapest_first)?;
+    /// let prices: Vec<(f64, DocAddress)> = top_docs
+    ///     .into_iter()
+    ///     .map(|(sort_key, doc_address)| (sort_key.value, doc_address))
+    ///     .collect();
+    /// assert_eq!(prices, vec![(7.9f64, DocAddress(0, 2)), (12.5f64, DocAddress(0, 0))]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Collecting returns a `SchemaError` if the field is not a single-valued
+    /// fast field of type `TFastValue`.
+    pub fn order_by_fast_field<TFastValue>(
+        self,
+        field: Field,
+        order: Order,
+    ) -> impl Collector<Fruit = Vec<(FastFieldSortKey<TFastValue>, DocAddress)>>
+    where
+        TFastValue: FastValue + 'static,
+    {
+        self.tweak_score(FastFieldSortKeyTweaker {
+            field,
+            order,
+            _phantom: PhantomData,
+        })
+    }
+
     /// Ranks the documents using a custom score.
     ///
     /// This method offers a convenient way to tweak or replace
@@ -326,7 +541,7 @@ impl TopDocs {
         TScoreSegmentTweaker: ScoreSegmentTweaker<TScore> + 'static,
         TScoreTweaker: ScoreTweaker<TScore, Child = TScoreSegmentTweaker>,
     {
-        TweakedScoreTopCollector::new(score_tweaker, self.0.into_tscore())
+        TweakedScoreTopCollector::new(score_tweaker, self.collector.into_tscore())
     }
 
     /// Ranks the documents using a custom score.
@@ -440,7 +655,7 @@ impl TopDocs {
         TCustomSegmentScorer: CustomSegmentScorer<TScore> + 'static,
         TCustomScorer: CustomScorer<TScore, Child = TCustomSegmentScorer>,
     {
-        CustomScoreTopCollector::new(custom_score, self.0.into_tscore())
+        CustomScoreTopCollector::new(custom_score, self.collector.into_tscore())
     }
 }
 
@@ -454,8 +669,12 @@ impl Collector for TopDocs {
         segment_local_id: SegmentLocalId,
         reader: &SegmentReader,
     ) -> crate::Result<Self::Child> {
-        let collector = self.0.for_segment(segment_local_id, reader)?;
-        Ok(TopScoreSegmentCollector(collector))
+        let collector = self.collector.for_segment(segment_local_id, reader)?;
+        Ok(TopScoreSegmentCollector {
+            collector,
+            segment_local_id,
+            search_after: self.search_after,
+        })
     }
 
     fn requires_scoring(&self) -> bool {
@@ -466,7 +685,7 @@ impl Collector for TopDocs {
         &self,
         child_fruits: Vec<Vec<(Score, DocAddress)>>,
     ) -> crate::Result<Self::Fruit> {
-        self.0.merge_fruits(child_fruits)
+        self.collector.merge_fruits(child_fruits)
     }
 
     fn collect_segment(
@@ -475,13 +694,15 @@ impl Collector for TopDocs {
         segment_ord: u32,
         reader: &SegmentReader,
     ) -> crate::Result<<Self::Child as SegmentCollector>::Fruit> {
-        let heap_len = self.0.limit + self.0.offset;
+        let heap_len = self.collector.limit + self.collector.offset;
         let mut heap: BinaryHeap<ComparableDoc<Score, DocId>> = BinaryHeap::with_capacity(heap_len);
 
         if let Some(delete_bitset) = reader.delete_bitset() {
             let mut threshold = f32::MIN;
             weight.for_each_pruning(threshold, reader, &mut |doc, score| {
-                if delete_bitset.is_deleted(doc) {
+                if delete_bitset.is_deleted(doc)
+                    || !ranks_after(self.search_after, score, DocAddress(segment_ord, doc))
+                {
                     return threshold;
                 }
                 let heap_item = ComparableDoc {
@@ -501,6 +722,13 @@ impl Collector for TopDocs {
             })?;
         } else {
             weight.for_each_pruning(f32::MIN, reader, &mut |doc, score| {
+                if !ranks_after(self.search_after, score, DocAddress(segment_ord, doc)) {
+                    if heap.len() == heap_len {
+                        return heap.peek().map(|el| el.feature).unwrap_or(f32::MIN);
+                    } else {
+                        return f32::MIN;
+                    }
+                }
                 let heap_item = ComparableDoc {
                     feature: score,
                     doc,
@@ -528,25 +756,46 @@ impl Collector for TopDocs {
     }
 }
 
+/// Returns true iff a hit is ranked after the `search_after` hit, if any.
+fn ranks_after(
+    search_after: Option<(Score, DocAddress)>,
+    score: Score,
+    doc_address: DocAddress,
+) -> bool {
+    match search_after {
+        Some((last_score, last_doc_address)) => {
+            score < last_score || (score == last_score && doc_address > last_doc_address)
+        }
+        None => true,
+    }
+}
+
 /// Segment Collector associated to `TopDocs`.
-pub struct TopScoreSegmentCollector(TopSegmentCollector<Score>);
+pub struct TopScoreSegmentCollector {
+    collector: TopSegmentCollector<Score>,
+    segment_local_id: SegmentLocalId,
+    search_after: Option<(Score, DocAddress)>,
+}
 
 impl SegmentCollector for TopScoreSegmentCollector {
     type Fruit = Vec<(Score, DocAddress)>;
 
     fn collect(&mut self, doc: DocId, score: Score) {
-        self.0.collect(doc, score);
+        let doc_address = DocAddress(self.segment_local_id, doc);
+        if ranks_after(self.search_after, score, doc_address) {
+            self.collector.collect(doc, score);
+        }
     }
 
     fn harvest(self) -> Vec<(Score, DocAddress)> {
-        self.0.harvest()
+        self.collector.harvest()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::TopDocs;
-    use crate::collector::Collector;
+    use super::{Order, TopDocs};
+    use crate::collector::{Collector, SegmentCollector};
     use crate::query::{AllQuery, Query, QueryParser};
     use crate::schema::{Field, Schema, FAST, STORED, TEXT};
     use crate::Index;
@@ -736,6 +985,126 @@ mod tests {
             .expect("should panic");
     }
 
+    #[test]
+    fn test_top_collector_search_after() {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field(TITLE, TEXT);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for titles in &[
+            &["beer", "bottle of beer", "beer"][..],
+            &["beer beer", "bottle of beer", "no wine"][..],
+            &["beer", "growler of beer"][..],
+        ] {
+            for title_val in titles.iter() {
+                index_writer.add_document(doc!(title => *title_val));
+            }
+            index_writer.commit().unwrap();
+        }
+        let searcher = index.reader().unwrap().searcher();
+        assert_eq!(searcher.segment_readers().len(), 3);
+        let query = QueryParser::for_index(&index, vec![title])
+            .parse_query("beer")
+            .unwrap();
+        let all_docs = searcher.search(&query, &TopDocs::with_limit(10)).unwrap();
+        assert_eq!(all_docs.len(), 7);
+        let mut paginated_docs: Vec<(Score, DocAddress)> = Vec::new();
+        let mut collector = TopDocs::with_limit(2);
+        loop {
+            let page = searcher.search(&query, &collector).unwrap();
+            if page.is_empty() {
+                break;
+            }
+            assert!(page.len() <= 2);
+            collector = TopDocs::with_limit(2).search_after(*page.last().unwrap());
+            paginated_docs.extend(page);
+        }
+        assert_eq!(paginated_docs, all_docs);
+
+        // Without pruning.
+        let collector = TopDocs::with_limit(10).search_after(all_docs[2]);
+        let mut segment_fruits = Vec::new();
+        for (segment_ord, segment_reader) in searcher.segment_readers().iter().enumerate() {
+            let mut segment_collector = collector
+                .for_segment(segment_ord as u32, segment_reader)
+                .unwrap();
+            for &(score, DocAddress(doc_segment_ord, doc)) in &all_docs {
+                if doc_segment_ord == segment_ord as u32 {
+                    segment_collector.collect(doc, score);
+                }
+            }
+            segment_fruits.push(segment_collector.harvest());
+        }
+        assert_eq!(
+            collector.merge_fruits(segment_fruits).unwrap(),
+            all_docs[3..].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_top_field_collector_order_by_fast_field() {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field(TITLE, TEXT);
+        let price = schema_builder.add_i64_field("price", FAST);
+        let schema = schema_builder.build();
+        let (index, query) = index("beer", title, schema, |index_writer| {
+            index_writer.add_document(doc!(title => "beer", price => 3i64));
+            index_writer.add_document(doc!(title => "bottle of beer", price => -2i64));
+            index_writer.add_document(doc!(title => "beer beer", price => 3i64));
+            index_writer.add_document(doc!(title => "growler of beer", price => 10i64));
+        });
+        let searcher = index.reader().unwrap().searcher();
+        let sorted_docs = |order: Order| -> Vec<(i64, DocAddress)> {
+            let top_collector = TopDocs::with_limit(4).order_by_fast_field::<i64>(price, order);
+            searcher
+                .search(&query, &top_collector)
+                .unwrap()
+                .into_iter()
+                .map(|(sort_key, doc_address)| (sort_key.value, doc_address))
+                .collect()
+        };
+        // The score breaks the tie between the documents priced 3.
+        assert_eq!(
+            sorted_docs(Order::Desc),
+            vec![
+                (10, DocAddress(0, 3)),
+                (3, DocAddress(0, 2)),
+                (3, DocAddress(0, 0)),
+                (-2, DocAddress(0, 1))
+            ]
+        );
+        assert_eq!(
+            sorted_docs(Order::Asc),
+            vec![
+                (-2, DocAddress(0, 1)),
+                (3, DocAddress(0, 2)),
+                (3, DocAddress(0, 0)),
+                (10, DocAddress(0, 3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_by_fast_field_wrong_type() {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field(TITLE, TEXT);
+        let size = schema_builder.add_u64_field(SIZE, FAST);
+        let schema = schema_builder.build();
+        let (index, _) = index("beer", title, schema, |index_writer| {
+            index_writer.add_document(doc!(title => "bottle of beer", size => 12u64));
+        });
+        let searcher = index.reader().unwrap().searcher();
+        let segment = searcher.segment_reader(0);
+        let top_collector = TopDocs::with_limit(4).order_by_fast_field::<i64>(size, Order::Asc);
+        assert!(matches!(
+            top_collector.for_segment(0, segment),
+            Err(crate::TantivyError::SchemaError(_))
+        ));
+        let top_collector = TopDocs::with_limit(4).order_by_fast_field::<u64>(size, Order::Asc);
+        assert!(top_collector.for_segment(0, segment).is_ok());
+    }
+
     #[test]
     fn test_field_not_fast_field() {
         let mut schema_builder = Schema::builder();
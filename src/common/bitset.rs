@@ -168,6 +168,19 @@ impl BitSet {
         };
     }
 
+    /// Intersects the `BitSet` with `other`, in place.
+    ///
+    /// Both bitsets are expected to have the same `max_value`.
+    pub fn intersect_update(&mut self, other: &BitSet) {
+        assert_eq!(self.max_value, other.max_value);
+        let mut len = 0;
+        for (tinyset, &other_tinyset) in self.tinysets.iter_mut().zip(other.tinysets.iter()) {
+            *tinyset = tinyset.intersect(other_tinyset);
+            len += tinyset.len() as usize;
+        }
+        self.len = len;
+    }
+
     /// Returns true iff the elements is in the `BitSet`.
     pub fn contains(&self, el: u32) -> bool {
         self.tinyset(el / 64u32).contains(el % 64)
@@ -340,6 +353,23 @@ mod tests {
         assert_eq!(bitset.len(), 3);
     }
 
+    #[test]
+    fn test_bitset_intersect_update() {
+        let mut left = BitSet::with_max_value(1_000);
+        let mut right = BitSet::with_max_value(1_000);
+        for el in (0u32..1_000u32).filter(|el| el % 2 == 0) {
+            left.insert(el);
+        }
+        for el in (0u32..1_000u32).filter(|el| el % 3 == 0) {
+            right.insert(el);
+        }
+        left.intersect_update(&right);
+        assert_eq!(left.len(), 167);
+        for el in 0u32..1_000u32 {
+            assert_eq!(left.contains(el), el % 6 == 0);
+        }
+    }
+
     #[test]
     fn test_bitset_clear() {
         let mut bitset = BitSet::with_max_value(1_000);
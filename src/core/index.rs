@@ -4,25 +4,31 @@ use crate::core::IndexMeta;
 use crate::core::SegmentId;
 use crate::core::SegmentMeta;
 use crate::core::SegmentMetaInventory;
+use crate::core::SegmentReader;
 use crate::core::META_FILEPATH;
 use crate::directory::ManagedDirectory;
 #[cfg(feature = "mmap")]
 use crate::directory::MmapDirectory;
-use crate::directory::INDEX_WRITER_LOCK;
 use crate::directory::{Directory, RAMDirectory};
+use crate::directory::{Lock, INDEX_WRITER_LOCK};
 use crate::error::DataCorruption;
 use crate::error::TantivyError;
 use crate::indexer::index_writer::HEAP_SIZE_MIN;
 use crate::indexer::segment_updater::save_new_metas;
+use crate::indexer::NoMergePolicy;
+use crate::query::{BM25Similarity, Similarity};
 use crate::reader::IndexReader;
 use crate::reader::IndexReaderBuilder;
 use crate::schema::Field;
 use crate::schema::FieldType;
 use crate::schema::Schema;
+use crate::space_usage::SearcherSpaceUsage;
+use crate::store::Compressor;
 use crate::tokenizer::{TextAnalyzer, TokenizerManager};
 use crate::IndexWriter;
+use futures::executor::block_on;
 use std::borrow::BorrowMut;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 #[cfg(feature = "mmap")]
@@ -53,6 +59,9 @@ pub struct Index {
     schema: Schema,
     executor: Arc<Executor>,
     tokenizers: TokenizerManager,
+    similarity: Arc<dyn Similarity>,
+    field_similarities: HashMap<Field, Arc<dyn Similarity>>,
+    store_compressor: Compressor,
     inventory: SegmentMetaInventory,
 }
 
@@ -166,6 +175,9 @@ impl Index {
             directory,
             schema,
             tokenizers: TokenizerManager::default(),
+            similarity: Arc::new(BM25Similarity::default()),
+            field_similarities: HashMap::new(),
+            store_compressor: Compressor::default(),
             executor: Arc::new(Executor::single_thread()),
             inventory,
         })
@@ -186,6 +198,10 @@ impl Index {
                 .get_indexing_options()
                 .map(|text_indexing_options| text_indexing_options.tokenizer().to_string())
                 .and_then(|tokenizer_name| tokenizer_manager.get(&tokenizer_name)),
+            FieldType::JsonObject(json_object_options) => json_object_options
+                .get_indexing_options()
+                .map(|text_indexing_options| text_indexing_options.tokenizer().to_string())
+                .and_then(|tokenizer_name| tokenizer_manager.get(&tokenizer_name)),
             _ => None,
         };
         match tokenizer_name_opt {
@@ -197,6 +213,61 @@ impl Index {
         }
     }
 
+    /// Returns the `Similarity` used to score the terms of the given field.
+    ///
+    /// Unless configured otherwise, this is the
+    /// [`BM25Similarity`](../query/struct.BM25Similarity.html).
+    pub fn similarity_for_field(&self, field: Field) -> Arc<dyn Similarity> {
+        self.field_similarities
+            .get(&field)
+            .unwrap_or(&self.similarity)
+            .clone()
+    }
+
+    /// Replaces the `Similarity` used to score all of the fields
+    /// that do not have a specific similarity.
+    ///
+    /// The similarity is only used at search time, so it can be changed
+    /// without reindexing. It needs to be set before creating
+    /// the `IndexReader` however.
+    pub fn set_similarity<S: Similarity>(&mut self, similarity: S) {
+        self.similarity = Arc::new(similarity);
+    }
+
+    /// Sets the `Similarity` used to score the terms of a specific field.
+    ///
+    /// See [`set_similarity`](#method.set_similarity).
+    pub fn set_field_similarity<S: Similarity>(&mut self, field: Field, similarity: S) {
+        self.field_similarities.insert(field, Arc::new(similarity));
+    }
+
+    /// Returns the `Compressor` used to write the doc store of new segments.
+    pub fn store_compressor(&self) -> Compressor {
+        self.store_compressor
+    }
+
+    /// Sets the `Compressor` used to write the doc store of new segments,
+    /// including the segments resulting from a merge.
+    ///
+    /// The compressor is recorded in the meta of each segment, so that
+    /// existing segments remain readable after a change of compressor.
+    /// It needs to be set before creating the `IndexWriter`.
+    pub fn set_store_compressor(&mut self, store_compressor: Compressor) {
+        self.store_compressor = store_compressor;
+    }
+
+    /// Sets whether the checksum of the index files should be verified
+    /// every time they are opened.
+    ///
+    /// Verifying the checksum requires reading the entire file, which
+    /// defeats the purpose of memory mapping, so this is disabled by default.
+    /// When enabled, opening a corrupted segment fails with a
+    /// `TantivyError::DataCorruption` error instead of returning garbage.
+    /// It needs to be set before creating the `IndexReader`.
+    pub fn set_validate_checksums_on_open(&mut self, validate_checksums: bool) {
+        self.directory.set_validate_checksums(validate_checksums);
+    }
+
     /// Create a default `IndexReader` for the given index.
     ///
     /// See [`Index.reader_builder()`](#method.reader_builder).
@@ -235,7 +306,9 @@ impl Index {
     /// `SegmentMeta` are guaranteed to not be garbage collected, regardless of
     /// whether the segment is recorded as part of the index or not.
     pub fn new_segment_meta(&self, segment_id: SegmentId, max_doc: u32) -> SegmentMeta {
-        self.inventory.new_segment_meta(segment_id, max_doc)
+        self.inventory
+            .new_segment_meta(segment_id, max_doc)
+            .with_store_compressor(self.store_compressor)
     }
 
     /// Open the index using the provided directory
@@ -276,21 +349,54 @@ impl Index {
         num_threads: usize,
         overall_heap_size_in_bytes: usize,
     ) -> crate::Result<IndexWriter> {
-        let directory_lock = self
-            .directory
-            .acquire_lock(&INDEX_WRITER_LOCK)
-            .map_err(|err| {
-                TantivyError::LockFailure(
-                    err,
-                    Some(
-                        "Failed to acquire index lock. If you are using \
+        self.writer_with_lock(&INDEX_WRITER_LOCK, num_threads, overall_heap_size_in_bytes)
+    }
+
+    /// Open a new index writer, waiting for the lockfile to be released
+    /// if another `IndexWriter` is working on the index.
+    ///
+    /// This behaves like `writer_with_num_threads`, except that the
+    /// index lock is acquired as a blocking [`Lock`](../directory/struct.Lock.html).
+    /// With a `MmapDirectory`, this relies on a blocking file lock.
+    /// Other directories retry acquiring the lock for about 10 seconds
+    /// before giving up.
+    ///
+    /// # Errors
+    /// If the lockfile is still present after the retries, returns
+    /// `Error::LockFailure` or an `Error::IOError`.
+    ///
+    /// # Panics
+    /// If the heap size per thread is too small, panics.
+    pub fn writer_with_num_threads_blocking(
+        &self,
+        num_threads: usize,
+        overall_heap_size_in_bytes: usize,
+    ) -> crate::Result<IndexWriter> {
+        let blocking_lock = Lock {
+            filepath: INDEX_WRITER_LOCK.filepath.clone(),
+            is_blocking: true,
+        };
+        self.writer_with_lock(&blocking_lock, num_threads, overall_heap_size_in_bytes)
+    }
+
+    fn writer_with_lock(
+        &self,
+        lock: &Lock,
+        num_threads: usize,
+        overall_heap_size_in_bytes: usize,
+    ) -> crate::Result<IndexWriter> {
+        let directory_lock = self.directory.acquire_lock(lock).map_err(|err| {
+            TantivyError::LockFailure(
+                err,
+                Some(
+                    "Failed to acquire index lock. If you are using \
                          a regular directory, this means there is already an \
                          `IndexWriter` working on this `Directory`, in this process \
                          or in a different process."
-                            .to_string(),
-                    ),
-                )
-            })?;
+                        .to_string(),
+                ),
+            )
+        })?;
         let heap_size_in_bytes_per_thread = overall_heap_size_in_bytes / num_threads;
         IndexWriter::new(
             self,
@@ -342,9 +448,7 @@ impl Index {
 
     /// Creates a new segment.
     pub fn new_segment(&self) -> Segment {
-        let segment_meta = self
-            .inventory
-            .new_segment_meta(SegmentId::generate_random(), 0);
+        let segment_meta = self.new_segment_meta(SegmentId::generate_random(), 0);
         self.segment(segment_meta)
     }
 
@@ -364,6 +468,40 @@ impl Index {
         Ok(self.load_metas()?.segments)
     }
 
+    /// Rewrites the segments written with an older index format
+    /// in the current index format.
+    ///
+    /// Tantivy can read segments written with at least the previous index
+    /// format, so there is no need to reindex the documents after upgrading tantivy.
+    /// However, support for an old index format is eventually dropped. Calling
+    /// `upgrade()` after each upgrade of tantivy ensures the index remains readable
+    /// by future versions. The format of a segment is given by
+    /// `SegmentMeta::index_format_version()`.
+    ///
+    /// Each outdated segment is rewritten on its own, by merging it into a new
+    /// segment. Deleted documents are purged in the process.
+    /// This method acquires the index lock, and fails if an `IndexWriter`
+    /// is already working on the index.
+    pub fn upgrade(&self) -> crate::Result<()> {
+        let outdated_segment_ids: Vec<SegmentId> = self
+            .searchable_segment_metas()?
+            .iter()
+            .filter(|segment_meta| {
+                segment_meta.index_format_version() < crate::INDEX_FORMAT_VERSION
+            })
+            .map(SegmentMeta::id)
+            .collect();
+        if outdated_segment_ids.is_empty() {
+            return Ok(());
+        }
+        let mut index_writer = self.writer_with_num_threads(1, HEAP_SIZE_MIN)?;
+        index_writer.set_merge_policy(Box::new(NoMergePolicy));
+        for segment_id in outdated_segment_ids {
+            block_on(index_writer.merge(&[segment_id]))?;
+        }
+        index_writer.wait_merging_threads()
+    }
+
     /// Returns the list of segment ids that are searchable.
     pub fn searchable_segment_ids(&self) -> crate::Result<Vec<SegmentId>> {
         Ok(self
@@ -373,6 +511,19 @@ impl Index {
             .collect())
     }
 
+    /// Summarizes the space usage of the searchable segments of the index,
+    /// broken down by segment, component and field.
+    ///
+    /// Unlike `Searcher::space_usage()`, this does not require an `IndexReader`:
+    /// the segments of the last commit are opened for the occasion.
+    pub fn space_usage(&self) -> crate::Result<SearcherSpaceUsage> {
+        let mut space_usage = SearcherSpaceUsage::new();
+        for segment in self.searchable_segments()? {
+            space_usage.add_segment(SegmentReader::open(&segment)?.space_usage());
+        }
+        Ok(space_usage)
+    }
+
     /// Returns the set of corrupted files
     pub fn validate_checksum(&self) -> crate::Result<HashSet<PathBuf>> {
         self.directory.list_damaged().map_err(Into::into)
@@ -387,12 +538,21 @@ impl fmt::Debug for Index {
 
 #[cfg(test)]
 mod tests {
+    use crate::collector::{Count, TopDocs};
+    use crate::common::bitpacker::BitPacker;
+    use crate::common::{compute_num_bits, BinarySerializable, CompositeWrite};
+    use crate::core::{SegmentComponent, META_FILEPATH};
     use crate::directory::RAMDirectory;
+    use crate::query::TermQuery;
     use crate::schema::Field;
+    use crate::schema::{IndexRecordOption, FAST, STORED};
     use crate::schema::{Schema, INDEXED, TEXT};
     use crate::IndexReader;
     use crate::ReloadPolicy;
+    use crate::Term;
     use crate::{Directory, Index};
+    use futures::executor::block_on;
+    use std::path::PathBuf;
 
     #[test]
     fn test_indexer_for_field() {
@@ -587,9 +747,13 @@ mod tests {
 
         assert_eq!(reader.searcher().num_docs(), 8_000);
         writer.wait_merging_threads().unwrap();
-        let mem_right_after_merge_finished = directory.total_mem_usage();
 
+        // The searchers of the reader pin the merged segments until it is reloaded.
         reader.reload().unwrap();
+        let writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        block_on(writer.garbage_collect_files()).unwrap();
+        let mem_right_after_merge_finished = directory.total_mem_usage();
+
         let searcher = reader.searcher();
         assert_eq!(searcher.num_docs(), 8_000);
         assert!(
@@ -599,4 +763,125 @@ mod tests {
             mem_right_after_commit
         );
     }
+
+    #[test]
+    fn test_searcher_pins_segment_files() {
+        let mut schema_builder = Schema::builder();
+        let text_field = schema_builder.add_text_field("text", TEXT | STORED);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        writer.add_document(doc!(text_field => "a"));
+        writer.commit().unwrap();
+        writer.add_document(doc!(text_field => "b"));
+        writer.commit().unwrap();
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .unwrap();
+        let searcher = reader.searcher();
+        let segment_files: Vec<PathBuf> = searcher
+            .segment_readers()
+            .iter()
+            .flat_map(|segment_reader| segment_reader.segment_meta().list_files())
+            .filter(|path| index.directory().exists(path))
+            .collect();
+        assert!(!segment_files.is_empty());
+        let segment_ids = index.searchable_segment_ids().unwrap();
+        block_on(writer.merge(&segment_ids)).unwrap();
+        reader.reload().unwrap();
+        block_on(writer.garbage_collect_files()).unwrap();
+
+        // The files of the merged segments are still used by `searcher`.
+        for path in &segment_files {
+            assert!(index.directory().exists(path));
+        }
+        let query = TermQuery::new(
+            Term::from_field_text(text_field, "b"),
+            IndexRecordOption::Basic,
+        );
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(1)).unwrap();
+        let doc = searcher.doc(top_docs[0].1).unwrap();
+        assert_eq!(doc.get_first(text_field).unwrap().text(), Some("b"));
+
+        drop(searcher);
+        block_on(writer.garbage_collect_files()).unwrap();
+        for path in &segment_files {
+            assert!(!index.directory().exists(path));
+        }
+    }
+
+    /// Rewrites the fast fields of the segment in the first index format,
+    /// and records the segment as written with this format in `meta.json`.
+    fn downgrade_to_legacy_format(index: &mut Index, field: Field, vals: &[u64]) {
+        let segment_meta = index.searchable_segment_metas().unwrap()[0].clone();
+        let fast_field_path = segment_meta.relative_path(SegmentComponent::FASTFIELDS);
+        index.directory_mut().delete(&fast_field_path).unwrap();
+        let write = index.directory_mut().open_write(&fast_field_path).unwrap();
+        let mut composite_write = CompositeWrite::wrap(write);
+        {
+            let field_write = composite_write.for_field(field);
+            let min_value = *vals.iter().min().unwrap();
+            let amplitude = *vals.iter().max().unwrap() - min_value;
+            min_value.serialize(field_write).unwrap();
+            amplitude.serialize(field_write).unwrap();
+            let mut bit_packer = BitPacker::new();
+            for &val in vals {
+                bit_packer
+                    .write(val - min_value, compute_num_bits(amplitude), field_write)
+                    .unwrap();
+            }
+            bit_packer.close(field_write).unwrap();
+        }
+        composite_write.close().unwrap();
+        let meta_json = serde_json::to_string(&index.load_metas().unwrap())
+            .unwrap()
+            .replace(
+                &format!("\"index_format_version\":{}", crate::INDEX_FORMAT_VERSION),
+                "\"index_format_version\":1",
+            );
+        index
+            .directory_mut()
+            .atomic_write(&META_FILEPATH, meta_json.as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_upgrade_legacy_index_format() {
+        let mut schema_builder = Schema::builder();
+        let text_field = schema_builder.add_text_field("text", TEXT);
+        let num_field = schema_builder.add_u64_field("num", FAST);
+        let schema = schema_builder.build();
+        let mut index = Index::create_in_ram(schema);
+        {
+            let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+            index_writer.add_document(doc!(text_field => "a", num_field => 10u64));
+            index_writer.add_document(doc!(text_field => "b", num_field => 12u64));
+            index_writer.add_document(doc!(text_field => "a", num_field => 17u64));
+            index_writer.commit().unwrap();
+        }
+        downgrade_to_legacy_format(&mut index, num_field, &[10, 12, 17]);
+        let check_index = |index: &Index, index_format_version: u32| {
+            let segment_metas = index.searchable_segment_metas().unwrap();
+            assert_eq!(segment_metas.len(), 1);
+            assert_eq!(
+                segment_metas[0].index_format_version(),
+                index_format_version
+            );
+            let searcher = index.reader().unwrap().searcher();
+            let fast_field_reader = searcher.segment_reader(0).fast_fields().u64(num_field);
+            let vals: Vec<u64> = (0..3)
+                .map(|doc| fast_field_reader.as_ref().unwrap().get(doc))
+                .collect();
+            assert_eq!(vals, vec![10, 12, 17]);
+            let query = TermQuery::new(
+                Term::from_field_text(text_field, "a"),
+                IndexRecordOption::Basic,
+            );
+            assert_eq!(searcher.search(&query, &Count).unwrap(), 2);
+        };
+        check_index(&index, 1);
+        index.upgrade().unwrap();
+        check_index(&index, crate::INDEX_FORMAT_VERSION);
+    }
 }
@@ -1,6 +1,7 @@
 use super::SegmentComponent;
 use crate::core::SegmentId;
 use crate::schema::Schema;
+use crate::store::Compressor;
 use crate::Opstamp;
 use census::{Inventory, TrackedObject};
 use serde::{Deserialize, Serialize};
@@ -34,6 +35,8 @@ impl SegmentMetaInventory {
             segment_id,
             max_doc,
             deletes: None,
+            store_compressor: Compressor::default(),
+            index_format_version: crate::INDEX_FORMAT_VERSION,
         };
         SegmentMeta::from(self.inventory.track(inner))
     }
@@ -129,6 +132,19 @@ impl SegmentMeta {
         self.tracked.max_doc
     }
 
+    /// Returns the compressor used by the doc store of the segment.
+    pub fn store_compressor(&self) -> Compressor {
+        self.tracked.store_compressor
+    }
+
+    /// Returns the version of the index format the segment was written with.
+    ///
+    /// Segments written with an older format remain readable, and can be
+    /// rewritten in the current format using `Index::upgrade()`.
+    pub fn index_format_version(&self) -> u32 {
+        self.tracked.index_format_version
+    }
+
     /// Return the number of documents in the segment.
     pub fn num_docs(&self) -> u32 {
         self.max_doc() - self.num_deleted_docs()
@@ -160,6 +176,23 @@ impl SegmentMeta {
             segment_id: inner_meta.segment_id,
             max_doc,
             deletes: None,
+            store_compressor: inner_meta.store_compressor,
+            index_format_version: inner_meta.index_format_version,
+        });
+        SegmentMeta { tracked }
+    }
+
+    /// Sets the compressor used to write the doc store of the segment.
+    ///
+    /// This method is only used on segments that are about to be
+    /// written, either freshly created or resulting from a merge.
+    pub(crate) fn with_store_compressor(self, store_compressor: Compressor) -> SegmentMeta {
+        let tracked = self.tracked.map(move |inner_meta| InnerSegmentMeta {
+            segment_id: inner_meta.segment_id,
+            max_doc: inner_meta.max_doc,
+            deletes: inner_meta.deletes.clone(),
+            store_compressor,
+            index_format_version: inner_meta.index_format_version,
         });
         SegmentMeta { tracked }
     }
@@ -174,6 +207,8 @@ impl SegmentMeta {
             segment_id: inner_meta.segment_id,
             max_doc: inner_meta.max_doc,
             deletes: Some(delete_meta),
+            store_compressor: inner_meta.store_compressor,
+            index_format_version: inner_meta.index_format_version,
         });
         SegmentMeta { tracked }
     }
@@ -184,6 +219,16 @@ struct InnerSegmentMeta {
     segment_id: SegmentId,
     max_doc: u32,
     deletes: Option<DeleteMeta>,
+    #[serde(default)]
+    store_compressor: Compressor,
+    #[serde(default = "legacy_index_format_version")]
+    index_format_version: u32,
+}
+
+/// Segments written before the format version was recorded in
+/// `meta.json` use the first index format.
+fn legacy_index_format_version() -> u32 {
+    1
 }
 
 impl InnerSegmentMeta {
@@ -281,8 +326,10 @@ impl fmt::Debug for IndexMeta {
 #[cfg(test)]
 mod tests {
 
-    use super::IndexMeta;
+    use super::{IndexMeta, SegmentMetaInventory};
+    use crate::core::SegmentId;
     use crate::schema::{Schema, TEXT};
+    use crate::store::Compressor;
     use serde_json;
 
     #[test]
@@ -304,4 +351,36 @@ mod tests {
             r#"{"segments":[],"schema":[{"name":"text","type":"text","options":{"indexing":{"record":"position","tokenizer":"default"},"stored":false}}],"opstamp":0}"#
         );
     }
+
+    #[test]
+    fn test_deserialize_metas_store_compressor() {
+        let inventory = SegmentMetaInventory::default();
+        let meta_json = r#"{"segments":[
+            {"segment_id":"a6a3b5c5-3c43-4ac4-86d4-8ea2c0b0a8e8","max_doc":2,"deletes":null},
+            {"segment_id":"b6a3b5c5-3c43-4ac4-86d4-8ea2c0b0a8e8","max_doc":2,"deletes":null,"store_compressor":"none"}
+        ],"schema":[],"opstamp":0}"#;
+        let index_meta = IndexMeta::deserialize(meta_json, &inventory).unwrap();
+        assert_eq!(
+            index_meta.segments[0].store_compressor(),
+            Compressor::default()
+        );
+        assert_eq!(index_meta.segments[1].store_compressor(), Compressor::None);
+    }
+
+    #[test]
+    fn test_deserialize_metas_index_format_version() {
+        let inventory = SegmentMetaInventory::default();
+        let meta_json = r#"{"segments":[
+            {"segment_id":"a6a3b5c5-3c43-4ac4-86d4-8ea2c0b0a8e8","max_doc":2,"deletes":null},
+            {"segment_id":"b6a3b5c5-3c43-4ac4-86d4-8ea2c0b0a8e8","max_doc":2,"deletes":null,"index_format_version":2}
+        ],"schema":[],"opstamp":0}"#;
+        let index_meta = IndexMeta::deserialize(meta_json, &inventory).unwrap();
+        assert_eq!(index_meta.segments[0].index_format_version(), 1);
+        assert_eq!(index_meta.segments[1].index_format_version(), 2);
+        let new_segment_meta = inventory.new_segment_meta(SegmentId::generate_random(), 0);
+        assert_eq!(
+            new_segment_meta.index_format_version(),
+            crate::INDEX_FORMAT_VERSION
+        );
+    }
 }
@@ -27,6 +27,7 @@ pub struct InvertedIndexReader {
     positions_source: ReadOnlySource,
     positions_idx_source: ReadOnlySource,
     record_option: IndexRecordOption,
+    index_format_version: u32,
     total_num_tokens: u64,
 }
 
@@ -38,6 +39,7 @@ impl InvertedIndexReader {
         positions_source: ReadOnlySource,
         positions_idx_source: ReadOnlySource,
         record_option: IndexRecordOption,
+        index_format_version: u32,
     ) -> InvertedIndexReader {
         let total_num_tokens_data = postings_source.slice(0, 8);
         let mut total_num_tokens_cursor = total_num_tokens_data.as_slice();
@@ -48,6 +50,7 @@ impl InvertedIndexReader {
             positions_source,
             positions_idx_source,
             record_option,
+            index_format_version,
             total_num_tokens,
         }
     }
@@ -64,6 +67,7 @@ impl InvertedIndexReader {
             positions_source: ReadOnlySource::empty(),
             positions_idx_source: ReadOnlySource::empty(),
             record_option,
+            index_format_version: crate::INDEX_FORMAT_VERSION,
             total_num_tokens: 0u64,
         }
     }
@@ -128,6 +132,7 @@ impl InvertedIndexReader {
             postings_data,
             self.record_option,
             requested_option,
+            self.index_format_version,
         )
     }
 
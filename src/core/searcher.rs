@@ -60,6 +60,16 @@ impl Searcher {
         store_reader.get(doc_id)
     }
 
+    /// Fetches a document from tantivy's store given a `DocAddress`,
+    /// only retaining the values of the given fields.
+    ///
+    /// See [`StoreReader::get_fields`](../store/struct.StoreReader.html#method.get_fields).
+    pub fn doc_fields(&self, doc_address: DocAddress, fields: &[Field]) -> crate::Result<Document> {
+        let DocAddress(segment_local_id, doc_id) = doc_address;
+        let store_reader = &self.store_readers[segment_local_id as usize];
+        store_reader.get_fields(doc_id, fields)
+    }
+
     /// Access the schema associated to the index of this searcher.
     pub fn schema(&self) -> &Schema {
         &self.schema
@@ -84,6 +94,17 @@ impl Searcher {
             .sum::<u64>()
     }
 
+    /// Returns the overall number of tokens indexed in the given field.
+    ///
+    /// Like the term frequencies, this number includes the tokens of the
+    /// deleted documents that have not been merged away yet.
+    pub fn total_num_tokens(&self, field: Field) -> u64 {
+        self.segment_readers
+            .iter()
+            .map(|segment_reader| segment_reader.inverted_index(field).total_num_tokens())
+            .sum::<u64>()
+    }
+
     /// Return the list of segment readers
     pub fn segment_readers(&self) -> &[SegmentReader] {
         &self.segment_readers
@@ -4,16 +4,18 @@ use crate::core::InvertedIndexReader;
 use crate::core::Segment;
 use crate::core::SegmentComponent;
 use crate::core::SegmentId;
+use crate::core::SegmentMeta;
 use crate::directory::ReadOnlySource;
 use crate::fastfield::DeleteBitSet;
 use crate::fastfield::FacetReader;
 use crate::fastfield::FastFieldReaders;
+use crate::fastfield::StrFastFieldReader;
 use crate::fieldnorm::{FieldNormReader, FieldNormReaders};
 use crate::schema::Field;
 use crate::schema::FieldType;
 use crate::schema::Schema;
 use crate::space_usage::SegmentSpaceUsage;
-use crate::store::StoreReader;
+use crate::store::{Compressor, StoreReader};
 use crate::termdict::TermDictionary;
 use crate::DocId;
 use fail::fail_point;
@@ -40,6 +42,9 @@ pub struct SegmentReader {
     inv_idx_reader_cache: Arc<RwLock<HashMap<Field, Arc<InvertedIndexReader>>>>,
 
     segment_id: SegmentId,
+    // Holding the `SegmentMeta` prevents the files of the segment
+    // from being garbage collected as long as the reader is alive.
+    segment_meta: SegmentMeta,
     max_doc: DocId,
     num_docs: DocId,
 
@@ -51,6 +56,7 @@ pub struct SegmentReader {
     fieldnorm_readers: FieldNormReaders,
 
     store_source: ReadOnlySource,
+    store_compressor: Compressor,
     delete_bitset_opt: Option<DeleteBitSet>,
     schema: Schema,
 }
@@ -118,6 +124,21 @@ impl SegmentReader {
         Some(facet_reader)
     }
 
+    /// Accessor to the `StrFastFieldReader` associated to a given `Field`.
+    ///
+    /// Returns `None` if the field is not a text field declared as fast.
+    pub fn str_fast_field_reader(&self, field: Field) -> Option<StrFastFieldReader> {
+        let field_entry = self.schema.get_field_entry(field);
+        match field_entry.field_type() {
+            FieldType::Str(ref text_options) if text_options.is_fast() => {}
+            _ => return None,
+        }
+        let term_ords_reader = self.fast_fields().u64s(field)?;
+        let termdict_source = self.termdict_composite.open_read(field)?;
+        let termdict = TermDictionary::from_source(&termdict_source);
+        Some(StrFastFieldReader::new(term_ords_reader, termdict))
+    }
+
     /// Accessor to the segment's `Field norms`'s reader.
     ///
     /// Field norms are the length (in tokens) of the fields.
@@ -125,9 +146,14 @@ impl SegmentReader {
     ///
     /// They are simply stored as a fast field, serialized in
     /// the `.fieldnorm` file of the segment.
+    ///
+    /// For indexed fields that do not record field norms, the returned
+    /// reader associates a fieldnorm of 1 to all documents.
     pub fn get_fieldnorms_reader(&self, field: Field) -> FieldNormReader {
         if let Some(fieldnorm_reader) = self.fieldnorm_readers.get_field(field) {
             fieldnorm_reader
+        } else if self.schema.get_field_entry(field).is_indexed() {
+            FieldNormReader::constant(self.max_doc, 1)
         } else {
             let field_name = self.schema.get_field_name(field);
             let err_msg = format!(
@@ -140,7 +166,7 @@ impl SegmentReader {
 
     /// Accessor to the segment's `StoreReader`.
     pub fn get_store_reader(&self) -> StoreReader {
-        StoreReader::from_source(self.store_source.clone())
+        StoreReader::from_source(self.store_source.clone(), self.store_compressor)
     }
 
     /// Open a new segment for reading.
@@ -175,8 +201,11 @@ impl SegmentReader {
 
         let fast_fields_data = segment.open_read(SegmentComponent::FASTFIELDS)?;
         let fast_fields_composite = CompositeFile::open(&fast_fields_data)?;
-        let fast_field_readers =
-            Arc::new(FastFieldReaders::load_all(&schema, &fast_fields_composite)?);
+        let fast_field_readers = Arc::new(FastFieldReaders::load_all(
+            &schema,
+            &fast_fields_composite,
+            segment.meta().index_format_version(),
+        )?);
 
         let fieldnorm_data = segment.open_read(SegmentComponent::FIELDNORMS)?;
         let fieldnorm_readers = FieldNormReaders::new(fieldnorm_data)?;
@@ -197,7 +226,9 @@ impl SegmentReader {
             fast_fields_readers: fast_field_readers,
             fieldnorm_readers,
             segment_id: segment.id(),
+            segment_meta: segment.meta().clone(),
             store_source,
+            store_compressor: segment.meta().store_compressor(),
             delete_bitset_opt,
             positions_composite,
             positions_idx_composite,
@@ -263,6 +294,7 @@ impl SegmentReader {
             positions_source,
             positions_idx_source,
             record_option,
+            self.segment_meta.index_format_version(),
         ));
 
         // by releasing the lock in between, we may end up opening the inverting index
@@ -280,6 +312,11 @@ impl SegmentReader {
         self.segment_id
     }
 
+    /// Returns the meta of the segment, as of the opening of the reader.
+    pub fn segment_meta(&self) -> &SegmentMeta {
+        &self.segment_meta
+    }
+
     /// Returns the bitset representing
     /// the documents that have been deleted.
     pub fn delete_bitset(&self) -> Option<&DeleteBitSet> {
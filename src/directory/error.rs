@@ -1,3 +1,4 @@
+use crate::error::DataCorruption;
 use crate::Version;
 use std::error::Error as StdError;
 use std::fmt;
@@ -226,6 +227,9 @@ pub enum OpenReadError {
     IOError(IOError),
     /// This library doesn't support the index version found on disk
     IncompatibleIndex(Incompatibility),
+    /// The file is corrupted: its footer cannot be read, or its
+    /// content does not match its checksum.
+    DataCorruption(DataCorruption),
 }
 
 impl From<IOError> for OpenReadError {
@@ -248,6 +252,9 @@ impl fmt::Display for OpenReadError {
             OpenReadError::IncompatibleIndex(ref footer) => {
                 write!(f, "Incompatible index format: {:?}", footer)
             }
+            OpenReadError::DataCorruption(ref data_corruption) => {
+                write!(f, "{:?}", data_corruption)
+            }
         }
     }
 }
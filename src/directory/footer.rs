@@ -80,6 +80,16 @@ impl Footer {
         }
         let (body_footer, footer_len_bytes) = source.split_from_end(u32::SIZE_IN_BYTES);
         let footer_len = LittleEndian::read_u32(footer_len_bytes.as_slice()) as usize;
+        if footer_len > body_footer.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "File corrupted. The footer len ({}) exceeds the file len ({}).",
+                    footer_len,
+                    body_footer.len()
+                ),
+            ));
+        }
         let body_len = body_footer.len() - footer_len;
         let (body, footer_data) = body_footer.split(body_len);
         let mut cursor = footer_data.as_slice();
@@ -88,6 +98,9 @@ impl Footer {
     }
 
     /// Confirms that the index will be read correctly by this version of tantivy
+    ///
+    /// Files written with any index format between `MIN_INDEX_FORMAT_VERSION`
+    /// and `INDEX_FORMAT_VERSION` are supported.
     /// Has to be called after `extract_footer` to make sure it's not accessing uninitialised memory
     pub fn is_compatible(&self) -> Result<(), Incompatibility> {
         let library_version = crate::version();
@@ -96,6 +109,15 @@ impl Footer {
                 crc32: _crc,
                 store_compression: compression,
             } => {
+                let index_format_version = self.version.index_format_version;
+                if index_format_version < crate::MIN_INDEX_FORMAT_VERSION
+                    || index_format_version > crate::INDEX_FORMAT_VERSION
+                {
+                    return Err(Incompatibility::IndexMismatch {
+                        library_version: library_version.clone(),
+                        index_version: self.version.clone(),
+                    });
+                }
                 if &library_version.store_compression != compression {
                     return Err(Incompatibility::CompressionMismatch {
                         library_compression_format: library_version.store_compression.to_string(),
@@ -235,6 +257,7 @@ mod tests {
     use super::CrcHashU32;
     use super::FooterProxy;
     use crate::common::{BinarySerializable, VInt};
+    use crate::directory::error::Incompatibility;
     use crate::directory::footer::{Footer, VersionedFooter};
     use crate::directory::TerminatingWrite;
     use byteorder::{ByteOrder, LittleEndian};
@@ -351,6 +374,22 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn index_format_version_mismatch() {
+        let mut footer = Footer::new(VersionedFooter::V1 {
+            crc32: 1111111u32,
+            store_compression: crate::store::COMPRESSION.to_string(),
+        });
+        assert!(footer.is_compatible().is_ok());
+        footer.version.index_format_version = crate::MIN_INDEX_FORMAT_VERSION;
+        assert!(footer.is_compatible().is_ok());
+        footer.version.index_format_version = crate::INDEX_FORMAT_VERSION + 1;
+        assert!(matches!(
+            footer.is_compatible(),
+            Err(Incompatibility::IndexMismatch { .. })
+        ));
+    }
+
     #[test]
     fn test_deserialize_too_large_footer() {
         let mut buf = vec![];
@@ -30,6 +30,32 @@ fn is_managed(path: &Path) -> bool {
         .unwrap_or(true)
 }
 
+/// Splits a file into its footer and its content.
+///
+/// A file too short to contain its footer is reported as corrupted.
+fn extract_footer(
+    path: &Path,
+    source: ReadOnlySource,
+) -> result::Result<(Footer, ReadOnlySource), OpenReadError> {
+    Footer::extract_footer(source).map_err(|err| {
+        OpenReadError::DataCorruption(DataCorruption::new(
+            path.to_path_buf(),
+            format!("Failed to read the footer of the file: {}", err),
+        ))
+    })
+}
+
+fn has_valid_checksum(footer: &Footer, data: &ReadOnlySource) -> bool {
+    let mut hasher = Hasher::new();
+    hasher.update(data.as_slice());
+    let crc = hasher.finalize();
+    footer
+        .versioned_footer
+        .crc()
+        .map(|v| v == crc)
+        .unwrap_or(false)
+}
+
 /// Wrapper of directories that keeps track of files created by Tantivy.
 ///
 /// A managed directory is just a wrapper of a directory
@@ -43,6 +69,7 @@ fn is_managed(path: &Path) -> bool {
 pub struct ManagedDirectory {
     directory: Box<dyn Directory>,
     meta_informations: Arc<RwLock<MetaInformation>>,
+    validate_checksums: bool,
 }
 
 #[derive(Debug, Default)]
@@ -80,11 +107,13 @@ impl ManagedDirectory {
                     meta_informations: Arc::new(RwLock::new(MetaInformation {
                         managed_paths: managed_files,
                     })),
+                    validate_checksums: false,
                 })
             }
             Err(OpenReadError::FileDoesNotExist(_)) => Ok(ManagedDirectory {
                 directory: Box::new(directory),
                 meta_informations: Arc::default(),
+                validate_checksums: false,
             }),
             Err(OpenReadError::IOError(e)) => Err(From::from(e)),
             Err(OpenReadError::IncompatibleIndex(incompatibility)) => {
@@ -92,9 +121,23 @@ impl ManagedDirectory {
                 // do not have any footer and cannot detect incompatibility.
                 Err(crate::TantivyError::IncompatibleIndex(incompatibility))
             }
+            Err(OpenReadError::DataCorruption(data_corruption)) => {
+                Err(crate::TantivyError::DataCorruption(data_corruption))
+            }
         }
     }
 
+    /// Sets whether the checksum of the files should be verified
+    /// when they are opened.
+    ///
+    /// Verifying the checksum requires to read the entire file, so
+    /// it is disabled by default.
+    /// If enabled, opening a file whose content does not match its checksum
+    /// fails with an `OpenReadError::DataCorruption` error.
+    pub fn set_validate_checksums(&mut self, validate_checksums: bool) {
+        self.validate_checksums = validate_checksums;
+    }
+
     /// Garbage collect unused files.
     ///
     /// Removes the files that were created by `tantivy` and are not
@@ -231,16 +274,8 @@ impl ManagedDirectory {
     /// Verify checksum of a managed file
     pub fn validate_checksum(&self, path: &Path) -> result::Result<bool, OpenReadError> {
         let reader = self.directory.open_read(path)?;
-        let (footer, data) = Footer::extract_footer(reader)
-            .map_err(|err| IOError::with_path(path.to_path_buf(), err))?;
-        let mut hasher = Hasher::new();
-        hasher.update(data.as_slice());
-        let crc = hasher.finalize();
-        Ok(footer
-            .versioned_footer
-            .crc()
-            .map(|v| v == crc)
-            .unwrap_or(false))
+        let (footer, data) = extract_footer(path, reader)?;
+        Ok(has_valid_checksum(&footer, &data))
     }
 
     /// List files for which checksum does not match content
@@ -265,9 +300,14 @@ impl ManagedDirectory {
 impl Directory for ManagedDirectory {
     fn open_read(&self, path: &Path) -> result::Result<ReadOnlySource, OpenReadError> {
         let read_only_source = self.directory.open_read(path)?;
-        let (footer, reader) = Footer::extract_footer(read_only_source)
-            .map_err(|err| IOError::with_path(path.to_path_buf(), err))?;
+        let (footer, reader) = extract_footer(path, read_only_source)?;
         footer.is_compatible()?;
+        if self.validate_checksums && !has_valid_checksum(&footer, &reader) {
+            return Err(OpenReadError::DataCorruption(DataCorruption::new(
+                path.to_path_buf(),
+                "The checksum of the file does not match its content".to_string(),
+            )));
+        }
         Ok(reader)
     }
 
@@ -314,6 +354,7 @@ impl Clone for ManagedDirectory {
         ManagedDirectory {
             directory: self.directory.box_clone(),
             meta_informations: Arc::clone(&self.meta_informations),
+            validate_checksums: self.validate_checksums,
         }
     }
 }
@@ -322,6 +363,7 @@ impl Clone for ManagedDirectory {
 #[cfg(test)]
 mod tests_mmap_specific {
 
+    use crate::directory::error::OpenReadError;
     use crate::directory::{Directory, ManagedDirectory, MmapDirectory, TerminatingWrite};
     use std::collections::HashSet;
     use std::fs::OpenOptions;
@@ -432,4 +474,69 @@ mod tests_mmap_specific {
         assert_eq!(damaged.len(), 1);
         assert!(damaged.contains(test_path2));
     }
+
+    #[test]
+    fn test_validate_checksums_on_open() {
+        let test_path: &'static Path = Path::new("some_path_for_test");
+
+        let tempdir = TempDir::new().unwrap();
+        let tempdir_path = PathBuf::from(tempdir.path());
+
+        let mmap_directory = MmapDirectory::open(&tempdir_path).unwrap();
+        let mut managed_directory = ManagedDirectory::wrap(mmap_directory).unwrap();
+        let mut write = managed_directory.open_write(test_path).unwrap();
+        write.write_all(&[3u8, 4u8, 5u8]).unwrap();
+        write.terminate().unwrap();
+
+        let mut corrupted_path = tempdir_path.clone();
+        corrupted_path.push(test_path);
+        let mut file = OpenOptions::new()
+            .write(true)
+            .open(&corrupted_path)
+            .unwrap();
+        file.write_all(&[255u8]).unwrap();
+        file.flush().unwrap();
+        drop(file);
+
+        assert!(managed_directory.open_read(test_path).is_ok());
+        managed_directory.set_validate_checksums(true);
+        assert!(matches!(
+            managed_directory.open_read(test_path),
+            Err(OpenReadError::DataCorruption(_))
+        ));
+        assert!(matches!(
+            managed_directory.clone().open_read(test_path),
+            Err(OpenReadError::DataCorruption(_))
+        ));
+    }
+
+    #[test]
+    fn test_open_read_truncated_footer() {
+        let test_path: &'static Path = Path::new("some_path_for_test");
+
+        let tempdir = TempDir::new().unwrap();
+        let tempdir_path = PathBuf::from(tempdir.path());
+
+        let mmap_directory = MmapDirectory::open(&tempdir_path).unwrap();
+        let mut managed_directory = ManagedDirectory::wrap(mmap_directory).unwrap();
+        let mut write = managed_directory.open_write(test_path).unwrap();
+        write.write_all(&[3u8, 4u8, 5u8]).unwrap();
+        write.terminate().unwrap();
+
+        let mut truncated_path = tempdir_path.clone();
+        truncated_path.push(test_path);
+        let mut file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .open(&truncated_path)
+            .unwrap();
+        file.write_all(&[1u8, 2u8, 255u8, 255u8, 0u8, 0u8]).unwrap();
+        file.flush().unwrap();
+        drop(file);
+
+        assert!(matches!(
+            managed_directory.open_read(test_path),
+            Err(OpenReadError::DataCorruption(_))
+        ));
+    }
 }
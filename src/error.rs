@@ -134,6 +134,9 @@ impl From<OpenReadError> for TantivyError {
             OpenReadError::IncompatibleIndex(incompatibility) => {
                 TantivyError::IncompatibleIndex(incompatibility)
             }
+            OpenReadError::DataCorruption(data_corruption) => {
+                TantivyError::DataCorruption(data_corruption)
+            }
         }
     }
 }
@@ -6,13 +6,13 @@ pub use self::writer::BytesFastFieldWriter;
 
 #[cfg(test)]
 mod tests {
-    use crate::schema::Schema;
-    use crate::Index;
+    use crate::schema::{Schema, FAST, STORED};
+    use crate::{DocAddress, Index};
 
     #[test]
     fn test_bytes() {
         let mut schema_builder = Schema::builder();
-        let field = schema_builder.add_bytes_field("bytesfield");
+        let field = schema_builder.add_bytes_field("bytesfield", FAST);
         let schema = schema_builder.build();
         let index = Index::create_in_ram(schema);
         let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
@@ -33,4 +33,23 @@ mod tests {
         let long = vec![0u8; 1000];
         assert_eq!(bytes_reader.get_bytes(4), long.as_slice());
     }
+
+    #[test]
+    fn test_stored_bytes() {
+        let mut schema_builder = Schema::builder();
+        let field = schema_builder.add_bytes_field("bytesfield", STORED);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.add_document(doc!(field=>vec![0u8, 1, 2, 3]));
+        assert!(index_writer.commit().is_ok());
+        let searcher = index.reader().unwrap().searcher();
+        let segment_reader = searcher.segment_reader(0);
+        assert!(segment_reader.fast_fields().bytes(field).is_none());
+        let doc = searcher.doc(DocAddress(0u32, 0u32)).unwrap();
+        assert_eq!(
+            doc.get_first(field).unwrap().bytes_value(),
+            Some(&[0u8, 1, 2, 3][..])
+        );
+    }
 }
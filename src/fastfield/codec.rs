@@ -0,0 +1,419 @@
+use crate::common::bitpacker::{BitPacker, BitUnpacker};
+use crate::common::compute_num_bits;
+use crate::common::minmax;
+use crate::common::BinarySerializable;
+use crate::directory::ReadOnlySource;
+use owning_ref::OwningRef;
+use std::io::{self, Write};
+
+type FastFieldData = BitUnpacker<OwningRef<ReadOnlySource, [u8]>>;
+
+/// Codec used to encode the values of a fast field.
+///
+/// The serializer estimates the size of the column with each of the
+/// codecs, and picks the smallest one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FastFieldCodec {
+    /// Bitpacks `val - min_value`.
+    Bitpacked,
+    /// Bitpacks `(val - min_value) / gcd`, where `gcd` is the greatest
+    /// common divisor of all of the `val - min_value`.
+    ///
+    /// Timestamps with a second or day precision typically share a large gcd.
+    Gcd,
+    /// Bitpacks the difference between the values and the line going from the
+    /// first to the last value.
+    ///
+    /// Monotonic values (e.g. ids, or timestamps of documents indexed in order)
+    /// are close to this line.
+    LinearInterpolation,
+}
+
+const CODECS: [FastFieldCodec; 3] = [
+    FastFieldCodec::Bitpacked,
+    FastFieldCodec::Gcd,
+    FastFieldCodec::LinearInterpolation,
+];
+
+/// Length of the header shared by all codecs: codec id, min value and amplitude.
+const COMMON_HEADER_LEN: usize = 1 + 8 + 8;
+
+impl FastFieldCodec {
+    fn id(self) -> u8 {
+        match self {
+            FastFieldCodec::Bitpacked => 0,
+            FastFieldCodec::Gcd => 1,
+            FastFieldCodec::LinearInterpolation => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<FastFieldCodec> {
+        CODECS.iter().cloned().find(|codec| codec.id() == id)
+    }
+
+    /// Returns the codec producing the smallest column for the given values.
+    pub fn select(vals: &[u64], min_value: u64, max_value: u64) -> FastFieldCodec {
+        CODECS
+            .iter()
+            .filter_map(|&codec| {
+                codec
+                    .estimate_num_bytes(vals, min_value, max_value)
+                    .map(|num_bytes| (num_bytes, codec))
+            })
+            .min_by_key(|&(num_bytes, _)| num_bytes)
+            .map(|(_, codec)| codec)
+            .unwrap_or(FastFieldCodec::Bitpacked)
+    }
+
+    /// Returns the number of bytes required to encode the values
+    /// (header excluded), or `None` if the codec cannot encode them.
+    pub fn estimate_num_bytes(self, vals: &[u64], min_value: u64, max_value: u64) -> Option<u64> {
+        let num_vals = vals.len() as u64;
+        match self {
+            FastFieldCodec::Bitpacked => Some(bitpacked_num_bytes(
+                num_vals,
+                compute_num_bits(max_value - min_value),
+            )),
+            FastFieldCodec::Gcd => {
+                let gcd = compute_gcd(vals, min_value)?;
+                let num_bits = compute_num_bits((max_value - min_value) / gcd);
+                Some(8 + bitpacked_num_bytes(num_vals, num_bits))
+            }
+            FastFieldCodec::LinearInterpolation => {
+                let line = Line::fit(vals)?;
+                let (_, num_bits) = line.offset_and_num_bits(vals)?;
+                Some(8 * 4 + 1 + bitpacked_num_bytes(num_vals, num_bits))
+            }
+        }
+    }
+
+    /// Serializes the values using the codec.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the codec cannot encode the values.
+    pub fn serialize<W: Write>(
+        self,
+        write: &mut W,
+        vals: &[u64],
+        min_value: u64,
+        max_value: u64,
+    ) -> io::Result<()> {
+        assert!(min_value <= max_value);
+        self.id().serialize(write)?;
+        min_value.serialize(write)?;
+        let amplitude = max_value - min_value;
+        amplitude.serialize(write)?;
+        let mut bit_packer = BitPacker::new();
+        match self {
+            FastFieldCodec::Bitpacked => {
+                let num_bits = compute_num_bits(amplitude);
+                for &val in vals {
+                    bit_packer.write(val - min_value, num_bits, write)?;
+                }
+            }
+            FastFieldCodec::Gcd => {
+                let gcd = compute_gcd(vals, min_value).expect("Gcd codec not applicable");
+                gcd.serialize(write)?;
+                let num_bits = compute_num_bits(amplitude / gcd);
+                for &val in vals {
+                    bit_packer.write((val - min_value) / gcd, num_bits, write)?;
+                }
+            }
+            FastFieldCodec::LinearInterpolation => {
+                let line = Line::fit(vals).expect("Linear interpolation codec not applicable");
+                let (offset, num_bits) = line
+                    .offset_and_num_bits(vals)
+                    .expect("Linear interpolation codec not applicable");
+                line.first_val.serialize(write)?;
+                line.last_val.serialize(write)?;
+                line.num_vals.serialize(write)?;
+                offset.serialize(write)?;
+                num_bits.serialize(write)?;
+                for (idx, &val) in vals.iter().enumerate() {
+                    let residual = i128::from(val) - line.predict(idx as u64) + i128::from(offset);
+                    bit_packer.write(residual as u64, num_bits, write)?;
+                }
+            }
+        }
+        bit_packer.close(write)
+    }
+}
+
+/// Serializes a column, using the codec producing the smallest output.
+pub(crate) fn serialize_column<W: Write>(write: &mut W, vals: &[u64]) -> io::Result<()> {
+    let (min_value, max_value) = minmax(vals.iter().cloned()).unwrap_or((0, 0));
+    let codec = FastFieldCodec::select(vals, min_value, max_value);
+    codec.serialize(write, vals, min_value, max_value)
+}
+
+/// Returns the number of bytes `serialize_column` would write for the given values.
+pub(crate) fn column_num_bytes(vals: &[u64]) -> u64 {
+    let (min_value, max_value) = minmax(vals.iter().cloned()).unwrap_or((0, 0));
+    let codec = FastFieldCodec::select(vals, min_value, max_value);
+    let num_bytes = codec
+        .estimate_num_bytes(vals, min_value, max_value)
+        .expect("The selected codec is always applicable");
+    COMMON_HEADER_LEN as u64 + num_bytes
+}
+
+fn bitpacked_num_bytes(num_vals: u64, num_bits: u8) -> u64 {
+    // The bitpacker pads the data with 7 bytes.
+    (num_vals * u64::from(num_bits) + 7) / 8 + 7
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Computes the gcd of the `val - min_value`.
+///
+/// Returns `None` if the gcd is not useful, i.e. if it is `0` or `1`.
+fn compute_gcd(vals: &[u64], min_value: u64) -> Option<u64> {
+    let mut current_gcd = 0u64;
+    for &val in vals {
+        current_gcd = gcd(current_gcd, val - min_value);
+        if current_gcd == 1 {
+            return None;
+        }
+    }
+    if current_gcd == 0 {
+        None
+    } else {
+        Some(current_gcd)
+    }
+}
+
+/// Line going from the first to the last value of a column.
+#[derive(Clone)]
+pub(crate) struct Line {
+    first_val: u64,
+    last_val: u64,
+    num_vals: u64,
+}
+
+impl Line {
+    fn fit(vals: &[u64]) -> Option<Line> {
+        if vals.len() < 2 {
+            return None;
+        }
+        Some(Line {
+            first_val: vals[0],
+            last_val: vals[vals.len() - 1],
+            num_vals: vals.len() as u64,
+        })
+    }
+
+    /// Integer arithmetic makes sure the serializer and the reader
+    /// agree on the predicted values.
+    fn predict(&self, idx: u64) -> i128 {
+        let delta = i128::from(self.last_val) - i128::from(self.first_val);
+        i128::from(self.first_val) + delta * i128::from(idx) / i128::from(self.num_vals - 1)
+    }
+
+    /// Returns the offset to add to the residuals to make them positive,
+    /// and the number of bits required to bitpack the shifted residuals.
+    ///
+    /// Returns `None` if the shifted residuals do not fit in a `u64`.
+    fn offset_and_num_bits(&self, vals: &[u64]) -> Option<(u64, u8)> {
+        let mut min_residual = 0i128;
+        let mut max_residual = 0i128;
+        for (idx, &val) in vals.iter().enumerate() {
+            let residual = i128::from(val) - self.predict(idx as u64);
+            min_residual = min_residual.min(residual);
+            max_residual = max_residual.max(residual);
+        }
+        let offset = -min_residual;
+        let amplitude = max_residual + offset;
+        if offset > i128::from(u64::max_value()) || amplitude > i128::from(u64::max_value()) {
+            return None;
+        }
+        Some((offset as u64, compute_num_bits(amplitude as u64)))
+    }
+}
+
+/// Decodes the values of a fast field, whatever its codec.
+#[derive(Clone)]
+pub(crate) enum FastFieldCodecReader {
+    Bitpacked {
+        min_value: u64,
+        data: FastFieldData,
+    },
+    Gcd {
+        min_value: u64,
+        gcd: u64,
+        data: FastFieldData,
+    },
+    LinearInterpolation {
+        line: Line,
+        offset: u64,
+        data: FastFieldData,
+    },
+}
+
+impl FastFieldCodecReader {
+    /// Opens a fast field, and returns its reader together with its
+    /// min value and its max value.
+    pub fn open(source: ReadOnlySource) -> io::Result<(FastFieldCodecReader, u64, u64)> {
+        let mut cursor = source.as_slice();
+        let codec_id = u8::deserialize(&mut cursor)?;
+        let min_value = u64::deserialize(&mut cursor)?;
+        let amplitude = u64::deserialize(&mut cursor)?;
+        let max_value = min_value + amplitude;
+        let codec = FastFieldCodec::from_id(codec_id).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unknown fast field codec {}", codec_id),
+            )
+        })?;
+        let open_data = |header_len: usize, num_bits: u8| {
+            let owning_ref = OwningRef::new(source.clone()).map(|data| &data[header_len..]);
+            BitUnpacker::new(owning_ref, num_bits)
+        };
+        let reader = match codec {
+            FastFieldCodec::Bitpacked => FastFieldCodecReader::Bitpacked {
+                min_value,
+                data: open_data(COMMON_HEADER_LEN, compute_num_bits(amplitude)),
+            },
+            FastFieldCodec::Gcd => {
+                let gcd = u64::deserialize(&mut cursor)?;
+                FastFieldCodecReader::Gcd {
+                    min_value,
+                    gcd,
+                    data: open_data(COMMON_HEADER_LEN + 8, compute_num_bits(amplitude / gcd)),
+                }
+            }
+            FastFieldCodec::LinearInterpolation => {
+                let first_val = u64::deserialize(&mut cursor)?;
+                let last_val = u64::deserialize(&mut cursor)?;
+                let num_vals = u64::deserialize(&mut cursor)?;
+                let offset = u64::deserialize(&mut cursor)?;
+                let num_bits = u8::deserialize(&mut cursor)?;
+                FastFieldCodecReader::LinearInterpolation {
+                    line: Line {
+                        first_val,
+                        last_val,
+                        num_vals,
+                    },
+                    offset,
+                    data: open_data(COMMON_HEADER_LEN + 8 * 4 + 1, num_bits),
+                }
+            }
+        };
+        Ok((reader, min_value, max_value))
+    }
+
+    /// Opens a fast field written with the first version of the index format,
+    /// which bitpacks `val - min_value` after a header without any codec id.
+    pub fn open_legacy(source: ReadOnlySource) -> io::Result<(FastFieldCodecReader, u64, u64)> {
+        let mut cursor = source.as_slice();
+        let min_value = u64::deserialize(&mut cursor)?;
+        let amplitude = u64::deserialize(&mut cursor)?;
+        let owning_ref = OwningRef::new(source).map(|data| &data[16..]);
+        let reader = FastFieldCodecReader::Bitpacked {
+            min_value,
+            data: BitUnpacker::new(owning_ref, compute_num_bits(amplitude)),
+        };
+        Ok((reader, min_value, min_value + amplitude))
+    }
+
+    /// Returns the value at the given index.
+    pub fn get(&self, idx: u64) -> u64 {
+        match *self {
+            FastFieldCodecReader::Bitpacked {
+                min_value,
+                ref data,
+            } => min_value + data.get(idx),
+            FastFieldCodecReader::Gcd {
+                min_value,
+                gcd,
+                ref data,
+            } => min_value + gcd * data.get(idx),
+            FastFieldCodecReader::LinearInterpolation {
+                ref line,
+                offset,
+                ref data,
+            } => (line.predict(idx) + i128::from(data.get(idx)) - i128::from(offset)) as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FastFieldCodec, FastFieldCodecReader};
+    use crate::directory::ReadOnlySource;
+
+    fn min_max(vals: &[u64]) -> (u64, u64) {
+        let min_value = vals.iter().cloned().min().unwrap_or(0);
+        let max_value = vals.iter().cloned().max().unwrap_or(0);
+        (min_value, max_value)
+    }
+
+    fn serialize_and_read(codec: FastFieldCodec, vals: &[u64]) -> usize {
+        let (min_value, max_value) = min_max(vals);
+        let mut buffer = Vec::new();
+        codec
+            .serialize(&mut buffer, vals, min_value, max_value)
+            .unwrap();
+        let num_bytes = buffer.len();
+        let estimate = codec
+            .estimate_num_bytes(vals, min_value, max_value)
+            .unwrap();
+        assert_eq!(num_bytes as u64, super::COMMON_HEADER_LEN as u64 + estimate);
+        let (reader, reader_min_value, reader_max_value) =
+            FastFieldCodecReader::open(ReadOnlySource::from(buffer)).unwrap();
+        assert_eq!(reader_min_value, min_value);
+        assert_eq!(reader_max_value, max_value);
+        for (idx, &val) in vals.iter().enumerate() {
+            assert_eq!(reader.get(idx as u64), val);
+        }
+        num_bytes
+    }
+
+    #[test]
+    fn test_codecs_roundtrip() {
+        let datasets: Vec<Vec<u64>> = vec![
+            vec![13, 14, 2],
+            vec![1_000, 3_000, 2_000, 10_000],
+            (0..1_000u64).map(|i| 1_500_000_000 + i * 60).collect(),
+            (0..1_000u64).map(|i| 5_000 - i * 3 + i % 7).collect(),
+            vec![u64::max_value(), 0, u64::max_value() / 2],
+            vec![0, u64::max_value()],
+        ];
+        for vals in &datasets {
+            let (min_value, max_value) = min_max(vals);
+            for &codec in &super::CODECS {
+                if codec
+                    .estimate_num_bytes(vals, min_value, max_value)
+                    .is_some()
+                {
+                    serialize_and_read(codec, vals);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codec_selection() {
+        let select = |vals: &[u64]| {
+            let (min_value, max_value) = min_max(vals);
+            FastFieldCodec::select(vals, min_value, max_value)
+        };
+        assert_eq!(select(&[]), FastFieldCodec::Bitpacked);
+        assert_eq!(select(&[13, 14, 2]), FastFieldCodec::Bitpacked);
+        let timestamps: Vec<u64> = (0..1_000u64)
+            .map(|i| 1_500_000_000 + (i * 7_919 % 1_000) * 86_400)
+            .collect();
+        assert_eq!(select(&timestamps), FastFieldCodec::Gcd);
+        let ids: Vec<u64> = (0..1_000u64).map(|i| 1_000_000 + i * 3 + i % 2).collect();
+        assert_eq!(select(&ids), FastFieldCodec::LinearInterpolation);
+        let linear_num_bytes = serialize_and_read(FastFieldCodec::LinearInterpolation, &ids);
+        let bitpacked_num_bytes = serialize_and_read(FastFieldCodec::Bitpacked, &ids);
+        assert!(linear_num_bytes * 5 < bitpacked_num_bytes);
+    }
+}
@@ -32,6 +32,7 @@ pub use self::multivalued::{MultiValueIntFastFieldReader, MultiValueIntFastField
 pub use self::reader::FastFieldReader;
 pub use self::readers::FastFieldReaders;
 pub use self::serializer::FastFieldSerializer;
+pub use self::str_reader::StrFastFieldReader;
 pub use self::writer::{FastFieldsWriter, IntFastFieldWriter};
 use crate::chrono::{NaiveDateTime, Utc};
 use crate::common;
@@ -40,16 +41,19 @@ use crate::schema::FieldType;
 use crate::schema::Value;
 
 mod bytes;
+mod codec;
 mod delete;
 mod error;
 mod facet_reader;
 mod multivalued;
+mod presence;
 mod reader;
 mod readers;
 mod serializer;
+mod str_reader;
 mod writer;
 
-/// Trait for types that are allowed for fast fields: (u64, i64 and f64).
+/// Trait for types that are allowed for fast fields: (u64, i64, f64, dates and bool).
 pub trait FastValue: Clone + Copy + Send + Sync + PartialOrd {
     /// Converts a value from u64
     ///
@@ -164,13 +168,39 @@ impl FastValue for crate::DateTime {
     }
 }
 
+impl FastValue for bool {
+    fn from_u64(val: u64) -> Self {
+        val != 0u64
+    }
+
+    fn to_u64(&self) -> u64 {
+        u64::from(*self)
+    }
+
+    fn fast_field_cardinality(field_type: &FieldType) -> Option<Cardinality> {
+        match *field_type {
+            FieldType::Bool(ref integer_options) => integer_options.get_fastfield_cardinality(),
+            _ => None,
+        }
+    }
+
+    fn as_u64(&self) -> u64 {
+        self.to_u64()
+    }
+
+    fn make_zero() -> Self {
+        false
+    }
+}
+
 fn value_to_u64(value: &Value) -> u64 {
     match *value {
         Value::U64(ref val) => *val,
         Value::I64(ref val) => common::i64_to_u64(*val),
         Value::F64(ref val) => common::f64_to_u64(*val),
         Value::Date(ref datetime) => common::i64_to_u64(datetime.timestamp()),
-        _ => panic!("Expected a u64/i64/f64 field, got {:?} ", value),
+        Value::Bool(ref val) => val.to_u64(),
+        _ => panic!("Expected a u64/i64/f64/bool field, got {:?} ", value),
     }
 }
 
@@ -234,7 +264,7 @@ mod tests {
         }
         let source = directory.open_read(&path).unwrap();
         {
-            assert_eq!(source.len(), 36 as usize);
+            assert_eq!(source.len(), 37 as usize);
         }
         {
             let composite_file = CompositeFile::open(&source).unwrap();
@@ -270,7 +300,7 @@ mod tests {
         }
         let source = directory.open_read(&path).unwrap();
         {
-            assert_eq!(source.len(), 61 as usize);
+            assert_eq!(source.len(), 62 as usize);
         }
         {
             let fast_fields_composite = CompositeFile::open(&source).unwrap();
@@ -307,7 +337,7 @@ mod tests {
         }
         let source = directory.open_read(&path).unwrap();
         {
-            assert_eq!(source.len(), 34 as usize);
+            assert_eq!(source.len(), 35 as usize);
         }
         {
             let fast_fields_composite = CompositeFile::open(&source).unwrap();
@@ -340,7 +370,7 @@ mod tests {
         }
         let source = directory.open_read(&path).unwrap();
         {
-            assert_eq!(source.len(), 80042 as usize);
+            assert_eq!(source.len(), 80043 as usize);
         }
         {
             let fast_fields_composite = CompositeFile::open(&source).unwrap();
@@ -380,7 +410,8 @@ mod tests {
         }
         let source = directory.open_read(&path).unwrap();
         {
-            assert_eq!(source.len(), 17709 as usize);
+            // The values are monotonic, and get encoded using the linear interpolation codec.
+            assert_eq!(source.len(), 68 as usize);
         }
         {
             let fast_fields_composite = CompositeFile::open(&source).unwrap();
@@ -495,6 +526,46 @@ mod tests {
         assert_eq!(reader.searcher().segment_readers().len(), 1);
     }
 
+    #[test]
+    fn test_fastfield_missing_values() {
+        let mut schema_builder = Schema::builder();
+        let field = schema_builder.add_u64_field("field", FAST);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.set_merge_policy(Box::new(NoMergePolicy));
+        index_writer.add_document(doc!(field=>0u64));
+        index_writer.add_document(doc!());
+        index_writer.add_document(doc!(field=>3u64));
+        index_writer.commit().unwrap();
+        index_writer.add_document(doc!(field=>4u64));
+        index_writer.commit().unwrap();
+        let reader = index.reader().unwrap();
+        let searcher = reader.searcher();
+        assert_eq!(searcher.segment_readers().len(), 2);
+        let fast_field_reader = searcher.segment_reader(0).fast_fields().u64(field).unwrap();
+        assert!(fast_field_reader.has_value(0));
+        assert!(!fast_field_reader.has_value(1));
+        assert!(fast_field_reader.has_value(2));
+        assert_eq!(fast_field_reader.get(0), 0u64);
+        assert_eq!(fast_field_reader.get(1), 0u64);
+        assert_eq!(fast_field_reader.get(2), 3u64);
+        let segment_ids: Vec<SegmentId> = searcher
+            .segment_readers()
+            .iter()
+            .map(SegmentReader::segment_id)
+            .collect();
+        assert!(futures::executor::block_on(index_writer.merge(&segment_ids[..])).is_ok());
+        assert!(reader.reload().is_ok());
+        let searcher = reader.searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        let fast_field_reader = searcher.segment_reader(0).fast_fields().u64(field).unwrap();
+        let has_values: Vec<bool> = (0..4).map(|doc| fast_field_reader.has_value(doc)).collect();
+        assert_eq!(has_values, vec![true, false, true, true]);
+        let vals: Vec<u64> = (0..4).map(|doc| fast_field_reader.get(doc)).collect();
+        assert_eq!(vals, vec![0u64, 0u64, 3u64, 4u64]);
+    }
+
     #[test]
     fn test_default_datetime() {
         assert_eq!(crate::DateTime::make_zero().timestamp(), 0i64);
@@ -554,6 +625,122 @@ mod tests {
             assert_eq!(dates[1].timestamp(), 6i64);
         }
     }
+
+    #[test]
+    fn test_boolfastfield() {
+        use crate::collector::Count;
+        use crate::query::TermQuery;
+        use crate::schema::{IndexRecordOption, INDEXED, STORED};
+        use crate::Term;
+        let mut schema_builder = Schema::builder();
+        let bool_field = schema_builder.add_bool_field("bool", INDEXED | STORED | FAST);
+        let multi_bool_field = schema_builder.add_bool_field(
+            "multi_bool",
+            IntOptions::default().set_fast(Cardinality::MultiValues),
+        );
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.set_merge_policy(Box::new(NoMergePolicy));
+        index_writer.add_document(doc!(
+            bool_field => true,
+            multi_bool_field => false,
+            multi_bool_field => true
+        ));
+        index_writer.add_document(doc!(bool_field => false));
+        index_writer.commit().unwrap();
+        index_writer.add_document(doc!(bool_field => true));
+        index_writer.commit().unwrap();
+        let segment_ids = index.searchable_segment_ids().unwrap();
+        futures::executor::block_on(index_writer.merge(&segment_ids)).unwrap();
+        index_writer.wait_merging_threads().unwrap();
+        let reader = index.reader().unwrap();
+        let searcher = reader.searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        let count_bool = |val: bool| {
+            let query = TermQuery::new(
+                Term::from_field_bool(bool_field, val),
+                IndexRecordOption::Basic,
+            );
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count_bool(true), 2);
+        assert_eq!(count_bool(false), 1);
+        let fast_fields = searcher.segment_reader(0).fast_fields();
+        let bool_fast_field = fast_fields.bool(bool_field).unwrap();
+        assert!(bool_fast_field.get(0u32));
+        assert!(!bool_fast_field.get(1u32));
+        assert!(bool_fast_field.get(2u32));
+        let bools_fast_field = fast_fields.bools(multi_bool_field).unwrap();
+        let mut bools = vec![];
+        bools_fast_field.get_vals(0u32, &mut bools);
+        assert_eq!(&bools, &[false, true]);
+        bools_fast_field.get_vals(1u32, &mut bools);
+        assert!(bools.is_empty());
+        let doc = searcher.doc(crate::DocAddress(0u32, 1u32)).unwrap();
+        assert_eq!(
+            doc.get_first(bool_field).map(Value::bool_value),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_str_fastfield() {
+        use crate::schema::STRING;
+        let mut schema_builder = Schema::builder();
+        let tag_field = schema_builder.add_text_field("tag", STRING | FAST);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.set_merge_policy(Box::new(NoMergePolicy));
+        index_writer.add_document(doc!(tag_field => "red", tag_field => "blue"));
+        index_writer.add_document(doc!());
+        index_writer.commit().unwrap();
+        index_writer.add_document(doc!(tag_field => "green"));
+        index_writer.add_document(doc!(tag_field => "blue"));
+        index_writer.commit().unwrap();
+        let read_tags = |index: &Index| {
+            let searcher = index.reader().unwrap().searcher();
+            let mut tags_per_doc = vec![];
+            for segment_reader in searcher.segment_readers() {
+                let mut str_reader = segment_reader.str_fast_field_reader(tag_field).unwrap();
+                let mut term_ords = vec![];
+                for doc in segment_reader.doc_ids_alive() {
+                    str_reader.term_ords(doc, &mut term_ords);
+                    let mut tags = vec![];
+                    for &term_ord in &term_ords {
+                        let mut tag = String::new();
+                        str_reader.ord_to_str(term_ord, &mut tag).unwrap();
+                        tags.push(tag);
+                    }
+                    tags_per_doc.push(tags);
+                }
+            }
+            tags_per_doc.sort();
+            tags_per_doc
+        };
+        let expected = vec![
+            vec![],
+            vec!["blue".to_string()],
+            vec!["blue".to_string(), "red".to_string()],
+            vec!["green".to_string()],
+        ];
+        assert_eq!(read_tags(&index), expected);
+        let segment_ids = index.searchable_segment_ids().unwrap();
+        futures::executor::block_on(index_writer.merge(&segment_ids)).unwrap();
+        index_writer.wait_merging_threads().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        assert_eq!(
+            searcher
+                .segment_reader(0)
+                .str_fast_field_reader(tag_field)
+                .unwrap()
+                .num_terms(),
+            3
+        );
+        assert_eq!(read_tags(&index), expected);
+    }
 }
 
 #[cfg(all(test, feature = "unstable"))]
@@ -7,14 +7,17 @@ pub use self::writer::MultiValueIntFastFieldWriter;
 #[cfg(test)]
 mod tests {
 
-    use crate::collector::TopDocs;
+    use crate::collector::{Count, FacetCollector, TopDocs};
+    use crate::query::AllQuery;
     use crate::query::QueryParser;
     use crate::schema::Cardinality;
     use crate::schema::Facet;
     use crate::schema::IntOptions;
     use crate::schema::Schema;
+    use crate::schema::{STORED, STRING};
     use crate::Index;
-    use chrono::Duration;
+    use chrono::{Duration, TimeZone};
+    use futures::executor::block_on;
 
     #[test]
     fn test_multivalued_u64() {
@@ -214,6 +217,111 @@ mod tests {
             assert_eq!(&vals, &[-5i64, -20i64, 1i64]);
         }
     }
+    #[test]
+    fn test_multivalued_end_to_end_with_merge() {
+        let mut schema_builder = Schema::builder();
+        let tags = schema_builder.add_text_field("tags", STRING | STORED);
+        let scores = schema_builder.add_u64_field(
+            "scores",
+            IntOptions::default()
+                .set_fast(Cardinality::MultiValues)
+                .set_stored(),
+        );
+        let dates = schema_builder.add_date_field(
+            "dates",
+            IntOptions::default()
+                .set_fast(Cardinality::MultiValues)
+                .set_indexed(),
+        );
+        let category = schema_builder.add_facet_field("category");
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let now = chrono::Utc.timestamp(1_500_000_000, 0);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.add_document(doc!(
+            tags => "rust",
+            tags => "search",
+            scores => 3u64,
+            scores => 1u64,
+            dates => now,
+            dates => now + Duration::days(1),
+            category => Facet::from("/lang/rust"),
+            category => Facet::from("/topic/search"),
+        ));
+        index_writer.commit().unwrap();
+        index_writer.add_document(doc!(
+            tags => "search",
+            scores => 2u64,
+            dates => now + Duration::days(2),
+            category => Facet::from("/topic/search"),
+        ));
+        index_writer.add_document(doc!());
+        index_writer.commit().unwrap();
+        let segment_ids = index.searchable_segment_ids().unwrap();
+        block_on(index_writer.merge(&segment_ids)).unwrap();
+        index_writer.wait_merging_threads().unwrap();
+
+        let reader = index.reader().unwrap();
+        let searcher = reader.searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        let count = |query: &str| {
+            let query_parser = QueryParser::for_index(&index, vec![]);
+            let query = query_parser.parse_query(query).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count("tags:rust"), 1);
+        assert_eq!(count("tags:search"), 2);
+        assert_eq!(count("dates:\"2017-07-15T02:40:00Z\""), 1);
+        assert_eq!(count("dates:\"2017-07-16T02:40:00Z\""), 1);
+
+        let query_parser = QueryParser::for_index(&index, vec![]);
+        let query = query_parser.parse_query("tags:rust").unwrap();
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(1)).unwrap();
+        let doc_address = top_docs[0].1;
+        let doc = searcher.doc(doc_address).unwrap();
+        let stored_tags: Vec<&str> = doc
+            .get_all(tags)
+            .iter()
+            .flat_map(|val| val.text())
+            .collect();
+        assert_eq!(stored_tags, vec!["rust", "search"]);
+        let stored_scores: Vec<u64> = doc
+            .get_all(scores)
+            .iter()
+            .map(|val| val.u64_value())
+            .collect();
+        assert_eq!(stored_scores, vec![3u64, 1u64]);
+
+        let fast_fields = searcher.segment_reader(0).fast_fields();
+        let mut vals = Vec::new();
+        let scores_reader = fast_fields.u64s(scores).unwrap();
+        scores_reader.get_vals(doc_address.doc(), &mut vals);
+        assert_eq!(&vals, &[3u64, 1u64]);
+        let mut all_vals = Vec::new();
+        for doc in 0..3 {
+            scores_reader.get_vals(doc, &mut vals);
+            all_vals.push(vals.clone());
+        }
+        all_vals.sort();
+        assert_eq!(all_vals, vec![vec![], vec![2u64], vec![3u64, 1u64]]);
+        let mut date_vals = Vec::new();
+        let dates_reader = fast_fields.dates(dates).unwrap();
+        dates_reader.get_vals(doc_address.doc(), &mut date_vals);
+        assert_eq!(&date_vals, &[now, now + Duration::days(1)]);
+
+        let mut facet_collector = FacetCollector::for_field(category);
+        facet_collector.add_facet("/");
+        let facet_counts = searcher.search(&AllQuery, &facet_collector).unwrap();
+        let facets: Vec<(String, u64)> = facet_counts
+            .get("/")
+            .map(|(facet, count)| (facet.to_string(), count))
+            .collect();
+        assert_eq!(
+            facets,
+            vec![("/lang".to_string(), 1), ("/topic".to_string(), 2)]
+        );
+    }
+
     #[test]
     #[ignore]
     fn test_many_facets() {
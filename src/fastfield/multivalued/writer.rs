@@ -66,7 +66,7 @@ impl MultiValueIntFastFieldWriter {
     /// all of the matching field values present in the document.
     pub fn add_document(&mut self, doc: &Document) {
         self.next_doc();
-        // facets are indexed in the `SegmentWriter` as we encode their unordered id.
+        // facets and text fields are indexed in the `SegmentWriter` as we encode their unordered id.
         if !self.is_facet {
             for field_value in doc.field_values() {
                 if field_value.field() == self.field {
@@ -90,7 +90,7 @@ impl MultiValueIntFastFieldWriter {
     /// Serializes fast field values by pushing them to the `FastFieldSerializer`.
     ///
     /// If a mapping is given, the values are remapped *and sorted* before serialization.
-    /// This is used when serializing `facets`. Specifically their terms are
+    /// This is used when serializing `facets` and text fast fields. Specifically their terms are
     /// first stored in the writer as their position in the `IndexWriter`'s `HashMap`.
     /// This value is called an `UnorderedTermId`.
     ///
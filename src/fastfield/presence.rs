@@ -0,0 +1,220 @@
+use crate::common::BinarySerializable;
+use crate::directory::ReadOnlySource;
+use crate::fastfield::codec::{column_num_bytes, serialize_column, FastFieldCodecReader};
+use crate::DocId;
+use std::io::{self, Write};
+
+/// Id written in place of the codec id for the columns of fast fields
+/// that are missing on some of the documents.
+///
+/// Such a column is laid out as follows
+/// `[OPTIONAL_COLUMN_ID][min value][amplitude][presence kind][val if missing]`
+/// `[num docs with value][presence column num bytes][presence column][values column]`
+/// where the presence column and the values column are regular columns.
+pub(crate) const OPTIONAL_COLUMN_ID: u8 = 128;
+
+const DENSE_PRESENCE: u8 = 0;
+const SPARSE_PRESENCE: u8 = 1;
+
+/// Keeps track of the documents having a value in an optional fast field.
+#[derive(Clone)]
+pub(crate) enum Presence {
+    /// The presence column holds one bit per document.
+    ///
+    /// The values column holds one value per document, the value of
+    /// the documents without any value being `val_if_missing`.
+    Dense { bitmap: FastFieldCodecReader },
+    /// The presence column holds the sorted list of the documents having
+    /// a value, and the values column only holds their values.
+    Sparse {
+        doc_ids: FastFieldCodecReader,
+        num_docs_with_value: u64,
+        val_if_missing: u64,
+    },
+}
+
+impl Presence {
+    /// Returns true if the document has a value.
+    pub fn contains(&self, doc: u64) -> bool {
+        match *self {
+            Presence::Dense { ref bitmap } => bitmap.get(doc) == 1,
+            Presence::Sparse {
+                ref doc_ids,
+                num_docs_with_value,
+                ..
+            } => find_doc(doc_ids, num_docs_with_value, doc).is_some(),
+        }
+    }
+
+    /// Returns the value associated to the document.
+    pub fn get(&self, values: &FastFieldCodecReader, doc: u64) -> u64 {
+        match *self {
+            Presence::Dense { .. } => values.get(doc),
+            Presence::Sparse {
+                ref doc_ids,
+                num_docs_with_value,
+                val_if_missing,
+            } => find_doc(doc_ids, num_docs_with_value, doc)
+                .map(|idx| values.get(idx))
+                .unwrap_or(val_if_missing),
+        }
+    }
+}
+
+/// Binary search of the document within the sorted list of documents.
+fn find_doc(doc_ids: &FastFieldCodecReader, num_docs: u64, doc: u64) -> Option<u64> {
+    let mut start = 0u64;
+    let mut end = num_docs;
+    while start < end {
+        let mid = start + (end - start) / 2;
+        let mid_doc = doc_ids.get(mid);
+        if mid_doc < doc {
+            start = mid + 1;
+        } else if mid_doc > doc {
+            end = mid;
+        } else {
+            return Some(mid);
+        }
+    }
+    None
+}
+
+/// Serializes the column of a fast field that is missing on some of the documents.
+///
+/// `vals` contains one value per document, and `missing_docs` the sorted list
+/// of the documents without any value. The presence is stored either as a bitmap
+/// or as the list of the documents having a value, whichever is the smallest.
+pub(crate) fn serialize_optional_column<W: Write>(
+    write: &mut W,
+    vals: &[u64],
+    missing_docs: &[DocId],
+    val_if_missing: u64,
+    min_value: u64,
+    max_value: u64,
+) -> io::Result<()> {
+    let mut bitmap = vec![1u64; vals.len()];
+    for &doc in missing_docs {
+        bitmap[doc as usize] = 0u64;
+    }
+    let doc_ids: Vec<u64> = (0..vals.len() as u64)
+        .filter(|&doc| bitmap[doc as usize] == 1)
+        .collect();
+    let sparse_vals: Vec<u64> = doc_ids.iter().map(|&doc| vals[doc as usize]).collect();
+    let dense_num_bytes = column_num_bytes(&bitmap) + column_num_bytes(vals);
+    let sparse_num_bytes = column_num_bytes(&doc_ids) + column_num_bytes(&sparse_vals);
+    let (presence_kind, presence_column, values_column) = if sparse_num_bytes < dense_num_bytes {
+        (SPARSE_PRESENCE, &doc_ids[..], &sparse_vals[..])
+    } else {
+        (DENSE_PRESENCE, &bitmap[..], vals)
+    };
+    let mut presence_buffer = Vec::new();
+    serialize_column(&mut presence_buffer, presence_column)?;
+
+    OPTIONAL_COLUMN_ID.serialize(write)?;
+    min_value.serialize(write)?;
+    (max_value - min_value).serialize(write)?;
+    presence_kind.serialize(write)?;
+    val_if_missing.serialize(write)?;
+    (doc_ids.len() as u64).serialize(write)?;
+    (presence_buffer.len() as u64).serialize(write)?;
+    write.write_all(&presence_buffer)?;
+    serialize_column(write, values_column)
+}
+
+/// Opens a column serialized with `serialize_optional_column`, and returns
+/// its presence, the reader of its values, its min value and its max value.
+pub(crate) fn open_optional_column(
+    source: ReadOnlySource,
+) -> io::Result<(Presence, FastFieldCodecReader, u64, u64)> {
+    let mut cursor = source.as_slice();
+    let column_id = u8::deserialize(&mut cursor)?;
+    assert_eq!(column_id, OPTIONAL_COLUMN_ID);
+    let min_value = u64::deserialize(&mut cursor)?;
+    let amplitude = u64::deserialize(&mut cursor)?;
+    let presence_kind = u8::deserialize(&mut cursor)?;
+    let val_if_missing = u64::deserialize(&mut cursor)?;
+    let num_docs_with_value = u64::deserialize(&mut cursor)?;
+    let presence_num_bytes = u64::deserialize(&mut cursor)? as usize;
+    let presence_start = source.len() - cursor.len();
+    let values_start = presence_start + presence_num_bytes;
+    let (presence_reader, _, _) =
+        FastFieldCodecReader::open(source.slice(presence_start, values_start))?;
+    let (values_reader, _, _) = FastFieldCodecReader::open(source.slice_from(values_start))?;
+    let presence = match presence_kind {
+        DENSE_PRESENCE => Presence::Dense {
+            bitmap: presence_reader,
+        },
+        SPARSE_PRESENCE => Presence::Sparse {
+            doc_ids: presence_reader,
+            num_docs_with_value,
+            val_if_missing,
+        },
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unknown fast field presence kind {}", presence_kind),
+            ));
+        }
+    };
+    Ok((presence, values_reader, min_value, min_value + amplitude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{open_optional_column, serialize_optional_column, Presence};
+    use crate::directory::ReadOnlySource;
+
+    fn serialize_and_read(vals: &[u64], missing_docs: &[u32]) -> (Presence, usize) {
+        let mut buffer = Vec::new();
+        serialize_optional_column(&mut buffer, vals, missing_docs, 0u64, 0u64, 1_000u64).unwrap();
+        let num_bytes = buffer.len();
+        let (presence, values, min_value, max_value) =
+            open_optional_column(ReadOnlySource::from(buffer)).unwrap();
+        assert_eq!(min_value, 0u64);
+        assert_eq!(max_value, 1_000u64);
+        for (doc, &val) in vals.iter().enumerate() {
+            let doc = doc as u64;
+            assert_eq!(
+                presence.contains(doc),
+                !missing_docs.contains(&(doc as u32))
+            );
+            assert_eq!(presence.get(&values, doc), val);
+        }
+        (presence, num_bytes)
+    }
+
+    #[test]
+    fn test_optional_column_dense() {
+        let is_missing = |doc: u32| (doc * 7_919) % 97 < 40;
+        let vals: Vec<u64> = (0..1_000u32)
+            .map(|doc| {
+                if is_missing(doc) {
+                    0
+                } else {
+                    u64::from(doc % 2)
+                }
+            })
+            .collect();
+        let missing_docs: Vec<u32> = (0..1_000u32).filter(|&doc| is_missing(doc)).collect();
+        let (presence, _) = serialize_and_read(&vals, &missing_docs);
+        assert!(matches!(presence, Presence::Dense { .. }));
+    }
+
+    #[test]
+    fn test_optional_column_sparse() {
+        let vals: Vec<u64> = (0..10_000u64)
+            .map(|doc| if doc % 1_000 == 3 { doc / 10 } else { 0 })
+            .collect();
+        let missing_docs: Vec<u32> = (0..10_000u32).filter(|doc| doc % 1_000 != 3).collect();
+        let (presence, num_bytes) = serialize_and_read(&vals, &missing_docs);
+        assert!(matches!(presence, Presence::Sparse { .. }));
+        assert!(num_bytes < 200);
+    }
+
+    #[test]
+    fn test_optional_column_all_missing() {
+        let vals = vec![0u64; 100];
+        let missing_docs: Vec<u32> = (0..100u32).collect();
+        serialize_and_read(&vals, &missing_docs);
+    }
+}
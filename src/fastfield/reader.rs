@@ -1,15 +1,13 @@
 use super::FastValue;
-use crate::common::bitpacker::BitUnpacker;
-use crate::common::compute_num_bits;
-use crate::common::BinarySerializable;
 use crate::common::CompositeFile;
 use crate::directory::ReadOnlySource;
 use crate::directory::{Directory, RAMDirectory, WritePtr};
+use crate::fastfield::codec::FastFieldCodecReader;
+use crate::fastfield::presence::{open_optional_column, Presence, OPTIONAL_COLUMN_ID};
 use crate::fastfield::{FastFieldSerializer, FastFieldsWriter};
 use crate::schema::Schema;
 use crate::schema::FAST;
 use crate::DocId;
-use owning_ref::OwningRef;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::path::Path;
@@ -20,7 +18,8 @@ use std::path::Path;
 /// fast field is required.
 #[derive(Clone)]
 pub struct FastFieldReader<Item: FastValue> {
-    bit_unpacker: BitUnpacker<OwningRef<ReadOnlySource, [u8]>>,
+    codec_reader: FastFieldCodecReader,
+    presence: Option<Presence>,
     min_value_u64: u64,
     max_value_u64: u64,
     _phantom: PhantomData<Item>,
@@ -29,30 +28,40 @@ pub struct FastFieldReader<Item: FastValue> {
 impl<Item: FastValue> FastFieldReader<Item> {
     /// Opens a fast field given a source.
     pub fn open(data: ReadOnlySource) -> Self {
-        let min_value: u64;
-        let amplitude: u64;
-        {
-            let mut cursor = data.as_slice();
-            min_value =
-                u64::deserialize(&mut cursor).expect("Failed to read the min_value of fast field.");
-            amplitude =
-                u64::deserialize(&mut cursor).expect("Failed to read the amplitude of fast field.");
-        }
-        let max_value = min_value + amplitude;
-        let num_bits = compute_num_bits(amplitude);
-        let owning_ref = OwningRef::new(data).map(|data| &data[16..]);
-        let bit_unpacker = BitUnpacker::new(owning_ref, num_bits);
+        FastFieldReader::open_with_format_version(data, crate::INDEX_FORMAT_VERSION)
+    }
+
+    /// Opens a fast field written with the given version of the index format.
+    pub(crate) fn open_with_format_version(
+        data: ReadOnlySource,
+        index_format_version: u32,
+    ) -> Self {
+        let (presence, codec_reader, min_value, max_value) = if index_format_version < 2 {
+            let (codec_reader, min_value, max_value) = FastFieldCodecReader::open_legacy(data)
+                .expect("Failed to read the header of fast field.");
+            (None, codec_reader, min_value, max_value)
+        } else if data.as_slice().first() == Some(&OPTIONAL_COLUMN_ID) {
+            let (presence, codec_reader, min_value, max_value) =
+                open_optional_column(data).expect("Failed to read the header of fast field.");
+            (Some(presence), codec_reader, min_value, max_value)
+        } else {
+            let (codec_reader, min_value, max_value) =
+                FastFieldCodecReader::open(data).expect("Failed to read the header of fast field.");
+            (None, codec_reader, min_value, max_value)
+        };
         FastFieldReader {
             min_value_u64: min_value,
             max_value_u64: max_value,
-            bit_unpacker,
+            codec_reader,
+            presence,
             _phantom: PhantomData,
         }
     }
 
     pub(crate) fn into_u64_reader(self) -> FastFieldReader<u64> {
         FastFieldReader {
-            bit_unpacker: self.bit_unpacker,
+            codec_reader: self.codec_reader,
+            presence: self.presence,
             min_value_u64: self.min_value_u64,
             max_value_u64: self.max_value_u64,
             _phantom: PhantomData,
@@ -72,7 +81,28 @@ impl<Item: FastValue> FastFieldReader<Item> {
     }
 
     pub(crate) fn get_u64(&self, doc: u64) -> Item {
-        Item::from_u64(self.min_value_u64 + self.bit_unpacker.get(doc))
+        let val = match self.presence {
+            Some(ref presence) => presence.get(&self.codec_reader, doc),
+            None => self.codec_reader.get(doc),
+        };
+        Item::from_u64(val)
+    }
+
+    /// Returns true if the document has a value for this fast field.
+    ///
+    /// Documents without any value are associated to the default
+    /// value of the field by `.get(...)`. This method makes it possible
+    /// to tell them apart from documents that actually have the default value.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `doc` is greater than the segment
+    // `maxdoc`.
+    pub fn has_value(&self, doc: DocId) -> bool {
+        match self.presence {
+            Some(ref presence) => presence.contains(u64::from(doc)),
+            None => true,
+        }
     }
 
     /// Internally `multivalued` also use SingleValue Fast fields.
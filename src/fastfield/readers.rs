@@ -15,10 +15,12 @@ pub struct FastFieldReaders {
     fast_field_u64: HashMap<Field, FastFieldReader<u64>>,
     fast_field_f64: HashMap<Field, FastFieldReader<f64>>,
     fast_field_date: HashMap<Field, FastFieldReader<crate::DateTime>>,
+    fast_field_bool: HashMap<Field, FastFieldReader<bool>>,
     fast_field_i64s: HashMap<Field, MultiValueIntFastFieldReader<i64>>,
     fast_field_u64s: HashMap<Field, MultiValueIntFastFieldReader<u64>>,
     fast_field_f64s: HashMap<Field, MultiValueIntFastFieldReader<f64>>,
     fast_field_dates: HashMap<Field, MultiValueIntFastFieldReader<crate::DateTime>>,
+    fast_field_bools: HashMap<Field, MultiValueIntFastFieldReader<bool>>,
     fast_bytes: HashMap<Field, BytesFastFieldReader>,
     fast_fields_composite: CompositeFile,
 }
@@ -28,6 +30,7 @@ enum FastType {
     U64,
     F64,
     Date,
+    Bool,
 }
 
 fn type_and_cardinality(field_type: &FieldType) -> Option<(FastType, Cardinality)> {
@@ -44,7 +47,13 @@ fn type_and_cardinality(field_type: &FieldType) -> Option<(FastType, Cardinality
         FieldType::Date(options) => options
             .get_fastfield_cardinality()
             .map(|cardinality| (FastType::Date, cardinality)),
+        FieldType::Bool(options) => options
+            .get_fastfield_cardinality()
+            .map(|cardinality| (FastType::Bool, cardinality)),
         FieldType::HierarchicalFacet => Some((FastType::U64, Cardinality::MultiValues)),
+        FieldType::Str(options) if options.is_fast() => {
+            Some((FastType::U64, Cardinality::MultiValues))
+        }
         _ => None,
     }
 }
@@ -53,26 +62,34 @@ impl FastFieldReaders {
     pub(crate) fn load_all(
         schema: &Schema,
         fast_fields_composite: &CompositeFile,
+        index_format_version: u32,
     ) -> crate::Result<FastFieldReaders> {
+        let open_u64 =
+            |data| FastFieldReader::<u64>::open_with_format_version(data, index_format_version);
         let mut fast_field_readers = FastFieldReaders {
             fast_field_i64: Default::default(),
             fast_field_u64: Default::default(),
             fast_field_f64: Default::default(),
             fast_field_date: Default::default(),
+            fast_field_bool: Default::default(),
             fast_field_i64s: Default::default(),
             fast_field_u64s: Default::default(),
             fast_field_f64s: Default::default(),
             fast_field_dates: Default::default(),
+            fast_field_bools: Default::default(),
             fast_bytes: Default::default(),
             fast_fields_composite: fast_fields_composite.clone(),
         };
         for (field, field_entry) in schema.fields() {
             let field_type = field_entry.field_type();
-            if field_type == &FieldType::Bytes {
+            if let FieldType::Bytes(ref bytes_options) = field_type {
+                if !bytes_options.is_fast() {
+                    continue;
+                }
                 let idx_reader = fast_fields_composite
                     .open_read_with_idx(field, 0)
                     .ok_or_else(|| FastFieldNotAvailableError::new(field_entry))
-                    .map(FastFieldReader::open)?;
+                    .map(open_u64)?;
                 let data = fast_fields_composite
                     .open_read_with_idx(field, 1)
                     .ok_or_else(|| FastFieldNotAvailableError::new(field_entry))?;
@@ -85,7 +102,11 @@ impl FastFieldReaders {
                         if let Some(fast_field_data) = fast_fields_composite.open_read(field) {
                             match fast_type {
                                 FastType::U64 => {
-                                    let fast_field_reader = FastFieldReader::open(fast_field_data);
+                                    let fast_field_reader =
+                                        FastFieldReader::open_with_format_version(
+                                            fast_field_data,
+                                            index_format_version,
+                                        );
                                     fast_field_readers
                                         .fast_field_u64
                                         .insert(field, fast_field_reader);
@@ -93,19 +114,37 @@ impl FastFieldReaders {
                                 FastType::I64 => {
                                     fast_field_readers.fast_field_i64.insert(
                                         field,
-                                        FastFieldReader::open(fast_field_data.clone()),
+                                        FastFieldReader::open_with_format_version(
+                                            fast_field_data.clone(),
+                                            index_format_version,
+                                        ),
                                     );
                                 }
                                 FastType::F64 => {
                                     fast_field_readers.fast_field_f64.insert(
                                         field,
-                                        FastFieldReader::open(fast_field_data.clone()),
+                                        FastFieldReader::open_with_format_version(
+                                            fast_field_data.clone(),
+                                            index_format_version,
+                                        ),
                                     );
                                 }
                                 FastType::Date => {
                                     fast_field_readers.fast_field_date.insert(
                                         field,
-                                        FastFieldReader::open(fast_field_data.clone()),
+                                        FastFieldReader::open_with_format_version(
+                                            fast_field_data.clone(),
+                                            index_format_version,
+                                        ),
+                                    );
+                                }
+                                FastType::Bool => {
+                                    fast_field_readers.fast_field_bool.insert(
+                                        field,
+                                        FastFieldReader::open_with_format_version(
+                                            fast_field_data.clone(),
+                                            index_format_version,
+                                        ),
                                     );
                                 }
                             }
@@ -117,10 +156,13 @@ impl FastFieldReaders {
                         let idx_opt = fast_fields_composite.open_read_with_idx(field, 0);
                         let data_opt = fast_fields_composite.open_read_with_idx(field, 1);
                         if let (Some(fast_field_idx), Some(fast_field_data)) = (idx_opt, data_opt) {
-                            let idx_reader = FastFieldReader::open(fast_field_idx);
+                            let idx_reader = open_u64(fast_field_idx);
                             match fast_type {
                                 FastType::I64 => {
-                                    let vals_reader = FastFieldReader::open(fast_field_data);
+                                    let vals_reader = FastFieldReader::open_with_format_version(
+                                        fast_field_data,
+                                        index_format_version,
+                                    );
                                     let multivalued_int_fast_field =
                                         MultiValueIntFastFieldReader::open(idx_reader, vals_reader);
                                     fast_field_readers
@@ -128,7 +170,10 @@ impl FastFieldReaders {
                                         .insert(field, multivalued_int_fast_field);
                                 }
                                 FastType::U64 => {
-                                    let vals_reader = FastFieldReader::open(fast_field_data);
+                                    let vals_reader = FastFieldReader::open_with_format_version(
+                                        fast_field_data,
+                                        index_format_version,
+                                    );
                                     let multivalued_int_fast_field =
                                         MultiValueIntFastFieldReader::open(idx_reader, vals_reader);
                                     fast_field_readers
@@ -136,7 +181,10 @@ impl FastFieldReaders {
                                         .insert(field, multivalued_int_fast_field);
                                 }
                                 FastType::F64 => {
-                                    let vals_reader = FastFieldReader::open(fast_field_data);
+                                    let vals_reader = FastFieldReader::open_with_format_version(
+                                        fast_field_data,
+                                        index_format_version,
+                                    );
                                     let multivalued_int_fast_field =
                                         MultiValueIntFastFieldReader::open(idx_reader, vals_reader);
                                     fast_field_readers
@@ -144,13 +192,27 @@ impl FastFieldReaders {
                                         .insert(field, multivalued_int_fast_field);
                                 }
                                 FastType::Date => {
-                                    let vals_reader = FastFieldReader::open(fast_field_data);
+                                    let vals_reader = FastFieldReader::open_with_format_version(
+                                        fast_field_data,
+                                        index_format_version,
+                                    );
                                     let multivalued_int_fast_field =
                                         MultiValueIntFastFieldReader::open(idx_reader, vals_reader);
                                     fast_field_readers
                                         .fast_field_dates
                                         .insert(field, multivalued_int_fast_field);
                                 }
+                                FastType::Bool => {
+                                    let vals_reader = FastFieldReader::open_with_format_version(
+                                        fast_field_data,
+                                        index_format_version,
+                                    );
+                                    let multivalued_int_fast_field =
+                                        MultiValueIntFastFieldReader::open(idx_reader, vals_reader);
+                                    fast_field_readers
+                                        .fast_field_bools
+                                        .insert(field, multivalued_int_fast_field);
+                                }
                             }
                         } else {
                             return Err(From::from(FastFieldNotAvailableError::new(field_entry)));
@@ -191,6 +253,9 @@ impl FastFieldReaders {
         if let Some(date_ff_reader) = self.date(field) {
             return Some(date_ff_reader.into_u64_reader());
         }
+        if let Some(bool_ff_reader) = self.bool(field) {
+            return Some(bool_ff_reader.into_u64_reader());
+        }
         None
     }
 
@@ -215,6 +280,13 @@ impl FastFieldReaders {
         self.fast_field_f64.get(&field).cloned()
     }
 
+    /// Returns the `bool` fast field reader reader associated to `field`.
+    ///
+    /// If `field` is not a bool fast field, this method returns `None`.
+    pub fn bool(&self, field: Field) -> Option<FastFieldReader<bool>> {
+        self.fast_field_bool.get(&field).cloned()
+    }
+
     /// Returns a `u64s` multi-valued fast field reader reader associated to `field`.
     ///
     /// If `field` is not a u64 multi-valued fast field, this method returns `None`.
@@ -237,6 +309,12 @@ impl FastFieldReaders {
         if let Some(f64s_ff_reader) = self.f64s(field) {
             return Some(f64s_ff_reader.into_u64s_reader());
         }
+        if let Some(dates_ff_reader) = self.dates(field) {
+            return Some(dates_ff_reader.into_u64s_reader());
+        }
+        if let Some(bools_ff_reader) = self.bools(field) {
+            return Some(bools_ff_reader.into_u64s_reader());
+        }
         None
     }
 
@@ -261,6 +339,13 @@ impl FastFieldReaders {
         self.fast_field_dates.get(&field).cloned()
     }
 
+    /// Returns a `bool` multi-valued fast field reader reader associated to `field`.
+    ///
+    /// If `field` is not a bool multi-valued fast field, this method returns `None`.
+    pub fn bools(&self, field: Field) -> Option<MultiValueIntFastFieldReader<bool>> {
+        self.fast_field_bools.get(&field).cloned()
+    }
+
     /// Returns the `bytes` fast field reader associated to `field`.
     ///
     /// If `field` is not a bytes fast field, returns `None`.
@@ -1,16 +1,24 @@
-use crate::common::bitpacker::BitPacker;
-use crate::common::compute_num_bits;
-use crate::common::BinarySerializable;
 use crate::common::CompositeWrite;
 use crate::common::CountingWriter;
 use crate::directory::WritePtr;
+use crate::fastfield::codec::FastFieldCodec;
+use crate::fastfield::presence::serialize_optional_column;
 use crate::schema::Field;
+use crate::DocId;
 use std::io::{self, Write};
 
 /// `FastFieldSerializer` is in charge of serializing
 /// fastfields on disk.
 ///
-/// Fast fields are encoded using bit-packing.
+/// Fast fields are encoded using bit-packing. Depending on the
+/// values, the bitpacked numbers are either the values themselves
+/// (minus the minimum value), the values divided by their greatest
+/// common divisor, or their distance to a line going through the first
+/// and the last value. The serializer picks whichever is the smallest.
+///
+/// If some of the documents do not have any value, the serializer
+/// also records which documents have a value, so that they can be
+/// told apart from the documents having the default value.
 ///
 /// `FastFieldWriter`s are in charge of pushing the data to
 /// the serializer.
@@ -80,50 +88,71 @@ impl FastFieldSerializer {
 }
 
 pub struct FastSingleFieldSerializer<'a, W: Write> {
-    bit_packer: BitPacker,
     write: &'a mut W,
     min_value: u64,
-    num_bits: u8,
+    max_value: u64,
+    vals: Vec<u64>,
+    missing_docs: Vec<DocId>,
+    val_if_missing: u64,
 }
 
 impl<'a, W: Write> FastSingleFieldSerializer<'a, W> {
     /// Creates a new fast field serializer.
     ///
-    /// The serializer in fact encode the values by bitpacking
-    /// `(val - min_value)`.
+    /// It requires a `min_value` and a `max_value`, that
+    /// bound all of the values of the fast field.
     ///
-    /// It requires a `min_value` and a `max_value` to compute
-    /// compute the minimum number of bits required to encode
-    /// values.
+    /// The values are buffered, as picking the codec requires
+    /// to look at all of them. They are only written when the field
+    /// is closed.
     fn open(
         write: &'a mut W,
         min_value: u64,
         max_value: u64,
     ) -> io::Result<FastSingleFieldSerializer<'a, W>> {
         assert!(min_value <= max_value);
-        min_value.serialize(write)?;
-        let amplitude = max_value - min_value;
-        amplitude.serialize(write)?;
-        let num_bits = compute_num_bits(amplitude);
-        let bit_packer = BitPacker::new();
         Ok(FastSingleFieldSerializer {
             write,
-            bit_packer,
             min_value,
-            num_bits,
+            max_value,
+            vals: Vec::new(),
+            missing_docs: Vec::new(),
+            val_if_missing: 0u64,
         })
     }
 
     /// Pushes a new value to the currently open u64 fast field.
     pub fn add_val(&mut self, val: u64) -> io::Result<()> {
-        let val_to_write: u64 = val - self.min_value;
-        self.bit_packer
-            .write(val_to_write, self.num_bits, &mut self.write)?;
+        debug_assert!(self.min_value <= val && val <= self.max_value);
+        self.vals.push(val);
         Ok(())
     }
 
-    pub fn close_field(mut self) -> io::Result<()> {
-        self.bit_packer.close(&mut self.write)
+    /// Pushes the value of a document that does not have any value
+    /// to the currently open u64 fast field.
+    ///
+    /// `val_if_missing` is expected to be the same for all of the documents
+    /// of the field.
+    pub fn add_missing_val(&mut self, val_if_missing: u64) -> io::Result<()> {
+        debug_assert!(self.missing_docs.is_empty() || self.val_if_missing == val_if_missing);
+        self.missing_docs.push(self.vals.len() as DocId);
+        self.val_if_missing = val_if_missing;
+        self.add_val(val_if_missing)
+    }
+
+    pub fn close_field(self) -> io::Result<()> {
+        if !self.missing_docs.is_empty() {
+            return serialize_optional_column(
+                self.write,
+                &self.vals,
+                &self.missing_docs,
+                self.val_if_missing,
+                self.min_value,
+                self.max_value,
+            );
+        }
+        let codec = FastFieldCodec::select(&self.vals, self.min_value, self.max_value);
+        codec.serialize(self.write, &self.vals, self.min_value, self.max_value)
     }
 }
 
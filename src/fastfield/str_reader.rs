@@ -0,0 +1,67 @@
+use super::MultiValueIntFastFieldReader;
+use crate::termdict::TermDictionary;
+use crate::termdict::TermOrdinal;
+use crate::DocId;
+use std::str;
+
+/// The str fast field reader makes it possible to access the
+/// terms of a text fast field associated to a given document
+/// in a specific segment.
+///
+/// Like for the `FacetReader`, terms are exposed in the form of
+/// term ordinals, which are their position in the sorted term
+/// dictionary of the segment. Term ordinals are segment local.
+///
+/// A term ordinal can then be translated into its text via
+/// `.ord_to_str(...)`.
+pub struct StrFastFieldReader {
+    term_ords: MultiValueIntFastFieldReader<u64>,
+    term_dict: TermDictionary,
+    buffer: Vec<u8>,
+}
+
+impl StrFastFieldReader {
+    /// Creates a new `StrFastFieldReader`.
+    pub fn new(
+        term_ords: MultiValueIntFastFieldReader<u64>,
+        term_dict: TermDictionary,
+    ) -> StrFastFieldReader {
+        StrFastFieldReader {
+            term_ords,
+            term_dict,
+            buffer: vec![],
+        }
+    }
+
+    /// Returns the number of terms in the segment.
+    /// This does not take in account the documents that may be marked
+    /// as deleted.
+    ///
+    /// Term ordinals range from `0` to `num_terms() - 1`.
+    pub fn num_terms(&self) -> usize {
+        self.term_dict.num_terms()
+    }
+
+    /// Accessor for the term dictionary.
+    pub fn term_dict(&self) -> &TermDictionary {
+        &self.term_dict
+    }
+
+    /// Given a term ordinal, writes the text associated to it into `output`.
+    pub fn ord_to_str(
+        &mut self,
+        term_ord: TermOrdinal,
+        output: &mut String,
+    ) -> Result<(), str::Utf8Error> {
+        let found_term = self.term_dict.ord_to_term(term_ord, &mut self.buffer);
+        assert!(found_term, "Term ordinal {} no found.", term_ord);
+        output.clear();
+        output.push_str(str::from_utf8(&self.buffer[..])?);
+        Ok(())
+    }
+
+    /// Return the sorted list of term ordinals associated to a document.
+    pub fn term_ords(&self, doc: DocId, output: &mut Vec<TermOrdinal>) {
+        self.term_ords.get_vals(doc, output);
+    }
+}
@@ -6,6 +6,7 @@ use crate::fastfield::{BytesFastFieldWriter, FastFieldSerializer};
 use crate::postings::UnorderedTermId;
 use crate::schema::{Cardinality, Document, Field, FieldEntry, FieldType, Schema};
 use crate::termdict::TermOrdinal;
+use crate::DocId;
 use fnv::FnvHashMap;
 use std::collections::HashMap;
 use std::io;
@@ -37,7 +38,8 @@ impl FastFieldsWriter {
                 FieldType::I64(ref int_options)
                 | FieldType::U64(ref int_options)
                 | FieldType::F64(ref int_options)
-                | FieldType::Date(ref int_options) => {
+                | FieldType::Date(ref int_options)
+                | FieldType::Bool(ref int_options) => {
                     match int_options.get_fastfield_cardinality() {
                         Some(Cardinality::SingleValue) => {
                             let mut fast_field_writer = IntFastFieldWriter::new(field);
@@ -56,9 +58,17 @@ impl FastFieldsWriter {
                     let fast_field_writer = MultiValueIntFastFieldWriter::new(field, true);
                     multi_values_writers.push(fast_field_writer);
                 }
-                FieldType::Bytes => {
-                    let fast_field_writer = BytesFastFieldWriter::new(field);
-                    bytes_value_writers.push(fast_field_writer);
+                FieldType::Str(ref text_options) => {
+                    if text_options.is_fast() {
+                        let fast_field_writer = MultiValueIntFastFieldWriter::new(field, true);
+                        multi_values_writers.push(fast_field_writer);
+                    }
+                }
+                FieldType::Bytes(ref bytes_options) => {
+                    if bytes_options.is_fast() {
+                        let fast_field_writer = BytesFastFieldWriter::new(field);
+                        bytes_value_writers.push(fast_field_writer);
+                    }
                 }
                 _ => {}
             }
@@ -157,6 +167,7 @@ pub struct IntFastFieldWriter {
     vals: Vec<u8>,
     val_count: usize,
     val_if_missing: u64,
+    missing_docs: Vec<DocId>,
     val_min: u64,
     val_max: u64,
 }
@@ -169,6 +180,7 @@ impl IntFastFieldWriter {
             vals: Vec::new(),
             val_count: 0,
             val_if_missing: 0u64,
+            missing_docs: Vec::new(),
             val_min: u64::max_value(),
             val_max: 0,
         }
@@ -213,22 +225,26 @@ impl IntFastFieldWriter {
     /// i64 and f64 are remapped to u64 using the logic
     /// in `common::i64_to_u64` and `common::f64_to_u64`.
     ///
-    /// If the value is missing, then the default value is used
-    /// instead.
     /// If the document has more than one value for the given field,
     /// only the first one is taken in account.
-    fn extract_val(&self, doc: &Document) -> u64 {
-        match doc.get_first(self.field) {
-            Some(v) => super::value_to_u64(v),
-            None => self.val_if_missing,
-        }
+    fn extract_val(&self, doc: &Document) -> Option<u64> {
+        doc.get_first(self.field).map(super::value_to_u64)
     }
 
     /// Extract the fast field value from the document
     /// (or use the default value) and records it.
+    ///
+    /// Documents without any value are recorded as such,
+    /// so that readers can tell them apart from documents
+    /// having the default value.
     pub fn add_document(&mut self, doc: &Document) {
-        let val = self.extract_val(doc);
-        self.add_val(val);
+        match self.extract_val(doc) {
+            Some(val) => self.add_val(val),
+            None => {
+                self.missing_docs.push(self.val_count as DocId);
+                self.add_val(self.val_if_missing);
+            }
+        }
     }
 
     /// Push the fast fields value to the `FastFieldWriter`.
@@ -241,9 +257,17 @@ impl IntFastFieldWriter {
 
         let mut single_field_serializer = serializer.new_u64_fast_field(self.field, min, max)?;
 
+        let mut missing_docs = self.missing_docs.iter().cloned().peekable();
         let mut cursor = self.vals.as_slice();
+        let mut doc: DocId = 0;
         while let Ok(VInt(val)) = VInt::deserialize(&mut cursor) {
-            single_field_serializer.add_val(val)?;
+            if missing_docs.peek() == Some(&doc) {
+                missing_docs.next();
+                single_field_serializer.add_missing_val(val)?;
+            } else {
+                single_field_serializer.add_val(val)?;
+            }
+            doc += 1;
         }
 
         single_field_serializer.close_field()
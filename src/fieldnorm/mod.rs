@@ -26,3 +26,55 @@ pub use self::serializer::FieldNormsSerializer;
 pub use self::writer::FieldNormsWriter;
 
 use self::code::{fieldnorm_to_id, id_to_fieldnorm};
+
+#[cfg(test)]
+mod tests {
+    use crate::collector::TopDocs;
+    use crate::query::TermQuery;
+    use crate::schema::{IndexRecordOption, Schema, TextFieldIndexing, TextOptions};
+    use crate::{Index, Term};
+
+    #[test]
+    fn test_fieldnorms_disabled() {
+        let mut schema_builder = Schema::builder();
+        let text_options = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_index_option(IndexRecordOption::WithFreqs)
+                .set_fieldnorms(false),
+        );
+        let text = schema_builder.add_text_field("text", text_options);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let query = TermQuery::new(
+            Term::from_field_text(text, "a"),
+            IndexRecordOption::WithFreqs,
+        );
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.add_document(doc!(text=>"a"));
+        index_writer.add_document(doc!(text=>"a b c d e f g"));
+        index_writer.add_document(doc!(text=>"z"));
+        index_writer.commit().unwrap();
+        let reader = index.reader().unwrap();
+        {
+            let searcher = reader.searcher();
+            let fieldnorm_reader = searcher.segment_reader(0).get_fieldnorms_reader(text);
+            assert_eq!(fieldnorm_reader.fieldnorm(0), 1);
+            assert_eq!(fieldnorm_reader.fieldnorm(1), 1);
+            let top_docs = searcher.search(&query, &TopDocs::with_limit(3)).unwrap();
+            assert_eq!(top_docs.len(), 2);
+            assert!((top_docs[0].0 - top_docs[1].0).abs() < 1e-5);
+        }
+        index_writer.delete_term(Term::from_field_text(text, "z"));
+        index_writer.add_document(doc!(text=>"a z"));
+        index_writer.commit().unwrap();
+        let segment_ids = index.searchable_segment_ids().unwrap();
+        futures::executor::block_on(index_writer.merge(&segment_ids)).unwrap();
+        index_writer.wait_merging_threads().unwrap();
+        reader.reload().unwrap();
+        let searcher = reader.searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(3)).unwrap();
+        assert_eq!(top_docs.len(), 3);
+        assert!((top_docs[0].0 - top_docs[2].0).abs() < 1e-5);
+    }
+}
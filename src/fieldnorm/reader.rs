@@ -56,18 +56,42 @@ impl FieldNormReaders {
 /// in a very short array.
 #[derive(Clone)]
 pub struct FieldNormReader {
-    data: ReadOnlySource,
+    data: FieldNormData,
+}
+
+#[derive(Clone)]
+enum FieldNormData {
+    Source(ReadOnlySource),
+    Constant { num_docs: u32, fieldnorm_id: u8 },
 }
 
 impl FieldNormReader {
     /// Opens a field norm reader given its data source.
     pub fn open(data: ReadOnlySource) -> Self {
-        FieldNormReader { data }
+        FieldNormReader {
+            data: FieldNormData::Source(data),
+        }
+    }
+
+    /// Creates a field norm reader returning the same `fieldnorm`
+    /// for all of the documents.
+    ///
+    /// This reader is used for the fields that do not record field norms.
+    pub fn constant(num_docs: u32, fieldnorm: u32) -> Self {
+        FieldNormReader {
+            data: FieldNormData::Constant {
+                num_docs,
+                fieldnorm_id: fieldnorm_to_id(fieldnorm),
+            },
+        }
     }
 
     /// Returns the number of documents in this segment.
     pub fn num_docs(&self) -> u32 {
-        self.data.len() as u32
+        match self.data {
+            FieldNormData::Source(ref data) => data.len() as u32,
+            FieldNormData::Constant { num_docs, .. } => num_docs,
+        }
     }
 
     /// Returns the `fieldnorm` associated to a doc id.
@@ -87,8 +111,10 @@ impl FieldNormReader {
     /// Returns the `fieldnorm_id` associated to a document.
     #[inline(always)]
     pub fn fieldnorm_id(&self, doc_id: DocId) -> u8 {
-        let fielnorms_data = self.data.as_slice();
-        fielnorms_data[doc_id as usize]
+        match self.data {
+            FieldNormData::Source(ref data) => data.as_slice()[doc_id as usize],
+            FieldNormData::Constant { fieldnorm_id, .. } => fieldnorm_id,
+        }
     }
 
     /// Converts a `fieldnorm_id` into a fieldnorm.
@@ -114,8 +140,6 @@ impl From<&[u32]> for FieldNormReader {
             .map(FieldNormReader::fieldnorm_to_id)
             .collect::<Vec<u8>>();
         let field_norms_data = ReadOnlySource::from(field_norms_id);
-        FieldNormReader {
-            data: field_norms_data,
-        }
+        FieldNormReader::open(field_norms_data)
     }
 }
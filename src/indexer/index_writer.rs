@@ -15,21 +15,26 @@ use crate::error::TantivyError;
 use crate::fastfield::write_delete_bitset;
 use crate::indexer::delete_queue::{DeleteCursor, DeleteQueue};
 use crate::indexer::doc_opstamp_mapping::DocToOpstampMapping;
-use crate::indexer::operation::DeleteOperation;
+use crate::indexer::operation::{DeleteOperation, DeleteTarget};
 use crate::indexer::stamper::Stamper;
+use crate::indexer::MergeOperation;
 use crate::indexer::MergePolicy;
 use crate::indexer::SegmentEntry;
 use crate::indexer::SegmentWriter;
+use crate::query::Query;
 use crate::schema::Document;
 use crate::schema::IndexRecordOption;
 use crate::schema::Term;
+use crate::schema::Value;
 use crate::Opstamp;
+use crate::Searcher;
 use crossbeam::channel;
 use futures::executor::block_on;
 use futures::future::Future;
 use smallvec::smallvec;
 use smallvec::SmallVec;
 use std::mem;
+use std::net::IpAddr;
 use std::ops::Range;
 use std::sync::Arc;
 use std::thread;
@@ -108,17 +113,29 @@ fn compute_deleted_bitset(
         // Limit doc helps identify the first document
         // that may be affected by the delete operation.
         let limit_doc = doc_opstamps.compute_doc_limit(delete_op.opstamp);
-        let inverted_index = segment_reader.inverted_index(delete_op.term.field());
-        if let Some(mut docset) =
-            inverted_index.read_postings(&delete_op.term, IndexRecordOption::Basic)
-        {
-            let mut deleted_doc = docset.doc();
-            while deleted_doc != TERMINATED {
-                if deleted_doc < limit_doc {
-                    delete_bitset.insert(deleted_doc);
-                    might_have_changed = true;
+        match delete_op.target {
+            DeleteTarget::Term(ref term) => {
+                let inverted_index = segment_reader.inverted_index(term.field());
+                if let Some(mut docset) =
+                    inverted_index.read_postings(term, IndexRecordOption::Basic)
+                {
+                    let mut deleted_doc = docset.doc();
+                    while deleted_doc != TERMINATED {
+                        if deleted_doc < limit_doc {
+                            delete_bitset.insert(deleted_doc);
+                            might_have_changed = true;
+                        }
+                        deleted_doc = docset.advance();
+                    }
                 }
-                deleted_doc = docset.advance();
+            }
+            DeleteTarget::Query(ref weight) => {
+                weight.for_each(segment_reader, &mut |deleted_doc, _| {
+                    if deleted_doc < limit_doc {
+                        delete_bitset.insert(deleted_doc);
+                        might_have_changed = true;
+                    }
+                })?;
             }
         }
         delete_cursor.advance();
@@ -528,6 +545,52 @@ impl IndexWriter {
         async move { segment_updater.start_merge(merge_operation)?.await }
     }
 
+    /// Merges the committed segments of the index, so that at most
+    /// `max_segments` segments remain.
+    ///
+    /// The smallest segments are merged together into a single segment.
+    /// The future resolves to the meta of the resulting segment, or to `None`
+    /// if the index already had at most `max_segments` segments.
+    ///
+    /// `max_segments` is required to be strictly positive.
+    pub fn force_merge(
+        &mut self,
+        max_segments: usize,
+    ) -> impl Future<Output = crate::Result<Option<SegmentMeta>>> {
+        let merge_operation_res = self.force_merge_operation(max_segments);
+        let segment_updater = self.segment_updater.clone();
+        async move {
+            match merge_operation_res? {
+                Some(merge_operation) => segment_updater
+                    .start_merge(merge_operation)?
+                    .await
+                    .map(Some),
+                None => Ok(None),
+            }
+        }
+    }
+
+    fn force_merge_operation(&self, max_segments: usize) -> crate::Result<Option<MergeOperation>> {
+        if max_segments == 0 {
+            return Err(TantivyError::InvalidArgument(
+                "The number of segments to force merge to must be strictly positive".to_string(),
+            ));
+        }
+        let mut segment_metas = self.index.searchable_segment_metas()?;
+        if segment_metas.len() <= max_segments {
+            return Ok(None);
+        }
+        segment_metas.sort_by_key(|segment_meta| segment_meta.num_docs());
+        let num_segments_to_merge = segment_metas.len() - max_segments + 1;
+        let segment_ids: Vec<SegmentId> = segment_metas[..num_segments_to_merge]
+            .iter()
+            .map(SegmentMeta::id)
+            .collect();
+        Ok(Some(
+            self.segment_updater.make_merge_operation(&segment_ids),
+        ))
+    }
+
     /// Closes the current document channel send.
     /// and replace all the channels by new ones.
     ///
@@ -673,11 +736,40 @@ impl IndexWriter {
     /// only after calling `commit()`.
     pub fn delete_term(&self, term: Term) -> Opstamp {
         let opstamp = self.stamper.stamp();
-        let delete_operation = DeleteOperation { opstamp, term };
+        let delete_operation = DeleteOperation {
+            opstamp,
+            target: DeleteTarget::Term(term),
+        };
         self.delete_queue.push(delete_operation);
         opstamp
     }
 
+    /// Delete all documents matching a given query.
+    ///
+    /// The query is evaluated, without scoring, against each of the segments
+    /// when the deletes are applied. As for `delete_term`, it only affects
+    /// documents that were added before the delete operation, and the deletion
+    /// will be visible only after calling `commit()`.
+    ///
+    /// Returns an error if the query's `Weight` cannot be built.
+    pub fn delete_query(&self, query: Box<dyn Query>) -> crate::Result<Opstamp> {
+        let segment_readers = self
+            .index
+            .searchable_segments()?
+            .iter()
+            .map(SegmentReader::open)
+            .collect::<crate::Result<Vec<_>>>()?;
+        let searcher = Searcher::new(self.index.schema(), self.index.clone(), segment_readers);
+        let weight = query.weight(&searcher, false)?;
+        let opstamp = self.stamper.stamp();
+        let delete_operation = DeleteOperation {
+            opstamp,
+            target: DeleteTarget::Query(Arc::from(weight)),
+        };
+        self.delete_queue.push(delete_operation);
+        Ok(opstamp)
+    }
+
     /// Returns the opstamp of the last successful commit.
     ///
     /// This is, for instance, the opstamp the index will
@@ -706,6 +798,52 @@ impl IndexWriter {
         opstamp
     }
 
+    /// Replaces the document sharing the same unique key, or adds the
+    /// document if there is no such document.
+    ///
+    /// The unique key is the field declared with
+    /// [`SchemaBuilder::set_unique_key`](../schema/struct.SchemaBuilder.html#method.set_unique_key).
+    /// The deletion of the previous documents and the addition of the new document
+    /// are run as a single batch of operations (see `run`), so that they will be
+    /// visible together after the next `commit()`.
+    ///
+    /// Returns an error if the schema does not declare any unique key,
+    /// or if the document does not have a value for it.
+    pub fn update_document(&self, mut document: Document) -> crate::Result<Opstamp> {
+        let schema = self.index.schema();
+        document.truncate_dates(&schema);
+        let key_field = schema.unique_key().ok_or_else(|| {
+            TantivyError::InvalidArgument("The schema does not declare a unique key".to_string())
+        })?;
+        let key_term = match document.get_first(key_field) {
+            Some(Value::Str(text)) => Term::from_field_text(key_field, text),
+            Some(Value::U64(val)) => Term::from_field_u64(key_field, *val),
+            Some(Value::I64(val)) => Term::from_field_i64(key_field, *val),
+            Some(Value::F64(val)) => Term::from_field_f64(key_field, *val),
+            Some(Value::Date(date)) => Term::from_field_date(key_field, date),
+            Some(Value::Bool(val)) => Term::from_field_bool(key_field, *val),
+            Some(Value::IpAddr(ip_addr)) => {
+                Term::from_field_ip_addr(key_field, IpAddr::V6(*ip_addr))
+            }
+            Some(_) => {
+                return Err(TantivyError::InvalidArgument(format!(
+                    "Unsupported value type for the unique key `{}`",
+                    schema.get_field_name(key_field)
+                )));
+            }
+            None => {
+                return Err(TantivyError::InvalidArgument(format!(
+                    "The document has no value for the unique key `{}`",
+                    schema.get_field_name(key_field)
+                )));
+            }
+        };
+        Ok(self.run(vec![
+            UserOperation::Delete(key_term),
+            UserOperation::Add(document),
+        ]))
+    }
+
     /// Gets a range of stamps from the stamper and "pops" the last stamp
     /// from the range returning a tuple of the last optstamp and the popped
     /// range.
@@ -751,7 +889,10 @@ impl IndexWriter {
         for (user_op, opstamp) in user_operations.into_iter().zip(stamps) {
             match user_op {
                 UserOperation::Delete(term) => {
-                    let delete_operation = DeleteOperation { opstamp, term };
+                    let delete_operation = DeleteOperation {
+                        opstamp,
+                        target: DeleteTarget::Term(term),
+                    };
                     self.delete_queue.push(delete_operation);
                 }
                 UserOperation::Add(document) => {
@@ -783,15 +924,16 @@ impl Drop for IndexWriter {
 mod tests {
 
     use super::super::operation::UserOperation;
-    use crate::collector::TopDocs;
+    use crate::collector::{Count, TopDocs};
     use crate::directory::error::LockError;
     use crate::error::*;
     use crate::indexer::NoMergePolicy;
-    use crate::query::TermQuery;
+    use crate::query::{RangeQuery, TermQuery};
     use crate::schema::{self, IndexRecordOption, STRING};
     use crate::Index;
     use crate::ReloadPolicy;
     use crate::Term;
+    use futures::executor::block_on;
 
     #[test]
     fn test_operations_group() {
@@ -921,6 +1063,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lockfile_blocking_writer() {
+        let schema_builder = schema::Schema::builder();
+        let index = Index::create_in_ram(schema_builder.build());
+        let index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        let drop_handle = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            drop(index_writer);
+        });
+        assert!(index.writer_with_num_threads_blocking(1, 3_000_000).is_ok());
+        drop_handle.join().unwrap();
+    }
+
     #[test]
     fn test_lockfile_already_exists_error_msg() {
         let schema_builder = schema::Schema::builder();
@@ -1040,6 +1195,135 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_delete_query() {
+        let mut schema_builder = schema::Schema::builder();
+        let text_field = schema_builder.add_text_field("text", schema::TEXT);
+        let timestamp_field = schema_builder.add_u64_field("timestamp", schema::INDEXED);
+        let index = Index::create_in_ram(schema_builder.build());
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .unwrap();
+        let num_docs_containing = |s: &str| {
+            let term_a = Term::from_field_text(text_field, s);
+            reader
+                .searcher()
+                .search(&TermQuery::new(term_a, IndexRecordOption::Basic), &Count)
+                .unwrap()
+        };
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for timestamp in 0u64..10u64 {
+            index_writer.add_document(doc!(text_field => "a", timestamp_field => timestamp));
+        }
+        index_writer.commit().unwrap();
+        for timestamp in 10u64..15u64 {
+            index_writer.add_document(doc!(text_field => "a", timestamp_field => timestamp));
+        }
+        let delete_query = RangeQuery::new_u64(timestamp_field, 0..12);
+        index_writer.delete_query(Box::new(delete_query)).unwrap();
+        // documents added after the delete operation are not affected.
+        index_writer.add_document(doc!(text_field => "a", timestamp_field => 3u64));
+        index_writer.commit().unwrap();
+        reader.reload().unwrap();
+        assert_eq!(num_docs_containing("a"), 4);
+    }
+
+    #[test]
+    fn test_update_document() {
+        let mut schema_builder = schema::Schema::builder();
+        let id_field = schema_builder.add_u64_field("id", schema::INDEXED);
+        let text_field = schema_builder.add_text_field("text", schema::TEXT);
+        schema_builder.set_unique_key(id_field);
+        let index = Index::create_in_ram(schema_builder.build());
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .unwrap();
+        let num_docs_containing = |s: &str| {
+            let term = Term::from_field_text(text_field, s);
+            reader
+                .searcher()
+                .search(&TermQuery::new(term, IndexRecordOption::Basic), &Count)
+                .unwrap()
+        };
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer
+            .update_document(doc!(id_field => 1u64, text_field => "a"))
+            .unwrap();
+        index_writer
+            .update_document(doc!(id_field => 2u64, text_field => "a"))
+            .unwrap();
+        index_writer.commit().unwrap();
+        reader.reload().unwrap();
+        assert_eq!(num_docs_containing("a"), 2);
+
+        index_writer
+            .update_document(doc!(id_field => 1u64, text_field => "b"))
+            .unwrap();
+        index_writer
+            .update_document(doc!(id_field => 1u64, text_field => "c"))
+            .unwrap();
+        index_writer.commit().unwrap();
+        reader.reload().unwrap();
+        assert_eq!(num_docs_containing("a"), 1);
+        assert_eq!(num_docs_containing("b"), 0);
+        assert_eq!(num_docs_containing("c"), 1);
+        assert_eq!(reader.searcher().num_docs(), 2);
+
+        assert!(index_writer
+            .update_document(doc!(text_field => "d"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_update_document_without_unique_key() {
+        let mut schema_builder = schema::Schema::builder();
+        let text_field = schema_builder.add_text_field("text", schema::TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        assert!(index_writer
+            .update_document(doc!(text_field => "a"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_force_merge() {
+        let mut schema_builder = schema::Schema::builder();
+        let text_field = schema_builder.add_text_field("text", schema::TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.set_merge_policy(Box::new(NoMergePolicy));
+        for num_docs in 1..=4 {
+            for _ in 0..num_docs {
+                index_writer.add_document(doc!(text_field => "a"));
+            }
+            index_writer.commit().unwrap();
+        }
+        assert_eq!(index.searchable_segment_ids().unwrap().len(), 4);
+
+        let segment_meta = block_on(index_writer.force_merge(2)).unwrap().unwrap();
+        assert_eq!(segment_meta.num_docs(), 6);
+        let mut num_docs: Vec<u32> = index
+            .searchable_segment_metas()
+            .unwrap()
+            .iter()
+            .map(|segment_meta| segment_meta.num_docs())
+            .collect();
+        num_docs.sort();
+        assert_eq!(num_docs, vec![4, 6]);
+
+        assert!(block_on(index_writer.force_merge(2)).unwrap().is_none());
+        assert!(block_on(index_writer.force_merge(0)).is_err());
+
+        let segment_meta = block_on(index_writer.force_merge(1)).unwrap().unwrap();
+        assert_eq!(segment_meta.num_docs(), 10);
+        assert_eq!(index.searchable_segment_ids().unwrap().len(), 1);
+        index_writer.wait_merging_threads().unwrap();
+    }
+
     #[test]
     fn test_prepare_with_commit_message() {
         let mut schema_builder = schema::Schema::builder();
@@ -0,0 +1,157 @@
+use crate::common;
+use crate::schema::{Field, Term};
+use byteorder::{BigEndian, WriteBytesExt};
+
+/// Separates the segments of a json path within a term.
+pub(crate) const JSON_PATH_SEGMENT_SEP: u8 = 1u8;
+/// Marks the end of the json path within a term.
+/// It is followed by the type code and the value of the leaf.
+pub(crate) const JSON_END_OF_PATH: u8 = 0u8;
+
+const TEXT_TYPE_CODE: u8 = b's';
+const I64_TYPE_CODE: u8 = b'i';
+const U64_TYPE_CODE: u8 = b'u';
+const F64_TYPE_CODE: u8 = b'f';
+const BOOL_TYPE_CODE: u8 = b'o';
+
+/// Builds the terms associated to the leaves of a json object.
+///
+/// A json term is laid out as follows
+/// `[field id][path][JSON_END_OF_PATH][type code][value]`
+/// where the segments of the path are separated by `JSON_PATH_SEGMENT_SEP`.
+/// Numerical values are encoded like the values of the matching field types.
+pub(crate) struct JsonTermWriter {
+    term: Term,
+    // Length of the term at the end of each of the segments of the current path.
+    path_stack: Vec<usize>,
+}
+
+impl JsonTermWriter {
+    pub fn new(field: Field) -> JsonTermWriter {
+        let term = Term::for_field(field);
+        let path_stack = vec![term.as_slice().len()];
+        JsonTermWriter { term, path_stack }
+    }
+
+    /// Creates a `JsonTermWriter` for a path expressed using the `.` separator
+    /// (e.g. `attributes.status`).
+    pub fn from_json_path(field: Field, json_path: &str) -> JsonTermWriter {
+        let mut json_term_writer = JsonTermWriter::new(field);
+        for segment in json_path.split('.') {
+            json_term_writer.push_path_segment(segment);
+        }
+        json_term_writer
+    }
+
+    fn trim_to_end_of_path(&mut self) {
+        let end_of_path = *self.path_stack.last().unwrap();
+        self.term.truncate(end_of_path);
+    }
+
+    pub fn push_path_segment(&mut self, segment: &str) {
+        self.trim_to_end_of_path();
+        if self.path_stack.len() > 1 {
+            self.term.append_bytes(&[JSON_PATH_SEGMENT_SEP]);
+        }
+        self.term.append_bytes(segment.as_bytes());
+        self.path_stack.push(self.term.as_slice().len());
+    }
+
+    pub fn pop_path_segment(&mut self) {
+        self.path_stack.pop();
+        assert!(!self.path_stack.is_empty());
+        self.trim_to_end_of_path();
+    }
+
+    fn set_value(&mut self, type_code: u8, value: &[u8]) {
+        self.trim_to_end_of_path();
+        self.term.append_bytes(&[JSON_END_OF_PATH, type_code]);
+        self.term.append_bytes(value);
+    }
+
+    fn set_u64_value(&mut self, type_code: u8, val: u64) {
+        let mut buffer = [0u8; 8];
+        (&mut buffer[..]).write_u64::<BigEndian>(val).unwrap();
+        self.set_value(type_code, &buffer);
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.set_value(TEXT_TYPE_CODE, text.as_bytes());
+    }
+
+    pub fn set_i64(&mut self, val: i64) {
+        self.set_u64_value(I64_TYPE_CODE, common::i64_to_u64(val));
+    }
+
+    pub fn set_u64(&mut self, val: u64) {
+        self.set_u64_value(U64_TYPE_CODE, val);
+    }
+
+    pub fn set_f64(&mut self, val: f64) {
+        self.set_u64_value(F64_TYPE_CODE, common::f64_to_u64(val));
+    }
+
+    pub fn set_bool(&mut self, val: bool) {
+        self.set_u64_value(BOOL_TYPE_CODE, u64::from(val));
+    }
+
+    /// Sets a numerical json value, using the narrowest type
+    /// among `i64`, `u64` and `f64` that can represent it.
+    ///
+    /// Returns false if the number could not be represented.
+    pub fn set_json_number(&mut self, number: &serde_json::Number) -> bool {
+        if let Some(val) = number.as_i64() {
+            self.set_i64(val);
+        } else if let Some(val) = number.as_u64() {
+            self.set_u64(val);
+        } else if let Some(val) = number.as_f64() {
+            self.set_f64(val);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    pub fn term(&self) -> &Term {
+        &self.term
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonTermWriter;
+    use crate::schema::Field;
+
+    #[test]
+    fn test_json_term_writer() {
+        let field = Field::from_field_id(1u32);
+        let mut json_term_writer = JsonTermWriter::new(field);
+        json_term_writer.push_path_segment("attributes");
+        json_term_writer.push_path_segment("color");
+        json_term_writer.set_text("red");
+        assert_eq!(
+            json_term_writer.term().value_bytes(),
+            b"attributes\x01color\x00sred"
+        );
+        json_term_writer.set_text("blue");
+        assert_eq!(
+            json_term_writer.term().value_bytes(),
+            b"attributes\x01color\x00sblue"
+        );
+        json_term_writer.pop_path_segment();
+        json_term_writer.push_path_segment("dimensions");
+        json_term_writer.push_path_segment("width");
+        json_term_writer.set_i64(400);
+        assert_eq!(
+            json_term_writer.term().value_bytes(),
+            b"attributes\x01dimensions\x01width\x00i\x80\x00\x00\x00\x00\x00\x01\x90"
+        );
+        let mut json_term_writer =
+            JsonTermWriter::from_json_path(field, "attributes.dimensions.width");
+        json_term_writer.set_i64(400);
+        assert_eq!(
+            json_term_writer.term().value_bytes(),
+            b"attributes\x01dimensions\x01width\x00i\x80\x00\x00\x00\x00\x00\x01\x90"
+        );
+    }
+}
@@ -28,7 +28,12 @@ fn compute_total_num_tokens(readers: &[SegmentReader], field: Field) -> u64 {
     let mut total_tokens = 0u64;
     let mut count: [usize; 256] = [0; 256];
     for reader in readers {
-        if reader.has_deletes() {
+        if reader.has_deletes() && !reader.schema().get_field_entry(field).has_fieldnorms() {
+            // without fieldnorms, we assume deleted documents had
+            // an average number of tokens.
+            let num_tokens = reader.inverted_index(field).total_num_tokens();
+            total_tokens += num_tokens * u64::from(reader.num_docs()) / u64::from(reader.max_doc());
+        } else if reader.has_deletes() {
             // if there are deletes, then we use an approximation
             // using the fieldnorm
             let fieldnorms_reader = reader.get_fieldnorms_reader(field);
@@ -199,7 +204,7 @@ impl IndexMerger {
                         .remove(&field)
                         .expect("Logic Error in Tantivy (Please report). HierarchicalFact field should have required a\
                         `term_ordinal_mapping`.");
-                    self.write_hierarchical_facet_field(
+                    self.write_term_ords_field(
                         field,
                         &term_ordinal_mapping,
                         fast_field_serializer,
@@ -208,7 +213,8 @@ impl IndexMerger {
                 FieldType::U64(ref options)
                 | FieldType::I64(ref options)
                 | FieldType::F64(ref options)
-                | FieldType::Date(ref options) => match options.get_fastfield_cardinality() {
+                | FieldType::Date(ref options)
+                | FieldType::Bool(ref options) => match options.get_fastfield_cardinality() {
                     Some(Cardinality::SingleValue) => {
                         self.write_single_fast_field(field, fast_field_serializer)?;
                     }
@@ -217,13 +223,27 @@ impl IndexMerger {
                     }
                     None => {}
                 },
-                FieldType::Str(_) => {
-                    // We don't handle str fast field for the moment
-                    // They can be implemented using what is done
-                    // for facets in the future.
+                FieldType::IpAddr(_) | FieldType::JsonObject(_) => {
+                    // Ip addresses and json objects are not available as fast fields.
                 }
-                FieldType::Bytes => {
-                    self.write_bytes_fast_field(field, fast_field_serializer)?;
+                FieldType::Str(ref text_options) => {
+                    if text_options.is_fast() {
+                        // Like for facets, the values of str fast fields are term ordinals.
+                        let term_ordinal_mapping = term_ord_mappings
+                            .remove(&field)
+                            .expect("Logic Error in Tantivy (Please report). Str fast field should have required a\
+                            `term_ordinal_mapping`.");
+                        self.write_term_ords_field(
+                            field,
+                            &term_ordinal_mapping,
+                            fast_field_serializer,
+                        )?;
+                    }
+                }
+                FieldType::Bytes(ref bytes_options) => {
+                    if bytes_options.is_fast() {
+                        self.write_bytes_fast_field(field, fast_field_serializer)?;
+                    }
                 }
             }
         }
@@ -272,7 +292,11 @@ impl IndexMerger {
                     .unwrap_or(false);
                 if !is_deleted {
                     let val = u64_reader.get(doc_id);
-                    fast_single_field_serializer.add_val(val)?;
+                    if u64_reader.has_value(doc_id) {
+                        fast_single_field_serializer.add_val(val)?;
+                    } else {
+                        fast_single_field_serializer.add_missing_val(val)?;
+                    }
                 }
             }
         }
@@ -327,7 +351,7 @@ impl IndexMerger {
         Ok(())
     }
 
-    fn write_hierarchical_facet_field(
+    fn write_term_ords_field(
         &self,
         field: Field,
         term_ordinal_mappings: &TermOrdinalMapping,
@@ -341,7 +365,7 @@ impl IndexMerger {
         self.write_fast_field_idx(field, fast_field_serializer)?;
 
         // We can now write the actual fast field values.
-        // In the case of hierarchical facets, they are actually term ordinals.
+        // In the case of hierarchical facets and str fast fields, they are actually term ordinals.
         let max_term_ord = term_ordinal_mappings.max_term_ord();
         {
             let mut serialize_vals =
@@ -511,7 +535,12 @@ impl IndexMerger {
             max_term_ords.push(terms.num_terms() as u64);
         }
 
-        let mut term_ord_mapping_opt = if *field_type == FieldType::HierarchicalFacet {
+        let has_term_ords_fast_field = match *field_type {
+            FieldType::HierarchicalFacet => true,
+            FieldType::Str(ref text_options) => text_options.is_fast(),
+            _ => false,
+        };
+        let mut term_ord_mapping_opt = if has_term_ords_fast_field {
             Some(TermOrdinalMapping::new(max_term_ords))
         } else {
             None
@@ -654,7 +683,11 @@ impl IndexMerger {
     fn write_storable_fields(&self, store_writer: &mut StoreWriter) -> crate::Result<()> {
         for reader in &self.readers {
             let store_reader = reader.get_store_reader();
-            if reader.num_deleted_docs() > 0 {
+            // Stacking copies the compressed blocks as is, so it is only
+            // possible if the segment was written with the same compressor.
+            if reader.num_deleted_docs() > 0
+                || store_reader.compressor() != store_writer.compressor()
+            {
                 for doc_id in reader.doc_ids_alive() {
                     let doc = store_reader.get(doc_id)?;
                     store_writer.store(&doc)?;
@@ -697,7 +730,11 @@ mod tests {
     use crate::schema::IntOptions;
     use crate::schema::Term;
     use crate::schema::TextFieldIndexing;
+    use crate::schema::FAST;
     use crate::schema::INDEXED;
+    use crate::schema::STORED;
+    use crate::schema::STRING;
+    use crate::store::Compressor;
     use crate::DocAddress;
     use crate::IndexWriter;
     use crate::Searcher;
@@ -719,7 +756,7 @@ mod tests {
         let date_field = schema_builder.add_date_field("date", INDEXED);
         let score_fieldtype = schema::IntOptions::default().set_fast(Cardinality::SingleValue);
         let score_field = schema_builder.add_u64_field("score", score_fieldtype);
-        let bytes_score_field = schema_builder.add_bytes_field("score_bytes");
+        let bytes_score_field = schema_builder.add_bytes_field("score_bytes", FAST);
         let index = Index::create_in_ram(schema_builder.build());
         let reader = index.reader().unwrap();
         let curr_time = chrono::Utc::now();
@@ -883,7 +920,7 @@ mod tests {
         let text_field = schema_builder.add_text_field("text", text_fieldtype);
         let score_fieldtype = schema::IntOptions::default().set_fast(Cardinality::SingleValue);
         let score_field = schema_builder.add_u64_field("score", score_fieldtype);
-        let bytes_score_field = schema_builder.add_bytes_field("score_bytes");
+        let bytes_score_field = schema_builder.add_bytes_field("score_bytes", FAST);
         let index = Index::create_in_ram(schema_builder.build());
         let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
         let reader = index.reader().unwrap();
@@ -1320,6 +1357,61 @@ mod tests {
         assert_eq!(reader.searcher().num_docs(), 2);
     }
 
+    #[test]
+    fn test_merge_segments_with_different_store_compressors() {
+        let mut schema_builder = schema::Schema::builder();
+        let text_field = schema_builder.add_text_field("text", STRING | STORED);
+        let mut index = Index::create_in_ram(schema_builder.build());
+        index.set_store_compressor(Compressor::Snappy);
+        {
+            let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+            index_writer.add_document(doc!(text_field => "a"));
+            index_writer.add_document(doc!(text_field => "b"));
+            index_writer.commit().expect("commit failed");
+        }
+        index.set_store_compressor(Compressor::None);
+        {
+            let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+            index_writer.add_document(doc!(text_field => "c"));
+            index_writer.commit().expect("commit failed");
+        }
+        let mut store_compressors: Vec<Compressor> = index
+            .searchable_segment_metas()
+            .unwrap()
+            .iter()
+            .map(|segment_meta| segment_meta.store_compressor())
+            .collect();
+        store_compressors.sort_by_key(|compressor| *compressor == Compressor::None);
+        assert_eq!(
+            store_compressors,
+            vec![Compressor::Snappy, Compressor::None]
+        );
+        {
+            let segment_ids = index
+                .searchable_segment_ids()
+                .expect("Searchable segments failed.");
+            let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+            let merged_segment_meta =
+                block_on(index_writer.merge(&segment_ids)).expect("Merging failed");
+            assert_eq!(merged_segment_meta.store_compressor(), Compressor::None);
+            index_writer.wait_merging_threads().unwrap();
+        }
+        let searcher = index.reader().unwrap().searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        let mut texts: Vec<String> = (0..3u32)
+            .map(|doc_id| {
+                let doc = searcher.doc(DocAddress(0u32, doc_id)).unwrap();
+                doc.get_first(text_field)
+                    .unwrap()
+                    .text()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        texts.sort();
+        assert_eq!(texts, vec!["a", "b", "c"]);
+    }
+
     #[test]
     fn test_merge_multivalued_int_fields_all_deleted() {
         let mut schema_builder = schema::Schema::builder();
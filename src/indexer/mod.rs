@@ -2,6 +2,7 @@ pub mod delete_queue;
 
 mod doc_opstamp_mapping;
 pub mod index_writer;
+pub(crate) mod json_term_writer;
 mod log_merge_policy;
 mod merge_operation;
 pub mod merge_policy;
@@ -1,12 +1,23 @@
+use crate::query::Weight;
 use crate::schema::Document;
 use crate::schema::Term;
 use crate::Opstamp;
+use std::sync::Arc;
+
+/// Set of documents targeted by a delete operation.
+#[derive(Clone)]
+pub enum DeleteTarget {
+    /// All of the documents containing the term.
+    Term(Term),
+    /// All of the documents matched by the weight of a query.
+    Query(Arc<dyn Weight>),
+}
 
 /// Timestamped Delete operation.
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone)]
 pub struct DeleteOperation {
     pub opstamp: Opstamp,
-    pub term: Term,
+    pub target: DeleteTarget,
 }
 
 /// Timestamped Add operation.
@@ -4,6 +4,7 @@ use crate::fastfield::FastFieldSerializer;
 use crate::fieldnorm::FieldNormsSerializer;
 use crate::postings::InvertedIndexSerializer;
 use crate::store::StoreWriter;
+use fail::fail_point;
 
 /// Segment serializer is in charge of laying out on disk
 /// the data accumulated and sorted by the `SegmentWriter`.
@@ -19,6 +20,7 @@ impl SegmentSerializer {
     /// Creates a new `SegmentSerializer`.
     pub fn for_segment(mut segment: Segment) -> crate::Result<SegmentSerializer> {
         let store_write = segment.open_write(SegmentComponent::STORE)?;
+        let store_compressor = segment.meta().store_compressor();
 
         let fast_field_write = segment.open_write(SegmentComponent::FASTFIELDS)?;
         let fast_field_serializer = FastFieldSerializer::from_write(fast_field_write)?;
@@ -29,7 +31,7 @@ impl SegmentSerializer {
         let postings_serializer = InvertedIndexSerializer::open(&mut segment)?;
         Ok(SegmentSerializer {
             segment,
-            store_writer: StoreWriter::new(store_write),
+            store_writer: StoreWriter::new(store_write, store_compressor),
             fast_field_serializer,
             fieldnorms_serializer: Some(fieldnorms_serializer),
             postings_serializer,
@@ -65,6 +67,9 @@ impl SegmentSerializer {
 
     /// Finalize the segment serialization.
     pub fn close(mut self) -> crate::Result<()> {
+        fail_point!("SegmentSerializer::close", |msg| Err(
+            crate::TantivyError::SystemError(msg.unwrap_or_else(|| "Undefined".to_string()))
+        ));
         if let Some(fieldnorms_serializer) = self.extract_fieldnorms_serializer() {
             fieldnorms_serializer.close()?;
         }
@@ -19,6 +19,7 @@ use crate::indexer::{DefaultMergePolicy, MergePolicy};
 use crate::indexer::{MergeCandidate, MergeOperation};
 use crate::schema::Schema;
 use crate::Opstamp;
+use fail::fail_point;
 use futures::channel::oneshot;
 use futures::executor::{ThreadPool, ThreadPoolBuilder};
 use futures::future::Future;
@@ -63,9 +64,18 @@ pub fn save_new_metas(schema: Schema, directory: &mut dyn Directory) -> crate::R
 /// - it success, and `meta.json` is written
 /// and flushed.
 ///
+/// `meta.json` is the commit point of the index: the files of the segments
+/// it references must have been terminated, and hence synced to disk,
+/// before it is written. A crash before this call leaves the previous
+/// commit untouched, and the files of the new segments are later removed
+/// by the garbage collection.
+///
 /// This method is not part of tantivy's public API
 fn save_metas(metas: &IndexMeta, directory: &mut dyn Directory) -> crate::Result<()> {
     info!("save metas");
+    fail_point!("save_metas", |msg| Err(crate::TantivyError::SystemError(
+        msg.unwrap_or_else(|| "Undefined".to_string())
+    )));
     let mut buffer = serde_json::to_vec_pretty(metas)?;
     // Just adding a new line at the end of the buffer.
     writeln!(&mut buffer)?;
@@ -337,6 +347,9 @@ impl SegmentUpdater {
             let segment_entries = segment_updater.purge_deletes(opstamp)?;
             segment_updater.segment_manager.commit(segment_entries);
             segment_updater.save_metas(opstamp, payload)?;
+            fail_point!("SegmentUpdater::commit#after_save_metas", |msg| Err(
+                crate::TantivyError::SystemError(msg.unwrap_or_else(|| "Undefined".to_string()))
+            ));
             let _ = garbage_collect_files(segment_updater.clone()).await;
             segment_updater.consider_merge_options().await;
             Ok(())
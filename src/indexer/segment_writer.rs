@@ -3,6 +3,7 @@ use crate::core::Segment;
 use crate::core::SerializableSegment;
 use crate::fastfield::FastFieldsWriter;
 use crate::fieldnorm::FieldNormsWriter;
+use crate::indexer::json_term_writer::JsonTermWriter;
 use crate::indexer::segment_serializer::SegmentSerializer;
 use crate::postings::compute_table_size;
 use crate::postings::MultiFieldPostingsWriter;
@@ -13,10 +14,11 @@ use crate::schema::Value;
 use crate::schema::{Field, FieldEntry};
 use crate::tokenizer::{BoxTokenStream, PreTokenizedStream};
 use crate::tokenizer::{FacetTokenizer, TextAnalyzer};
-use crate::tokenizer::{TokenStreamChain, Tokenizer};
+use crate::tokenizer::{Token, TokenStreamChain, Tokenizer, MAX_TOKEN_LEN};
 use crate::DocId;
 use crate::Opstamp;
 use std::io;
+use std::net::IpAddr;
 use std::str;
 
 /// Computes the initial size of the hash table.
@@ -79,6 +81,12 @@ impl SegmentWriter {
                             let tokenizer_name = &text_index_option.tokenizer();
                             tokenizer_manager.get(tokenizer_name)
                         }),
+                    FieldType::JsonObject(ref json_object_options) => json_object_options
+                        .get_indexing_options()
+                        .and_then(|text_index_option| {
+                            let tokenizer_name = &text_index_option.tokenizer();
+                            tokenizer_manager.get(tokenizer_name)
+                        }),
                     _ => None,
                 },
             )
@@ -120,6 +128,7 @@ impl SegmentWriter {
         let doc_id = self.max_doc;
         let mut doc = add_operation.document;
         self.doc_opstamps.push(add_operation.opstamp);
+        doc.truncate_dates(schema);
 
         self.fast_field_writers.add_document(&doc);
 
@@ -190,8 +199,12 @@ impl SegmentWriter {
                         0
                     } else {
                         let mut token_stream = TokenStreamChain::new(offsets, token_streams);
-                        self.multifield_postings
-                            .index_text(doc_id, field, &mut token_stream)
+                        self.multifield_postings.index_text(
+                            doc_id,
+                            field,
+                            &mut token_stream,
+                            self.fast_field_writers.get_multivalue_writer(field),
+                        )
                     };
 
                     self.fieldnorms_writer.record(doc_id, field, num_tokens);
@@ -240,7 +253,48 @@ impl SegmentWriter {
                         }
                     }
                 }
-                FieldType::Bytes => {
+                FieldType::Bool(ref int_option) => {
+                    if int_option.is_indexed() {
+                        for field_value in field_values {
+                            let term = Term::from_field_bool(
+                                field_value.field(),
+                                field_value.value().bool_value(),
+                            );
+                            self.multifield_postings.subscribe(doc_id, &term);
+                        }
+                    }
+                }
+                FieldType::IpAddr(ref ip_addr_options) => {
+                    if ip_addr_options.is_indexed() {
+                        for field_value in field_values {
+                            let term = Term::from_field_ip_addr(
+                                field_value.field(),
+                                IpAddr::V6(field_value.value().ip_addr_value()),
+                            );
+                            self.multifield_postings.subscribe(doc_id, &term);
+                        }
+                    }
+                }
+                FieldType::JsonObject(_) => {
+                    let text_analyzer = self.tokenizers[field.field_id() as usize].as_ref();
+                    let mut json_term_writer = JsonTermWriter::new(field);
+                    let mut position = 0u32;
+                    let mut num_tokens = 0u32;
+                    for field_value in field_values {
+                        if let Value::JsonObject(ref json_object) = *field_value.value() {
+                            num_tokens += index_json_object(
+                                doc_id,
+                                json_object,
+                                text_analyzer,
+                                &mut json_term_writer,
+                                &mut self.multifield_postings,
+                                &mut position,
+                            );
+                        }
+                    }
+                    self.fieldnorms_writer.record(doc_id, field, num_tokens);
+                }
+                FieldType::Bytes(_) => {
                     // Do nothing. Bytes only supports fast fields.
                 }
             }
@@ -274,6 +328,108 @@ impl SegmentWriter {
     }
 }
 
+/// Gap inserted between the positions of two leaves of a json object,
+/// so that phrase queries do not match across leaves.
+const JSON_POSITION_GAP: u32 = 1;
+
+/// Indexes the leaves of a json object, and returns the number of tokens indexed.
+fn index_json_object(
+    doc_id: DocId,
+    json_object: &serde_json::Map<String, serde_json::Value>,
+    text_analyzer: Option<&TextAnalyzer>,
+    json_term_writer: &mut JsonTermWriter,
+    multifield_postings: &mut MultiFieldPostingsWriter,
+    position: &mut u32,
+) -> u32 {
+    let mut num_tokens = 0u32;
+    for (key, json_value) in json_object {
+        json_term_writer.push_path_segment(key);
+        num_tokens += index_json_value(
+            doc_id,
+            json_value,
+            text_analyzer,
+            json_term_writer,
+            multifield_postings,
+            position,
+        );
+        json_term_writer.pop_path_segment();
+    }
+    num_tokens
+}
+
+fn index_json_value(
+    doc_id: DocId,
+    json_value: &serde_json::Value,
+    text_analyzer: Option<&TextAnalyzer>,
+    json_term_writer: &mut JsonTermWriter,
+    multifield_postings: &mut MultiFieldPostingsWriter,
+    position: &mut u32,
+) -> u32 {
+    match *json_value {
+        serde_json::Value::Null => 0,
+        serde_json::Value::Bool(val) => {
+            json_term_writer.set_bool(val);
+            multifield_postings.subscribe_at_position(doc_id, *position, json_term_writer.term());
+            *position += 1 + JSON_POSITION_GAP;
+            1
+        }
+        serde_json::Value::Number(ref number) => {
+            if !json_term_writer.set_json_number(number) {
+                return 0;
+            }
+            multifield_postings.subscribe_at_position(doc_id, *position, json_term_writer.term());
+            *position += 1 + JSON_POSITION_GAP;
+            1
+        }
+        serde_json::Value::String(ref text) => {
+            let text_analyzer = if let Some(text_analyzer) = text_analyzer {
+                text_analyzer
+            } else {
+                return 0;
+            };
+            let start_position = *position;
+            let mut end_position = start_position;
+            let num_tokens = text_analyzer
+                .token_stream(text)
+                .process(&mut |token: &Token| {
+                    let token_position = start_position + token.position as u32;
+                    end_position = end_position.max(token_position + 1);
+                    if token.text.len() <= MAX_TOKEN_LEN {
+                        json_term_writer.set_text(&token.text);
+                        multifield_postings.subscribe_at_position(
+                            doc_id,
+                            token_position,
+                            json_term_writer.term(),
+                        );
+                    }
+                });
+            *position = end_position + JSON_POSITION_GAP;
+            num_tokens
+        }
+        serde_json::Value::Array(ref json_values) => json_values
+            .iter()
+            .map(|json_value| {
+                index_json_value(
+                    doc_id,
+                    json_value,
+                    text_analyzer,
+                    json_term_writer,
+                    multifield_postings,
+                    position,
+                )
+            })
+            .sum(),
+        serde_json::Value::Object(ref json_object) => index_json_object(
+            doc_id,
+            json_object,
+            text_analyzer,
+            json_term_writer,
+            multifield_postings,
+            position,
+        ),
+    }
+}
+
 // This method is used as a trick to workaround the borrow checker
 fn write(
     multifield_postings: &MultiFieldPostingsWriter,
@@ -306,6 +462,12 @@ impl SerializableSegment for SegmentWriter {
 #[cfg(test)]
 mod tests {
     use super::initial_table_size;
+    use crate::collector::Count;
+    use crate::query::{QueryParser, TermQuery};
+    use crate::schema::{Cardinality, DatePrecision, IndexRecordOption, IntOptions, Schema};
+    use crate::schema::{Value, STORED, TEXT};
+    use crate::{DateTime, DocAddress, Index, Term};
+    use std::str::FromStr;
 
     #[test]
     fn test_hashmap_size() {
@@ -314,4 +476,114 @@ mod tests {
         assert_eq!(initial_table_size(10_000_000).unwrap(), 17);
         assert_eq!(initial_table_size(1_000_000_000).unwrap(), 19);
     }
+
+    #[test]
+    fn test_date_precision() {
+        let mut schema_builder = Schema::builder();
+        let date_options = IntOptions::default()
+            .set_indexed()
+            .set_stored()
+            .set_fast(Cardinality::SingleValue)
+            .set_precision(DatePrecision::Days);
+        let date_field = schema_builder.add_date_field("date", date_options);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        let date = DateTime::from_str("2020-03-15T13:47:21+00:00").unwrap();
+        index_writer.add_document(doc!(date_field => date));
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let day = DateTime::from_str("2020-03-15T00:00:00+00:00").unwrap();
+        let query = TermQuery::new(
+            Term::from_field_date(date_field, &day),
+            IndexRecordOption::Basic,
+        );
+        assert_eq!(searcher.search(&query, &Count).unwrap(), 1);
+        let query_parser = QueryParser::for_index(&index, vec![]);
+        let query = query_parser
+            .parse_query("date:\"2020-03-15T18:00:00+00:00\"")
+            .unwrap();
+        assert_eq!(searcher.search(&query, &Count).unwrap(), 1);
+        let stored_doc = searcher.doc(DocAddress(0u32, 0u32)).unwrap();
+        assert_eq!(stored_doc.get_first(date_field).unwrap().date_value(), &day);
+        let fast_field_reader = searcher
+            .segment_reader(0u32)
+            .fast_fields()
+            .date(date_field)
+            .unwrap();
+        assert_eq!(fast_field_reader.get(0u32), day);
+    }
+
+    #[test]
+    fn test_json_object_indexing() {
+        let mut schema_builder = Schema::builder();
+        let json_field = schema_builder.add_json_object_field("attributes", STORED | TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        let json_object = serde_json::from_str(
+            r#"{
+                "message": "connection refused by server",
+                "status": 503,
+                "latency": 2.5,
+                "retried": true,
+                "host": {"name": "db-1", "region": "eu"},
+                "tags": ["prod", "critical"],
+                "trace": null
+            }"#,
+        )
+        .unwrap();
+        index_writer.add_document(doc!(json_field => Value::JsonObject(json_object)));
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let query_parser = QueryParser::for_index(&index, vec![json_field]);
+        let count = |query: &str| {
+            let query = query_parser.parse_query(query).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count("attributes.message:refused"), 1);
+        assert_eq!(count("attributes.message:\"refused by\""), 1);
+        assert_eq!(count("attributes.message:\"server refused\""), 0);
+        assert_eq!(count("attributes.status:503"), 1);
+        assert_eq!(count("attributes.status:504"), 0);
+        assert_eq!(count("attributes.latency:2.5"), 1);
+        assert_eq!(count("attributes.retried:true"), 1);
+        assert_eq!(count("attributes.retried:false"), 0);
+        assert_eq!(count("attributes.host.name:db"), 1);
+        assert_eq!(count("attributes.host.region:eu"), 1);
+        assert_eq!(count("attributes.host:eu"), 0);
+        assert_eq!(count("attributes.tags:critical"), 1);
+        // Phrases do not match across leaves.
+        assert_eq!(count("attributes.tags:\"prod critical\""), 0);
+        // The status is indexed as a number, not as text.
+        assert_eq!(count("attributes.message:503"), 0);
+        let stored_doc = searcher.doc(DocAddress(0u32, 0u32)).unwrap();
+        let stored_json = stored_doc.get_first(json_field).unwrap().as_json().unwrap();
+        assert_eq!(stored_json["host"]["name"], "db-1");
+    }
+
+    #[test]
+    fn test_json_object_numeric_string() {
+        let mut schema_builder = Schema::builder();
+        let json_field = schema_builder.add_json_object_field("attributes", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for json in &[
+            r#"{"status": "503", "retried": "true"}"#,
+            r#"{"status": 503}"#,
+        ] {
+            let json_object = serde_json::from_str(json).unwrap();
+            index_writer.add_document(doc!(json_field => Value::JsonObject(json_object)));
+        }
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let query_parser = QueryParser::for_index(&index, vec![json_field]);
+        let count = |query: &str| {
+            let query = query_parser.parse_query(query).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        // Strings looking like a number or a boolean are indexed as text,
+        // and still match.
+        assert_eq!(count("attributes.status:503"), 2);
+        assert_eq!(count("attributes.retried:true"), 1);
+        assert_eq!(count("attributes.status:504"), 0);
+    }
 }
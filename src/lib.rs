@@ -173,7 +173,13 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
 /// Index format version.
-const INDEX_FORMAT_VERSION: u32 = 1;
+const INDEX_FORMAT_VERSION: u32 = 3;
+
+/// Oldest index format version that can still be read.
+///
+/// Segments written with an older format than `INDEX_FORMAT_VERSION` can be
+/// rewritten in the current format using `Index::upgrade()`.
+const MIN_INDEX_FORMAT_VERSION: u32 = 1;
 
 /// Structure version for the index.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -409,6 +415,7 @@ mod tests {
             assert_eq!(searcher.doc_freq(&term_c), 2);
             let term_d = Term::from_field_text(text_field, "d");
             assert_eq!(searcher.doc_freq(&term_d), 0);
+            assert_eq!(searcher.total_num_tokens(text_field), 7);
         }
     }
 
@@ -75,6 +75,7 @@ impl BlockSegmentPostings {
         data: ReadOnlySource,
         record_option: IndexRecordOption,
         requested_option: IndexRecordOption,
+        index_format_version: u32,
     ) -> BlockSegmentPostings {
         let freq_reading_option = match (record_option, requested_option) {
             (IndexRecordOption::Basic, _) => FreqReadingOption::NoFreq,
@@ -84,8 +85,15 @@ impl BlockSegmentPostings {
 
         let (skip_data_opt, postings_data) = split_into_skips_and_postings(doc_freq, data);
         let skip_reader = match skip_data_opt {
-            Some(skip_data) => SkipReader::new(skip_data, doc_freq, record_option),
-            None => SkipReader::new(ReadOnlySource::empty(), doc_freq, record_option),
+            Some(skip_data) => {
+                SkipReader::new(skip_data, doc_freq, record_option, index_format_version)
+            }
+            None => SkipReader::new(
+                ReadOnlySource::empty(),
+                doc_freq,
+                record_option,
+                index_format_version,
+            ),
         };
 
         let doc_freq = doc_freq as usize;
@@ -175,6 +183,34 @@ impl BlockSegmentPostings {
         self.doc_decoder.output_len
     }
 
+    /// Returns the max term frequency of the documents of the current block.
+    ///
+    /// It is an upper bound of the term frequencies of the block,
+    /// that does not require to decode them. It is only available for
+    /// bitpacked blocks (i.e. not for the last incomplete block) of fields
+    /// indexed with their term frequencies.
+    ///
+    /// If the term frequencies are not read, they all are equal to 1.
+    pub fn block_max_term_freq(&self) -> Option<u32> {
+        if let FreqReadingOption::ReadFreq = self.freq_reading_option {
+            self.skip_reader.block_max_term_freq()
+        } else {
+            Some(1u32)
+        }
+    }
+
+    /// Returns true if the term frequencies are decoded.
+    pub(crate) fn reads_freqs(&self) -> bool {
+        self.freq_reading_option == FreqReadingOption::ReadFreq
+    }
+
+    /// Returns the last doc of the block the skip reader is positioned on.
+    ///
+    /// It is `TERMINATED` for the last incomplete block.
+    pub(crate) fn last_doc_in_block(&self) -> DocId {
+        self.skip_reader.last_doc_in_block()
+    }
+
     pub(crate) fn position_offset(&self) -> u64 {
         self.skip_reader.position_offset()
     }
@@ -184,10 +220,20 @@ impl BlockSegmentPostings {
     /// If all docs are smaller than target, the block loaded may be empty,
     /// or be the last an incomplete VInt block.
     pub fn seek(&mut self, target_doc: DocId) {
-        self.skip_reader.seek(target_doc);
+        self.shallow_seek(target_doc);
         self.load_block();
     }
 
+    /// Positions the skip reader on the block that may contain `target_doc`,
+    /// without decoding this block.
+    ///
+    /// The block information (e.g. `block_max_term_freq()`) is then the one of
+    /// the new block, while `docs()` and `freqs()` still return the content
+    /// of the previously loaded block, until the next call to `seek`.
+    pub(crate) fn shallow_seek(&mut self, target_doc: DocId) {
+        self.skip_reader.seek(target_doc);
+    }
+
     fn load_block(&mut self) {
         let offset = self.skip_reader.byte_offset();
         if self.loaded_offset == offset {
@@ -250,7 +296,12 @@ impl BlockSegmentPostings {
             freq_reading_option: FreqReadingOption::NoFreq,
             doc_freq: 0,
             data: ReadOnlySource::new(vec![]),
-            skip_reader: SkipReader::new(ReadOnlySource::new(vec![]), 0, IndexRecordOption::Basic),
+            skip_reader: SkipReader::new(
+                ReadOnlySource::new(vec![]),
+                0,
+                IndexRecordOption::Basic,
+                crate::INDEX_FORMAT_VERSION,
+            ),
         }
     }
 }
@@ -267,7 +318,7 @@ mod tests {
     use crate::schema::IndexRecordOption;
     use crate::schema::Schema;
     use crate::schema::Term;
-    use crate::schema::INDEXED;
+    use crate::schema::{INDEXED, TEXT};
     use crate::DocId;
 
     #[test]
@@ -391,6 +442,33 @@ mod tests {
         assert_eq!(block_postings.doc(COMPRESSION_BLOCK_SIZE - 1), TERMINATED);
     }
 
+    #[test]
+    fn test_block_max_term_freq() {
+        let mut schema_builder = Schema::builder();
+        let text_field = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for i in 0..300 {
+            let text = vec!["a"; 1 + (i * 7) % 13].join(" ");
+            index_writer.add_document(doc!(text_field => text));
+        }
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let inverted_index = searcher.segment_reader(0).inverted_index(text_field);
+        let term = Term::from_field_text(text_field, "a");
+        let term_info = inverted_index.get_term_info(&term).unwrap();
+        let mut block_postings = inverted_index
+            .read_block_postings_from_terminfo(&term_info, IndexRecordOption::WithFreqs);
+        for _ in 0..2 {
+            assert_eq!(block_postings.block_len(), COMPRESSION_BLOCK_SIZE);
+            let max_freq = block_postings.freqs().iter().cloned().max();
+            assert_eq!(block_postings.block_max_term_freq(), max_freq);
+            block_postings.advance();
+        }
+        assert_eq!(block_postings.block_len(), 300 - 2 * COMPRESSION_BLOCK_SIZE);
+        assert_eq!(block_postings.block_max_term_freq(), None);
+    }
+
     #[test]
     fn test_reset_block_segment_postings() {
         let mut schema_builder = Schema::builder();
@@ -1,5 +1,6 @@
 use super::stacker::{Addr, MemoryArena, TermHashMap};
 
+use crate::fastfield::MultiValueIntFastFieldWriter;
 use crate::postings::recorder::{
     BufferLender, NothingRecorder, Recorder, TFAndPositionRecorder, TermFrequencyRecorder,
 };
@@ -19,9 +20,10 @@ use std::ops::DerefMut;
 
 fn posting_from_field_entry(field_entry: &FieldEntry) -> Box<dyn PostingsWriter> {
     match *field_entry.field_type() {
-        FieldType::Str(ref text_options) => text_options
-            .get_indexing_options()
-            .map(|indexing_options| match indexing_options.index_option() {
+        FieldType::Str(_) | FieldType::JsonObject(_) => field_entry
+            .field_type()
+            .get_index_record_option()
+            .map(|index_record_option| match index_record_option {
                 IndexRecordOption::Basic => {
                     SpecializedPostingsWriter::<NothingRecorder>::new_boxed()
                 }
@@ -37,8 +39,10 @@ fn posting_from_field_entry(field_entry: &FieldEntry) -> Box<dyn PostingsWriter>
         | FieldType::I64(_)
         | FieldType::F64(_)
         | FieldType::Date(_)
+        | FieldType::Bool(_)
+        | FieldType::IpAddr(_)
         | FieldType::HierarchicalFacet => SpecializedPostingsWriter::<NothingRecorder>::new_boxed(),
-        FieldType::Bytes => {
+        FieldType::Bytes(_) => {
             // FieldType::Bytes cannot actually be indexed.
             // TODO fix during the indexer refactoring described in #276
             SpecializedPostingsWriter::<NothingRecorder>::new_boxed()
@@ -104,6 +108,7 @@ impl MultiFieldPostingsWriter {
         doc: DocId,
         field: Field,
         token_stream: &mut dyn TokenStream,
+        term_id_fast_field_writer_opt: Option<&mut MultiValueIntFastFieldWriter>,
     ) -> u32 {
         let postings_writer =
             self.per_field_postings_writers[field.field_id() as usize].deref_mut();
@@ -113,13 +118,23 @@ impl MultiFieldPostingsWriter {
             field,
             token_stream,
             &mut self.heap,
+            term_id_fast_field_writer_opt,
         )
     }
 
     pub fn subscribe(&mut self, doc: DocId, term: &Term) -> UnorderedTermId {
+        self.subscribe_at_position(doc, 0u32, term)
+    }
+
+    pub fn subscribe_at_position(
+        &mut self,
+        doc: DocId,
+        position: u32,
+        term: &Term,
+    ) -> UnorderedTermId {
         let postings_writer =
             self.per_field_postings_writers[term.field().field_id() as usize].deref_mut();
-        postings_writer.subscribe(&mut self.term_index, doc, 0u32, term, &mut self.heap)
+        postings_writer.subscribe(&mut self.term_index, doc, position, term, &mut self.heap)
     }
 
     /// Serialize the inverted index.
@@ -156,8 +171,14 @@ impl MultiFieldPostingsWriter {
                         .collect();
                     unordered_term_mappings.insert(field, mapping);
                 }
-                FieldType::U64(_) | FieldType::I64(_) | FieldType::F64(_) | FieldType::Date(_) => {}
-                FieldType::Bytes => {}
+                FieldType::U64(_)
+                | FieldType::I64(_)
+                | FieldType::F64(_)
+                | FieldType::Date(_)
+                | FieldType::Bool(_)
+                | FieldType::IpAddr(_)
+                | FieldType::JsonObject(_) => {}
+                FieldType::Bytes(_) => {}
             }
 
             let postings_writer = &self.per_field_postings_writers[field.field_id() as usize];
@@ -207,6 +228,9 @@ pub trait PostingsWriter {
     ) -> io::Result<()>;
 
     /// Tokenize a text and subscribe all of its token.
+    ///
+    /// If a fast field writer is given, the unordered term ids of
+    /// the tokens are pushed to it.
     fn index_text(
         &mut self,
         term_index: &mut TermHashMap,
@@ -214,13 +238,18 @@ pub trait PostingsWriter {
         field: Field,
         token_stream: &mut dyn TokenStream,
         heap: &mut MemoryArena,
+        mut term_id_fast_field_writer_opt: Option<&mut MultiValueIntFastFieldWriter>,
     ) -> u32 {
         let mut term = Term::for_field(field);
         let mut sink = |token: &Token| {
             // We skip all tokens with a len greater than u16.
             if token.text.len() <= MAX_TOKEN_LEN {
                 term.set_text(token.text.as_str());
-                self.subscribe(term_index, doc_id, token.position as u32, &term, heap);
+                let unordered_term_id =
+                    self.subscribe(term_index, doc_id, token.position as u32, &term, heap);
+                if let Some(term_id_fast_field_writer) = term_id_fast_field_writer_opt.as_mut() {
+                    term_id_fast_field_writer.add_val(unordered_term_id);
+                }
             } else {
                 info!(
                     "A token exceeding MAX_TOKEN_LEN ({}>{}) was dropped. Search for \
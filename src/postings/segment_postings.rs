@@ -62,6 +62,7 @@ impl SegmentPostings {
             ReadOnlySource::from(buffer),
             IndexRecordOption::Basic,
             IndexRecordOption::Basic,
+            crate::INDEX_FORMAT_VERSION,
         );
         SegmentPostings::from_block_postings(block_segment_postings, None)
     }
@@ -83,6 +84,34 @@ impl SegmentPostings {
             block_searcher: BlockSearcher::default(),
         }
     }
+
+    /// Positions the skip reader on the block that may contain `target`,
+    /// without decoding it.
+    ///
+    /// The current document is left unchanged. The next call must be
+    /// a call to `seek`, with a target greater or equal to `target`.
+    pub(crate) fn shallow_seek(&mut self, target: DocId) {
+        self.block_cursor.shallow_seek(target);
+    }
+
+    /// Returns the last doc of the block the skip reader is positioned on,
+    /// or `TERMINATED` for the last incomplete block.
+    pub(crate) fn last_doc_in_block(&self) -> DocId {
+        self.block_cursor.last_doc_in_block()
+    }
+
+    /// Returns the max term frequency of the block the skip reader is positioned on,
+    /// if available.
+    pub(crate) fn block_max_term_freq(&self) -> Option<u32> {
+        self.block_cursor.block_max_term_freq()
+    }
+
+    /// Returns true if the term frequencies are decoded.
+    ///
+    /// If not, `term_freq()` always returns 1.
+    pub(crate) fn reads_freqs(&self) -> bool {
+        self.block_cursor.reads_freqs()
+    }
 }
 
 impl DocSet for SegmentPostings {
@@ -144,6 +144,14 @@ impl<'a> FieldSerializer<'a> {
                     (false, false)
                 }
             }
+            FieldType::JsonObject(ref json_object_options) => {
+                if let Some(text_indexing_options) = json_object_options.get_indexing_options() {
+                    let index_option = text_indexing_options.index_option();
+                    (index_option.has_freq(), index_option.has_positions())
+                } else {
+                    (false, false)
+                }
+            }
             _ => (false, false),
         };
         let term_dictionary_builder = TermDictionaryBuilder::create(term_dictionary_write)?;
@@ -352,6 +360,14 @@ impl<W: Write> PostingsSerializer<W> {
                 let sum_freq = self.block.term_freqs().iter().cloned().sum();
                 self.skip_write.write_total_term_freq(sum_freq);
             }
+            let block_max_tf = self
+                .block
+                .term_freqs()
+                .iter()
+                .cloned()
+                .max()
+                .unwrap_or(0u32);
+            self.skip_write.write_block_max_term_freq(block_max_tf);
         }
         self.block.clear();
     }
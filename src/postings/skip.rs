@@ -1,4 +1,4 @@
-use crate::common::BinarySerializable;
+use crate::common::{BinarySerializable, VInt};
 use crate::directory::ReadOnlySource;
 use crate::postings::compression::{compressed_block_size, COMPRESSION_BLOCK_SIZE};
 use crate::schema::IndexRecordOption;
@@ -40,6 +40,12 @@ impl SkipSerializer {
             .expect("Should never fail");
     }
 
+    pub fn write_block_max_term_freq(&mut self, block_max_tf: u32) {
+        VInt(u64::from(block_max_tf))
+            .serialize(&mut self.buffer)
+            .expect("Should never fail");
+    }
+
     pub fn data(&self) -> &[u8] {
         &self.buffer[..]
     }
@@ -55,10 +61,14 @@ pub(crate) struct SkipReader {
     pub(crate) last_doc_in_previous_block: DocId,
     owned_read: OwnedRead,
     skip_info: IndexRecordOption,
+    // Skip entries only record the max term frequency of
+    // their block starting from index format 3.
+    has_block_max: bool,
     byte_offset: usize,
     remaining_docs: u32, // number of docs remaining, including the
     // documents in the current block.
     block_info: BlockInfo,
+    block_max_term_freq: Option<u32>,
 
     position_offset: u64,
 }
@@ -80,7 +90,12 @@ impl Default for BlockInfo {
 }
 
 impl SkipReader {
-    pub fn new(data: ReadOnlySource, doc_freq: u32, skip_info: IndexRecordOption) -> SkipReader {
+    pub fn new(
+        data: ReadOnlySource,
+        doc_freq: u32,
+        skip_info: IndexRecordOption,
+        index_format_version: u32,
+    ) -> SkipReader {
         let mut skip_reader = SkipReader {
             last_doc_in_block: if doc_freq >= COMPRESSION_BLOCK_SIZE as u32 {
                 0
@@ -90,7 +105,9 @@ impl SkipReader {
             last_doc_in_previous_block: 0u32,
             owned_read: OwnedRead::new(data),
             skip_info,
+            has_block_max: index_format_version >= 3,
             block_info: BlockInfo::VInt(doc_freq),
+            block_max_term_freq: None,
             byte_offset: 0,
             remaining_docs: doc_freq,
             position_offset: 0u64,
@@ -110,6 +127,7 @@ impl SkipReader {
         self.last_doc_in_previous_block = 0u32;
         self.owned_read = OwnedRead::new(data);
         self.block_info = BlockInfo::VInt(doc_freq);
+        self.block_max_term_freq = None;
         self.byte_offset = 0;
         self.remaining_docs = doc_freq;
         self.position_offset = 0u64;
@@ -118,7 +136,6 @@ impl SkipReader {
         }
     }
 
-    #[cfg(test)]
     #[inline(always)]
     pub(crate) fn last_doc_in_block(&self) -> DocId {
         self.last_doc_in_block
@@ -165,12 +182,27 @@ impl SkipReader {
                 };
             }
         }
+        self.block_max_term_freq = if self.has_block_max && self.skip_info.has_freq() {
+            let block_max_tf =
+                VInt::deserialize(&mut self.owned_read).expect("Skip data corrupted");
+            Some(block_max_tf.0 as u32)
+        } else {
+            None
+        };
     }
 
     pub fn block_info(&self) -> BlockInfo {
         self.block_info
     }
 
+    /// Returns the max term frequency of the documents of the current block.
+    ///
+    /// It is only available for bitpacked blocks of fields indexed with
+    /// their term frequencies, and written with index format 3 or above.
+    pub fn block_max_term_freq(&self) -> Option<u32> {
+        self.block_max_term_freq
+    }
+
     /// Advance the skip reader to the block that may contain the target.
     ///
     /// If the target is larger than all documents, the skip_reader
@@ -204,6 +236,7 @@ impl SkipReader {
         } else {
             self.last_doc_in_block = TERMINATED;
             self.block_info = BlockInfo::VInt(self.remaining_docs);
+            self.block_max_term_freq = None;
         }
     }
 }
@@ -223,8 +256,10 @@ mod tests {
             let mut skip_serializer = SkipSerializer::new();
             skip_serializer.write_doc(1u32, 2u8);
             skip_serializer.write_term_freq(3u8);
+            skip_serializer.write_block_max_term_freq(7u32);
             skip_serializer.write_doc(5u32, 5u8);
             skip_serializer.write_term_freq(2u8);
+            skip_serializer.write_block_max_term_freq(300u32);
             skip_serializer.data().to_owned()
         };
         let doc_freq = 3u32 + (COMPRESSION_BLOCK_SIZE * 2) as u32;
@@ -232,6 +267,7 @@ mod tests {
             ReadOnlySource::new(buf),
             doc_freq,
             IndexRecordOption::WithFreqs,
+            crate::INDEX_FORMAT_VERSION,
         );
         assert_eq!(skip_reader.last_doc_in_block(), 1u32);
         assert_eq!(
@@ -242,6 +278,7 @@ mod tests {
                 tf_sum: 0
             }
         );
+        assert_eq!(skip_reader.block_max_term_freq(), Some(7u32));
         skip_reader.advance();
         assert_eq!(skip_reader.last_doc_in_block(), 5u32);
         assert_eq!(
@@ -252,14 +289,50 @@ mod tests {
                 tf_sum: 0
             }
         );
+        assert_eq!(skip_reader.block_max_term_freq(), Some(300u32));
         skip_reader.advance();
         assert_eq!(skip_reader.block_info(), BlockInfo::VInt(3u32));
+        assert_eq!(skip_reader.block_max_term_freq(), None);
         skip_reader.advance();
         assert_eq!(skip_reader.block_info(), BlockInfo::VInt(0u32));
         skip_reader.advance();
         assert_eq!(skip_reader.block_info(), BlockInfo::VInt(0u32));
     }
 
+    #[test]
+    fn test_skip_with_freq_legacy_format() {
+        let buf = {
+            let mut skip_serializer = SkipSerializer::new();
+            skip_serializer.write_doc(1u32, 2u8);
+            skip_serializer.write_term_freq(3u8);
+            skip_serializer.write_doc(5u32, 5u8);
+            skip_serializer.write_term_freq(2u8);
+            skip_serializer.data().to_owned()
+        };
+        let doc_freq = 3u32 + (COMPRESSION_BLOCK_SIZE * 2) as u32;
+        let mut skip_reader = SkipReader::new(
+            ReadOnlySource::new(buf),
+            doc_freq,
+            IndexRecordOption::WithFreqs,
+            2u32,
+        );
+        assert_eq!(skip_reader.last_doc_in_block(), 1u32);
+        assert_eq!(skip_reader.block_max_term_freq(), None);
+        skip_reader.advance();
+        assert_eq!(skip_reader.last_doc_in_block(), 5u32);
+        assert_eq!(
+            skip_reader.block_info(),
+            BlockInfo::BitPacked {
+                doc_num_bits: 5u8,
+                tf_num_bits: 2u8,
+                tf_sum: 0
+            }
+        );
+        assert_eq!(skip_reader.block_max_term_freq(), None);
+        skip_reader.advance();
+        assert_eq!(skip_reader.block_info(), BlockInfo::VInt(3u32));
+    }
+
     #[test]
     fn test_skip_no_freq() {
         let buf = {
@@ -273,6 +346,7 @@ mod tests {
             ReadOnlySource::from(buf),
             doc_freq,
             IndexRecordOption::Basic,
+            crate::INDEX_FORMAT_VERSION,
         );
         assert_eq!(skip_reader.last_doc_in_block(), 1u32);
         assert_eq!(
@@ -313,6 +387,7 @@ mod tests {
             ReadOnlySource::from(buf),
             doc_freq,
             IndexRecordOption::Basic,
+            crate::INDEX_FORMAT_VERSION,
         );
         assert_eq!(skip_reader.last_doc_in_block(), 1u32);
         assert_eq!(
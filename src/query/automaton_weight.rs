@@ -11,10 +11,14 @@ use crate::TantivyError;
 use std::sync::Arc;
 use tantivy_fst::Automaton;
 
-/// A weight struct for Fuzzy Term and Regex Queries
+/// Number of terms expanded between two checks of the interruption of the search.
+const CHECK_INTERVAL: usize = 64;
+
+/// A weight struct for Fuzzy Term, Regex, Prefix and Wildcard Queries
 pub struct AutomatonWeight<A> {
     field: Field,
     automaton: Arc<A>,
+    max_expansions: Option<usize>,
 }
 
 impl<A> AutomatonWeight<A>
@@ -26,27 +30,44 @@ where
         AutomatonWeight {
             field,
             automaton: automaton.into(),
+            max_expansions: None,
         }
     }
 
-    fn automaton_stream<'a>(&'a self, term_dict: &'a TermDictionary) -> TermStreamer<'a, &'a A> {
-        let automaton: &A = &*self.automaton;
-        let term_stream_builder = term_dict.search(automaton);
-        term_stream_builder.into_stream()
+    /// Caps the number of terms the automaton may match within a segment.
+    ///
+    /// If more terms match, building the scorer fails with an
+    /// `InvalidArgument` error.
+    pub fn set_max_expansions(&mut self, max_expansions: usize) {
+        self.max_expansions = Some(max_expansions);
     }
-}
 
-impl<A> Weight for AutomatonWeight<A>
-where
-    A: Automaton + Send + Sync + 'static,
-{
-    fn scorer(&self, reader: &SegmentReader, boost: f32) -> Result<Box<dyn Scorer>> {
+    /// Returns the set of the documents containing a term matched by the automaton,
+    /// or `None` if `is_interrupted` returned true while streaming the term dictionary.
+    fn doc_bitset(
+        &self,
+        reader: &SegmentReader,
+        is_interrupted: &dyn Fn() -> bool,
+    ) -> Result<Option<BitSet>> {
         let max_doc = reader.max_doc();
         let mut doc_bitset = BitSet::with_max_value(max_doc);
         let inverted_index = reader.inverted_index(self.field);
         let term_dict = inverted_index.terms();
         let mut term_stream = self.automaton_stream(term_dict);
+        let mut num_expansions = 0;
         while term_stream.advance() {
+            if num_expansions % CHECK_INTERVAL == 0 && is_interrupted() {
+                return Ok(None);
+            }
+            num_expansions += 1;
+            if let Some(max_expansions) = self.max_expansions {
+                if num_expansions > max_expansions {
+                    return Err(TantivyError::InvalidArgument(format!(
+                        "The query matches more than {} terms",
+                        max_expansions
+                    )));
+                }
+            }
             let term_info = term_stream.value();
             let mut block_segment_postings = inverted_index
                 .read_block_postings_from_terminfo(term_info, IndexRecordOption::Basic);
@@ -61,9 +82,35 @@ where
                 block_segment_postings.advance();
             }
         }
-        let doc_bitset = BitSetDocSet::from(doc_bitset);
-        let const_scorer = ConstScorer::new(doc_bitset, boost);
-        Ok(Box::new(const_scorer))
+        Ok(Some(doc_bitset))
+    }
+
+    fn automaton_stream<'a>(&'a self, term_dict: &'a TermDictionary) -> TermStreamer<'a, &'a A> {
+        let automaton: &A = &*self.automaton;
+        let term_stream_builder = term_dict.search(automaton);
+        term_stream_builder.into_stream()
+    }
+}
+
+impl<A> Weight for AutomatonWeight<A>
+where
+    A: Automaton + Send + Sync + 'static,
+{
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> Result<Box<dyn Scorer>> {
+        let scorer_opt = self.interruptible_scorer(reader, boost, &|| false)?;
+        Ok(scorer_opt.unwrap()) //< safe, as the search is never interrupted
+    }
+
+    fn interruptible_scorer(
+        &self,
+        reader: &SegmentReader,
+        boost: f32,
+        is_interrupted: &dyn Fn() -> bool,
+    ) -> Result<Option<Box<dyn Scorer>>> {
+        Ok(self.doc_bitset(reader, is_interrupted)?.map(|doc_bitset| {
+            let const_scorer = ConstScorer::new(BitSetDocSet::from(doc_bitset), boost);
+            Box::new(const_scorer) as Box<dyn Scorer>
+        }))
     }
 
     fn explain(&self, reader: &SegmentReader, doc: DocId) -> Result<Explanation> {
@@ -154,6 +201,41 @@ mod tests {
         assert_eq!(scorer.advance(), TERMINATED);
     }
 
+    #[test]
+    fn test_automaton_weight_max_expansions() {
+        let index = create_index();
+        let field = index.schema().get_field("title").unwrap();
+        let reader = index.reader().unwrap();
+        let searcher = reader.searcher();
+        let mut automaton_weight = AutomatonWeight::new(field, PrefixedByA);
+        automaton_weight.set_max_expansions(2);
+        assert!(automaton_weight
+            .scorer(searcher.segment_reader(0u32), 1.0f32)
+            .is_ok());
+        automaton_weight.set_max_expansions(1);
+        assert!(automaton_weight
+            .scorer(searcher.segment_reader(0u32), 1.0f32)
+            .is_err());
+    }
+
+    #[test]
+    fn test_automaton_weight_interrupted() {
+        let index = create_index();
+        let field = index.schema().get_field("title").unwrap();
+        let automaton_weight = AutomatonWeight::new(field, PrefixedByA);
+        let reader = index.reader().unwrap();
+        let searcher = reader.searcher();
+        let segment_reader = searcher.segment_reader(0u32);
+        assert!(automaton_weight
+            .interruptible_scorer(segment_reader, 1.0f32, &|| false)
+            .unwrap()
+            .is_some());
+        assert!(automaton_weight
+            .interruptible_scorer(segment_reader, 1.0f32, &|| true)
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_automaton_weight_boost() {
         let index = create_index();
@@ -1,9 +1,14 @@
 use crate::common::{BitSet, TinySet};
 use crate::docset::{DocSet, TERMINATED};
 use crate::DocId;
+use std::borrow::Borrow;
+use std::sync::Arc;
 
 /// A `BitSetDocSet` makes it possible to iterate through a bitset as if it was a `DocSet`.
 ///
+/// The bitset is either owned by the `BitSetDocSet`, or shared
+/// as an `Arc<BitSet>`, e.g. with a cache.
+///
 /// # Implementation detail
 ///
 /// Skipping is relatively fast here as we can directly point to the
@@ -11,26 +16,27 @@ use crate::DocId;
 ///
 /// TODO: Consider implementing a `BitTreeSet` in order to advance faster
 /// when the bitset is sparse
-pub struct BitSetDocSet {
-    docs: BitSet,
+pub struct BitSetDocSet<TBitSet: Borrow<BitSet> = BitSet> {
+    docs: TBitSet,
     cursor_bucket: u32, //< index associated to the current tiny bitset
     cursor_tinybitset: TinySet,
     doc: u32,
 }
 
 impl BitSetDocSet {
-    fn go_to_bucket(&mut self, bucket_addr: u32) {
-        self.cursor_bucket = bucket_addr;
-        self.cursor_tinybitset = self.docs.tinyset(bucket_addr);
+    /// Returns the underlying `BitSet`, regardless of the
+    /// position of the `DocSet`.
+    pub(crate) fn into_bitset(self) -> BitSet {
+        self.docs
     }
 }
 
-impl From<BitSet> for BitSetDocSet {
-    fn from(docs: BitSet) -> BitSetDocSet {
-        let first_tiny_bitset = if docs.max_value() == 0 {
+impl<TBitSet: Borrow<BitSet>> BitSetDocSet<TBitSet> {
+    fn new(docs: TBitSet) -> BitSetDocSet<TBitSet> {
+        let first_tiny_bitset = if docs.borrow().max_value() == 0 {
             TinySet::empty()
         } else {
-            docs.tinyset(0)
+            docs.borrow().tinyset(0)
         };
         let mut docset = BitSetDocSet {
             docs,
@@ -41,15 +47,36 @@ impl From<BitSet> for BitSetDocSet {
         docset.advance();
         docset
     }
+
+    fn go_to_bucket(&mut self, bucket_addr: u32) {
+        self.cursor_bucket = bucket_addr;
+        self.cursor_tinybitset = self.docs.borrow().tinyset(bucket_addr);
+    }
 }
 
-impl DocSet for BitSetDocSet {
+impl From<BitSet> for BitSetDocSet {
+    fn from(docs: BitSet) -> BitSetDocSet {
+        BitSetDocSet::new(docs)
+    }
+}
+
+impl From<Arc<BitSet>> for BitSetDocSet<Arc<BitSet>> {
+    fn from(docs: Arc<BitSet>) -> BitSetDocSet<Arc<BitSet>> {
+        BitSetDocSet::new(docs)
+    }
+}
+
+impl<TBitSet: Borrow<BitSet>> DocSet for BitSetDocSet<TBitSet> {
     fn advance(&mut self) -> DocId {
         if let Some(lower) = self.cursor_tinybitset.pop_lowest() {
             self.doc = (self.cursor_bucket as u32 * 64u32) | lower;
             return self.doc;
         }
-        if let Some(cursor_bucket) = self.docs.first_non_empty_bucket(self.cursor_bucket + 1) {
+        if let Some(cursor_bucket) = self
+            .docs
+            .borrow()
+            .first_non_empty_bucket(self.cursor_bucket + 1)
+        {
             self.go_to_bucket(cursor_bucket);
             let lower = self.cursor_tinybitset.pop_lowest().unwrap();
             self.doc = (cursor_bucket * 64u32) | lower;
@@ -61,7 +88,7 @@ impl DocSet for BitSetDocSet {
     }
 
     fn seek(&mut self, target: DocId) -> DocId {
-        if target >= self.docs.max_value() {
+        if target >= self.docs.borrow().max_value() {
             self.doc = TERMINATED;
             return TERMINATED;
         }
@@ -90,7 +117,7 @@ impl DocSet for BitSetDocSet {
     /// but we don't have access to any better
     /// value.
     fn size_hint(&self) -> u32 {
-        self.docs.len() as u32
+        self.docs.borrow().len() as u32
     }
 }
 
@@ -0,0 +1,208 @@
+use crate::docset::{DocSet, TERMINATED};
+use crate::query::term_query::TermScorer;
+use crate::query::Scorer;
+use crate::{DocId, Score};
+
+struct TermScorerWithMaxScore {
+    scorer: TermScorer,
+    max_score: Score,
+}
+
+impl From<TermScorer> for TermScorerWithMaxScore {
+    fn from(scorer: TermScorer) -> TermScorerWithMaxScore {
+        let max_score = scorer.max_score();
+        TermScorerWithMaxScore { scorer, max_score }
+    }
+}
+
+/// Finds the pivot, that is the first document that may have a
+/// score exceeding the threshold, given the max score of each term.
+///
+/// Returns the number of scorers positioned on a document lower or equal
+/// to the pivot, and the pivot document.
+///
+/// The scorers are expected to be sorted by their current document.
+fn find_pivot_doc(scorers: &[TermScorerWithMaxScore], threshold: Score) -> Option<(usize, DocId)> {
+    let mut max_score = 0f32;
+    let mut pivot_len = 0;
+    for scorer in scorers {
+        max_score += scorer.max_score;
+        pivot_len += 1;
+        if max_score > threshold {
+            let pivot_doc = scorer.scorer.doc();
+            // All of the scorers positioned on the pivot take part to its score.
+            pivot_len += scorers[pivot_len..]
+                .iter()
+                .take_while(|scorer| scorer.scorer.doc() == pivot_doc)
+                .count();
+            return Some((pivot_len, pivot_doc));
+        }
+    }
+    None
+}
+
+/// Skips the documents that cannot beat the threshold, after the block max scores
+/// showed that none of the documents within `[pivot_doc, next_target)` can.
+///
+/// Only the scorer with the highest max score is advanced: the others are left
+/// positioned where they are, and will be moved once they become relevant again.
+fn advance_past_blocks(scorers: &mut Vec<TermScorerWithMaxScore>, pivot_len: usize) {
+    let mut next_target = scorers[..pivot_len]
+        .iter()
+        .map(|scorer| scorer.scorer.last_doc_in_block())
+        .min()
+        .unwrap_or(TERMINATED)
+        .saturating_add(1);
+    if let Some(scorer) = scorers.get(pivot_len) {
+        next_target = next_target.min(scorer.scorer.doc());
+    }
+    let scorer_ord = (0..pivot_len)
+        .max_by(|&left, &right| {
+            scorers[left]
+                .max_score
+                .partial_cmp(&scorers[right].max_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(0);
+    if scorers[scorer_ord].scorer.seek(next_target) == TERMINATED {
+        scorers.swap_remove(scorer_ord);
+    }
+}
+
+/// Calls `callback` with the `(doc, score)` of the documents matching
+/// any of the `scorers`, with a score exceeding the threshold.
+///
+/// This implements Block-Max WAND: each term has an upper bound of its score,
+/// for the whole segment and for each of its blocks of documents. The documents for
+/// which the sum of these bounds does not exceed the threshold are skipped,
+/// and when possible, without decoding the blocks they belong to.
+///
+/// As for `for_each_pruning_scorer`, `callback` returns the new threshold.
+pub(crate) fn block_wand(
+    scorers: Vec<TermScorer>,
+    mut threshold: Score,
+    callback: &mut dyn FnMut(DocId, Score) -> Score,
+) {
+    let mut scorers: Vec<TermScorerWithMaxScore> = scorers
+        .into_iter()
+        .filter(|scorer| scorer.doc() != TERMINATED)
+        .map(TermScorerWithMaxScore::from)
+        .collect();
+    loop {
+        // Scorers that were only shallow seeked are still positioned on a
+        // document lower than their actual next match, which is fine as their
+        // documents before the last pivot were shown not to be competitive.
+        scorers.sort_by_key(|scorer| scorer.scorer.doc());
+        let (pivot_len, pivot_doc) = match find_pivot_doc(&scorers, threshold) {
+            Some(pivot) => pivot,
+            None => return,
+        };
+
+        let block_max_score_upperbound: Score = scorers[..pivot_len]
+            .iter_mut()
+            .map(|scorer| {
+                scorer.scorer.shallow_seek(pivot_doc);
+                scorer.scorer.block_max_score()
+            })
+            .sum();
+        if block_max_score_upperbound <= threshold {
+            advance_past_blocks(&mut scorers, pivot_len);
+            continue;
+        }
+
+        // Documents before the pivot cannot beat the threshold.
+        let mut is_aligned = true;
+        for scorer in &mut scorers[..pivot_len] {
+            if scorer.scorer.seek(pivot_doc) != pivot_doc {
+                is_aligned = false;
+            }
+        }
+        if is_aligned {
+            let score: Score = scorers[..pivot_len]
+                .iter_mut()
+                .map(|scorer| scorer.scorer.score())
+                .sum();
+            if score > threshold {
+                threshold = callback(pivot_doc, score);
+            }
+            for scorer in &mut scorers[..pivot_len] {
+                scorer.scorer.advance();
+            }
+        }
+        scorers.retain(|scorer| scorer.scorer.doc() != TERMINATED);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collector::TopDocs;
+    use crate::query::{BooleanQuery, Occur, Query, TermQuery};
+    use crate::schema::{IndexRecordOption, Schema, TEXT};
+    use crate::{DocAddress, Index, Score, Term};
+
+    fn create_index() -> Index {
+        let mut schema_builder = Schema::builder();
+        let text_field = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        let mut rand = 17u32;
+        for _ in 0..5_000 {
+            let mut tokens = Vec::new();
+            for _ in 0..(rand % 20) {
+                rand = rand.wrapping_mul(1_103_515_245).wrapping_add(12_345) % (1 << 31);
+                tokens.push(["a", "b", "c", "d", "e", "f", "g", "h"][(rand % 8) as usize]);
+                rand = rand.wrapping_mul(1_103_515_245).wrapping_add(12_345) % (1 << 31);
+                if rand % 5 == 0 {
+                    // makes the term "z" rare.
+                    tokens.push("z");
+                }
+            }
+            index_writer.add_document(doc!(text_field => tokens.join(" ")));
+        }
+        index_writer.commit().unwrap();
+        index
+    }
+
+    fn check_top_docs(index: &Index, terms: &[&str], limit: usize) {
+        let text_field = index.schema().get_field("text").unwrap();
+        let query = BooleanQuery::from(
+            terms
+                .iter()
+                .map(|text| {
+                    let query: Box<dyn Query> = Box::new(TermQuery::new(
+                        Term::from_field_text(text_field, text),
+                        IndexRecordOption::WithFreqs,
+                    ));
+                    (Occur::Should, query)
+                })
+                .collect::<Vec<_>>(),
+        );
+        let searcher = index.reader().unwrap().searcher();
+        let top_docs: Vec<(Score, DocAddress)> = searcher
+            .search(&query, &TopDocs::with_limit(limit))
+            .unwrap();
+        let weight = query.weight(&searcher, true).unwrap();
+        let mut expected: Vec<Score> = Vec::new();
+        weight
+            .for_each(searcher.segment_reader(0), &mut |_, score| {
+                expected.push(score)
+            })
+            .unwrap();
+        expected.sort_by(|left, right| right.partial_cmp(left).unwrap());
+        expected.truncate(limit);
+        assert_eq!(top_docs.len(), expected.len());
+        for ((score, _), expected_score) in top_docs.iter().zip(expected.iter()) {
+            assert!((score - expected_score).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_block_wand_same_top_docs_as_exhaustive_search() {
+        let index = create_index();
+        check_top_docs(&index, &["a", "b"], 10);
+        check_top_docs(&index, &["a", "z"], 10);
+        check_top_docs(&index, &["a", "b", "c", "z"], 3);
+        check_top_docs(&index, &["c", "d", "e", "f", "g"], 100);
+        check_top_docs(&index, &["z", "y"], 10_000);
+    }
+}
@@ -20,6 +20,10 @@ use std::collections::BTreeSet;
 /// * match at least one of the subqueries that is not
 /// a `MustNot` occurence.
 ///
+/// Optionally, a minimum number of `Should` subqueries to match can be set
+/// with [`set_minimum_should_match`](#method.set_minimum_should_match).
+/// In that case, the `Should` subqueries become required, even in the presence
+/// of `Must` subqueries.
 ///
 /// You can combine other query types and their `Occur`ances into one `BooleanQuery`
 ///
@@ -129,21 +133,29 @@ use std::collections::BTreeSet;
 #[derive(Debug)]
 pub struct BooleanQuery {
     subqueries: Vec<(Occur, Box<dyn Query>)>,
+    minimum_should_match: usize,
 }
 
 impl Clone for BooleanQuery {
     fn clone(&self) -> Self {
-        self.subqueries
+        let subqueries = self
+            .subqueries
             .iter()
             .map(|(occur, subquery)| (*occur, subquery.box_clone()))
-            .collect::<Vec<_>>()
-            .into()
+            .collect::<Vec<_>>();
+        BooleanQuery {
+            subqueries,
+            minimum_should_match: self.minimum_should_match,
+        }
     }
 }
 
 impl From<Vec<(Occur, Box<dyn Query>)>> for BooleanQuery {
     fn from(subqueries: Vec<(Occur, Box<dyn Query>)>) -> BooleanQuery {
-        BooleanQuery { subqueries }
+        BooleanQuery {
+            subqueries,
+            minimum_should_match: 0,
+        }
     }
 }
 
@@ -156,7 +168,11 @@ impl Query for BooleanQuery {
                 Ok((*occur, subquery.weight(searcher, scoring_enabled)?))
             })
             .collect::<crate::Result<_>>()?;
-        Ok(Box::new(BooleanWeight::new(sub_weights, scoring_enabled)))
+        Ok(Box::new(BooleanWeight::new(
+            sub_weights,
+            self.minimum_should_match,
+            scoring_enabled,
+        )))
     }
 
     fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
@@ -181,6 +197,22 @@ impl BooleanQuery {
         BooleanQuery::from(occur_term_queries)
     }
 
+    /// Sets the minimum number of `Should` subqueries a document
+    /// is required to match.
+    ///
+    /// The default value, `0`, means that `Should` subqueries are optional
+    /// if the query has `Must` subqueries, and that at least one of them
+    /// should match otherwise.
+    pub fn set_minimum_should_match(&mut self, minimum_should_match: usize) {
+        self.minimum_should_match = minimum_should_match;
+    }
+
+    /// Returns the minimum number of `Should` subqueries a document
+    /// is required to match.
+    pub fn minimum_should_match(&self) -> usize {
+        self.minimum_should_match
+    }
+
     /// Deconstructed view of the clauses making up this query.
     pub fn clauses(&self) -> &[(Occur, Box<dyn Query>)] {
         &self.subqueries[..]
@@ -1,8 +1,11 @@
 use crate::core::SegmentReader;
+use crate::query::boolean_query::block_wand::block_wand;
+use crate::query::disjunction::Disjunction;
 use crate::query::explanation::does_not_match;
+use crate::query::intersection::intersect_scorers_without_scoring;
 use crate::query::score_combiner::{DoNothingCombiner, ScoreCombiner, SumWithCoordsCombiner};
 use crate::query::term_query::TermScorer;
-use crate::query::weight::{for_each_pruning_scorer, for_each_scorer};
+use crate::query::weight::{for_each_pruning_scorer, for_each_scorer, for_each_until_scorer};
 use crate::query::EmptyScorer;
 use crate::query::Exclude;
 use crate::query::Occur;
@@ -14,12 +17,12 @@ use crate::query::{intersect_scorers, Explanation};
 use crate::{DocId, Score};
 use std::collections::HashMap;
 
-enum SpecializedScorer<TScoreCombiner: ScoreCombiner> {
-    TermUnion(Union<TermScorer, TScoreCombiner>),
+enum SpecializedScorer {
+    TermUnion(Vec<TermScorer>),
     Other(Box<dyn Scorer>),
 }
 
-fn scorer_union<TScoreCombiner>(scorers: Vec<Box<dyn Scorer>>) -> SpecializedScorer<TScoreCombiner>
+fn scorer_union<TScoreCombiner>(scorers: Vec<Box<dyn Scorer>>) -> SpecializedScorer
 where
     TScoreCombiner: ScoreCombiner,
 {
@@ -35,32 +38,36 @@ where
                 .into_iter()
                 .map(|scorer| *(scorer.downcast::<TermScorer>().map_err(|_| ()).unwrap()))
                 .collect();
-            return SpecializedScorer::TermUnion(Union::<TermScorer, TScoreCombiner>::from(
-                scorers,
-            ));
+            return SpecializedScorer::TermUnion(scorers);
         }
     }
     SpecializedScorer::Other(Box::new(Union::<_, TScoreCombiner>::from(scorers)))
 }
 
-impl<TScoreCombiner: ScoreCombiner> Into<Box<dyn Scorer>> for SpecializedScorer<TScoreCombiner> {
-    fn into(self) -> Box<dyn Scorer> {
-        match self {
-            Self::TermUnion(union) => Box::new(union),
-            Self::Other(scorer) => scorer,
+fn into_box_scorer<TScoreCombiner: ScoreCombiner>(scorer: SpecializedScorer) -> Box<dyn Scorer> {
+    match scorer {
+        SpecializedScorer::TermUnion(term_scorers) => {
+            Box::new(Union::<TermScorer, TScoreCombiner>::from(term_scorers))
         }
+        SpecializedScorer::Other(scorer) => scorer,
     }
 }
 
 pub struct BooleanWeight {
     weights: Vec<(Occur, Box<dyn Weight>)>,
+    minimum_should_match: usize,
     scoring_enabled: bool,
 }
 
 impl BooleanWeight {
-    pub fn new(weights: Vec<(Occur, Box<dyn Weight>)>, scoring_enabled: bool) -> BooleanWeight {
+    pub fn new(
+        weights: Vec<(Occur, Box<dyn Weight>)>,
+        minimum_should_match: usize,
+        scoring_enabled: bool,
+    ) -> BooleanWeight {
         BooleanWeight {
             weights,
+            minimum_should_match,
             scoring_enabled,
         }
     }
@@ -69,89 +76,151 @@ impl BooleanWeight {
         &self,
         reader: &SegmentReader,
         boost: f32,
-    ) -> crate::Result<HashMap<Occur, Vec<Box<dyn Scorer>>>> {
+        is_interrupted: &dyn Fn() -> bool,
+    ) -> crate::Result<Option<HashMap<Occur, Vec<Box<dyn Scorer>>>>> {
         let mut per_occur_scorers: HashMap<Occur, Vec<Box<dyn Scorer>>> = HashMap::new();
         for &(ref occur, ref subweight) in &self.weights {
-            let sub_scorer: Box<dyn Scorer> = subweight.scorer(reader, boost)?;
+            let sub_scorer: Box<dyn Scorer> =
+                match subweight.interruptible_scorer(reader, boost, is_interrupted)? {
+                    Some(sub_scorer) => sub_scorer,
+                    None => return Ok(None),
+                };
             per_occur_scorers
                 .entry(*occur)
                 .or_insert_with(Vec::new)
                 .push(sub_scorer);
         }
-        Ok(per_occur_scorers)
+        Ok(Some(per_occur_scorers))
     }
 
     fn complex_scorer<TScoreCombiner: ScoreCombiner>(
         &self,
         reader: &SegmentReader,
         boost: f32,
-    ) -> crate::Result<SpecializedScorer<TScoreCombiner>> {
-        let mut per_occur_scorers = self.per_occur_scorers(reader, boost)?;
+    ) -> crate::Result<SpecializedScorer> {
+        let scorer_opt =
+            self.interruptible_complex_scorer::<TScoreCombiner>(reader, boost, &|| false)?;
+        Ok(scorer_opt.unwrap()) //< safe, as the search is never interrupted
+    }
+
+    fn interruptible_complex_scorer<TScoreCombiner: ScoreCombiner>(
+        &self,
+        reader: &SegmentReader,
+        boost: f32,
+        is_interrupted: &dyn Fn() -> bool,
+    ) -> crate::Result<Option<SpecializedScorer>> {
+        let mut per_occur_scorers = match self.per_occur_scorers(reader, boost, is_interrupted)? {
+            Some(per_occur_scorers) => per_occur_scorers,
+            None => return Ok(None),
+        };
 
-        let should_scorer_opt: Option<SpecializedScorer<TScoreCombiner>> = per_occur_scorers
+        if self.minimum_should_match > 0 {
+            let should_scorers = per_occur_scorers
+                .remove(&Occur::Should)
+                .unwrap_or_else(Vec::new);
+            if should_scorers.len() < self.minimum_should_match {
+                return Ok(Some(SpecializedScorer::Other(Box::new(EmptyScorer))));
+            }
+            let should_scorer: Box<dyn Scorer> = if self.minimum_should_match == 1 {
+                into_box_scorer::<TScoreCombiner>(scorer_union::<TScoreCombiner>(should_scorers))
+            } else {
+                Box::new(Disjunction::<_, TScoreCombiner>::new(
+                    should_scorers,
+                    self.minimum_should_match,
+                ))
+            };
+            // The `Should` clauses are now required.
+            per_occur_scorers
+                .entry(Occur::Must)
+                .or_insert_with(Vec::new)
+                .push(should_scorer);
+        }
+
+        let should_scorer_opt: Option<SpecializedScorer> = per_occur_scorers
             .remove(&Occur::Should)
             .map(scorer_union::<TScoreCombiner>);
 
-        let exclude_scorer_opt: Option<Box<dyn Scorer>> = per_occur_scorers
-            .remove(&Occur::MustNot)
-            .map(scorer_union::<DoNothingCombiner>)
-            .map(Into::into);
-
-        let must_scorer_opt: Option<Box<dyn Scorer>> = per_occur_scorers
-            .remove(&Occur::Must)
-            .map(intersect_scorers);
-
-        let positive_scorer: SpecializedScorer<TScoreCombiner> =
-            match (should_scorer_opt, must_scorer_opt) {
-                (Some(should_scorer), Some(must_scorer)) => {
-                    if self.scoring_enabled {
-                        SpecializedScorer::Other(Box::new(RequiredOptionalScorer::<
-                            Box<dyn Scorer>,
-                            Box<dyn Scorer>,
-                            TScoreCombiner,
-                        >::new(
-                            must_scorer, should_scorer.into()
-                        )))
-                    } else {
-                        SpecializedScorer::Other(must_scorer)
-                    }
+        let exclude_scorers_opt: Option<Vec<Box<dyn Scorer>>> =
+            per_occur_scorers.remove(&Occur::MustNot);
+
+        let must_scorer_opt: Option<Box<dyn Scorer>> =
+            per_occur_scorers.remove(&Occur::Must).map(|must_scorers| {
+                if self.scoring_enabled {
+                    intersect_scorers(must_scorers)
+                } else {
+                    intersect_scorers_without_scoring(must_scorers, reader.max_doc())
                 }
-                (None, Some(must_scorer)) => SpecializedScorer::Other(must_scorer),
-                (Some(should_scorer), None) => should_scorer,
-                (None, None) => {
-                    return Ok(SpecializedScorer::Other(Box::new(EmptyScorer)));
+            });
+
+        let positive_scorer: SpecializedScorer = match (should_scorer_opt, must_scorer_opt) {
+            (Some(should_scorer), Some(must_scorer)) => {
+                if self.scoring_enabled {
+                    SpecializedScorer::Other(Box::new(RequiredOptionalScorer::<
+                        Box<dyn Scorer>,
+                        Box<dyn Scorer>,
+                        TScoreCombiner,
+                    >::new(
+                        must_scorer,
+                        into_box_scorer::<TScoreCombiner>(should_scorer),
+                    )))
+                } else {
+                    SpecializedScorer::Other(must_scorer)
                 }
-            };
+            }
+            (None, Some(must_scorer)) => SpecializedScorer::Other(must_scorer),
+            (Some(should_scorer), None) => should_scorer,
+            (None, None) => {
+                return Ok(Some(SpecializedScorer::Other(Box::new(EmptyScorer))));
+            }
+        };
 
-        if let Some(exclude_scorer) = exclude_scorer_opt {
-            let positive_scorer_boxed: Box<dyn Scorer> = positive_scorer.into();
-            Ok(SpecializedScorer::Other(Box::new(Exclude::new(
-                positive_scorer_boxed,
-                exclude_scorer,
+        if let Some(exclude_scorers) = exclude_scorers_opt {
+            let positive_scorer_boxed: Box<dyn Scorer> =
+                into_box_scorer::<TScoreCombiner>(positive_scorer);
+            Ok(Some(SpecializedScorer::Other(Box::new(
+                Exclude::with_excluding_docsets(positive_scorer_boxed, exclude_scorers),
             ))))
         } else {
-            Ok(positive_scorer)
+            Ok(Some(positive_scorer))
         }
     }
 }
 
 impl Weight for BooleanWeight {
     fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>> {
+        let scorer_opt = self.interruptible_scorer(reader, boost, &|| false)?;
+        Ok(scorer_opt.unwrap()) //< safe, as the search is never interrupted
+    }
+
+    fn interruptible_scorer(
+        &self,
+        reader: &SegmentReader,
+        boost: f32,
+        is_interrupted: &dyn Fn() -> bool,
+    ) -> crate::Result<Option<Box<dyn Scorer>>> {
         if self.weights.is_empty() {
-            Ok(Box::new(EmptyScorer))
-        } else if self.weights.len() == 1 {
+            Ok(Some(Box::new(EmptyScorer)))
+        } else if self.weights.len() == 1 && self.minimum_should_match <= 1 {
             let &(occur, ref weight) = &self.weights[0];
             if occur == Occur::MustNot {
-                Ok(Box::new(EmptyScorer))
+                Ok(Some(Box::new(EmptyScorer)))
             } else {
-                weight.scorer(reader, boost)
+                weight.interruptible_scorer(reader, boost, is_interrupted)
             }
         } else if self.scoring_enabled {
-            self.complex_scorer::<SumWithCoordsCombiner>(reader, boost)
-                .map(Into::into)
+            let scorer_opt = self.interruptible_complex_scorer::<SumWithCoordsCombiner>(
+                reader,
+                boost,
+                is_interrupted,
+            )?;
+            Ok(scorer_opt.map(into_box_scorer::<SumWithCoordsCombiner>))
         } else {
-            self.complex_scorer::<DoNothingCombiner>(reader, boost)
-                .map(Into::into)
+            let scorer_opt = self.interruptible_complex_scorer::<DoNothingCombiner>(
+                reader,
+                boost,
+                is_interrupted,
+            )?;
+            Ok(scorer_opt.map(into_box_scorer::<DoNothingCombiner>))
         }
     }
 
@@ -182,7 +251,9 @@ impl Weight for BooleanWeight {
     ) -> crate::Result<()> {
         let scorer = self.complex_scorer::<SumWithCoordsCombiner>(reader, 1.0f32)?;
         match scorer {
-            SpecializedScorer::TermUnion(mut union_scorer) => {
+            SpecializedScorer::TermUnion(term_scorers) => {
+                let mut union_scorer =
+                    Union::<TermScorer, SumWithCoordsCombiner>::from(term_scorers);
                 for_each_scorer(&mut union_scorer, callback);
             }
             SpecializedScorer::Other(mut scorer) => {
@@ -192,6 +263,25 @@ impl Weight for BooleanWeight {
         Ok(())
     }
 
+    fn for_each_until(
+        &self,
+        reader: &SegmentReader,
+        callback: &mut dyn FnMut(DocId, Score) -> bool,
+    ) -> crate::Result<()> {
+        let scorer = self.complex_scorer::<SumWithCoordsCombiner>(reader, 1.0f32)?;
+        match scorer {
+            SpecializedScorer::TermUnion(term_scorers) => {
+                let mut union_scorer =
+                    Union::<TermScorer, SumWithCoordsCombiner>::from(term_scorers);
+                for_each_until_scorer(&mut union_scorer, callback);
+            }
+            SpecializedScorer::Other(mut scorer) => {
+                for_each_until_scorer(scorer.as_mut(), callback);
+            }
+        }
+        Ok(())
+    }
+
     /// Calls `callback` with all of the `(doc, score)` for which score
     /// is exceeding a given threshold.
     ///
@@ -210,8 +300,8 @@ impl Weight for BooleanWeight {
     ) -> crate::Result<()> {
         let scorer = self.complex_scorer::<SumWithCoordsCombiner>(reader, 1.0f32)?;
         match scorer {
-            SpecializedScorer::TermUnion(mut union_scorer) => {
-                for_each_pruning_scorer(&mut union_scorer, threshold, callback);
+            SpecializedScorer::TermUnion(term_scorers) => {
+                block_wand(term_scorers, threshold, callback);
             }
             SpecializedScorer::Other(mut scorer) => {
                 for_each_pruning_scorer(scorer.as_mut(), threshold, callback);
@@ -1,3 +1,4 @@
+mod block_wand;
 mod boolean_query;
 mod boolean_weight;
 
@@ -22,6 +23,7 @@ mod tests {
     use crate::schema::*;
     use crate::Index;
     use crate::{DocAddress, DocId, Score};
+    use std::cell::Cell;
 
     fn aux_test_helper() -> (Index, Field) {
         let mut schema_builder = Schema::builder();
@@ -52,6 +54,63 @@ mod tests {
         assert_eq!(query.count(&searcher).unwrap(), 3);
     }
 
+    #[test]
+    pub fn test_boolean_minimum_should_match() {
+        let (index, text_field) = aux_test_helper();
+        let searcher = index.reader().unwrap().searcher();
+        let term_query = |text: &str| -> Box<dyn Query> {
+            Box::new(TermQuery::new(
+                Term::from_field_text(text_field, text),
+                IndexRecordOption::Basic,
+            ))
+        };
+        let matching_docs = |query: &BooleanQuery| -> Vec<DocId> {
+            searcher
+                .search(query, &TEST_COLLECTOR_WITH_SCORE)
+                .unwrap()
+                .docs()
+                .iter()
+                .map(|doc| doc.1)
+                .collect::<Vec<DocId>>()
+        };
+        let mut should_query = BooleanQuery::from(vec![
+            (Occur::Should, term_query("a")),
+            (Occur::Should, term_query("b")),
+            (Occur::Should, term_query("d")),
+        ]);
+        assert_eq!(matching_docs(&should_query), vec![0, 1, 2, 3, 4]);
+        should_query.set_minimum_should_match(2);
+        assert_eq!(matching_docs(&should_query), vec![0, 3]);
+        should_query.set_minimum_should_match(3);
+        assert_eq!(matching_docs(&should_query), vec![3]);
+        should_query.set_minimum_should_match(4);
+        assert!(matching_docs(&should_query).is_empty());
+
+        let mut must_should_query = BooleanQuery::from(vec![
+            (Occur::Must, term_query("c")),
+            (Occur::Should, term_query("a")),
+            (Occur::Should, term_query("d")),
+            (Occur::MustNot, term_query("b")),
+        ]);
+        assert_eq!(matching_docs(&must_should_query), vec![1]);
+        must_should_query.set_minimum_should_match(1);
+        assert_eq!(matching_docs(&must_should_query), vec![1]);
+        let mut must_should_query = BooleanQuery::from(vec![
+            (Occur::Must, term_query("c")),
+            (Occur::Should, term_query("a")),
+            (Occur::Should, term_query("d")),
+        ]);
+        assert_eq!(matching_docs(&must_should_query), vec![0, 1, 2, 3]);
+        must_should_query.set_minimum_should_match(1);
+        assert_eq!(matching_docs(&must_should_query), vec![0, 1, 3]);
+        must_should_query.set_minimum_should_match(2);
+        assert_eq!(matching_docs(&must_should_query), vec![3]);
+
+        let mut single_should_query = BooleanQuery::from(vec![(Occur::Should, term_query("a"))]);
+        single_should_query.set_minimum_should_match(2);
+        assert!(matching_docs(&single_should_query).is_empty());
+    }
+
     #[test]
     pub fn test_boolean_single_must_clause() {
         let (index, text_field) = aux_test_helper();
@@ -65,6 +124,35 @@ mod tests {
         assert!(scorer.is::<TermScorer>());
     }
 
+    #[test]
+    pub fn test_boolean_interruptible_scorer() {
+        let (index, text_field) = aux_test_helper();
+        let query_parser = QueryParser::for_index(&index, vec![text_field]);
+        let query = query_parser.parse_query("+a +(b c) -d").unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let weight = query.weight(&searcher, true).unwrap();
+        let segment_reader = searcher.segment_reader(0u32);
+        let num_checks = Cell::new(0);
+        let scorer_opt = weight
+            .interruptible_scorer(segment_reader, 1.0f32, &|| {
+                num_checks.set(num_checks.get() + 1);
+                false
+            })
+            .unwrap();
+        assert!(scorer_opt.is_some());
+        // The interruption is checked before building the scorer of each term.
+        assert_eq!(num_checks.get(), 4);
+        num_checks.set(0);
+        let scorer_opt = weight
+            .interruptible_scorer(segment_reader, 1.0f32, &|| {
+                num_checks.set(num_checks.get() + 1);
+                num_checks.get() > 2
+            })
+            .unwrap();
+        assert!(scorer_opt.is_none());
+        assert_eq!(num_checks.get(), 3);
+    }
+
     #[test]
     pub fn test_boolean_termonly_intersection() {
         let (index, text_field) = aux_test_helper();
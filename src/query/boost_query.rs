@@ -69,6 +69,16 @@ impl Weight for BoostWeight {
         self.weight.scorer(reader, boost * self.boost)
     }
 
+    fn interruptible_scorer(
+        &self,
+        reader: &SegmentReader,
+        boost: f32,
+        is_interrupted: &dyn Fn() -> bool,
+    ) -> crate::Result<Option<Box<dyn Scorer>>> {
+        self.weight
+            .interruptible_scorer(reader, boost * self.boost, is_interrupted)
+    }
+
     fn explain(&self, reader: &SegmentReader, doc: u32) -> crate::Result<Explanation> {
         let mut scorer = self.scorer(reader, 1.0f32)?;
         if scorer.seek(doc) != doc {
@@ -0,0 +1,154 @@
+use crate::query::explanation::does_not_match;
+use crate::query::{BitSetDocSet, ConstScorer, Explanation, Query, Scorer, Weight};
+use crate::{DocId, DocSet, Searcher, SegmentReader, Term};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// `ConstScoreQuery` is a wrapper over a query, giving the same score
+/// to all of the documents it matches.
+///
+/// The document set matched by the `ConstScoreQuery` is strictly the same as
+/// the underlying query. The underlying query is never scored: its weight is built
+/// with scoring disabled, so that term frequencies and fieldnorms are not read.
+///
+/// This is the query to use for filters, e.g. as a `Must` clause of a `BooleanQuery`.
+pub struct ConstScoreQuery {
+    query: Box<dyn Query>,
+    score: f32,
+}
+
+impl ConstScoreQuery {
+    /// Builds a const score query.
+    pub fn new(query: Box<dyn Query>, score: f32) -> ConstScoreQuery {
+        ConstScoreQuery { query, score }
+    }
+}
+
+impl Clone for ConstScoreQuery {
+    fn clone(&self) -> Self {
+        ConstScoreQuery {
+            query: self.query.box_clone(),
+            score: self.score,
+        }
+    }
+}
+
+impl fmt::Debug for ConstScoreQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ConstScore(query={:?}, score={})",
+            self.query, self.score
+        )
+    }
+}
+
+impl Query for ConstScoreQuery {
+    fn weight(&self, searcher: &Searcher, scoring_enabled: bool) -> crate::Result<Box<dyn Weight>> {
+        let weight_without_scoring = self.query.weight(searcher, false)?;
+        let const_weight = if scoring_enabled {
+            Box::new(ConstWeight::new(weight_without_scoring, self.score))
+        } else {
+            weight_without_scoring
+        };
+        Ok(const_weight)
+    }
+
+    fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
+        self.query.query_terms(term_set)
+    }
+}
+
+struct ConstWeight {
+    weight: Box<dyn Weight>,
+    score: f32,
+}
+
+impl ConstWeight {
+    fn new(weight: Box<dyn Weight>, score: f32) -> Self {
+        ConstWeight { weight, score }
+    }
+}
+
+impl Weight for ConstWeight {
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>> {
+        let scorer = self.weight.scorer(reader, 1.0f32)?;
+        let score = self.score * boost;
+        // Bitset scorers are kept as such, so that their intersections
+        // are still computed with bitwise ANDs.
+        match scorer.downcast::<ConstScorer<BitSetDocSet>>() {
+            Ok(bitset_scorer) => Ok(Box::new(ConstScorer::new(
+                bitset_scorer.into_docset(),
+                score,
+            ))),
+            Err(scorer) => Ok(Box::new(ConstScorer::new(scorer, score))),
+        }
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> crate::Result<Explanation> {
+        let mut scorer = self.weight.scorer(reader, 1.0f32)?;
+        if scorer.seek(doc) != doc {
+            return Err(does_not_match(doc));
+        }
+        Ok(Explanation::new("ConstScoreQuery", self.score))
+    }
+
+    fn count(&self, reader: &SegmentReader) -> crate::Result<u32> {
+        self.weight.count(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstScoreQuery;
+    use crate::collector::{Count, TopDocs};
+    use crate::query::{BooleanQuery, Occur, Query, TermQuery};
+    use crate::schema::{IndexRecordOption, Schema, TEXT};
+    use crate::{DocAddress, Index, Term};
+
+    #[test]
+    fn test_const_score_query() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(text => "a b"));
+        index_writer.add_document(doc!(text => "a a a c"));
+        index_writer.add_document(doc!(text => "b c"));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let term_query = |text_val: &str| -> Box<dyn Query> {
+            Box::new(TermQuery::new(
+                Term::from_field_text(text, text_val),
+                IndexRecordOption::WithFreqs,
+            ))
+        };
+        let const_score_query = ConstScoreQuery::new(term_query("a"), 2.0f32);
+        assert_eq!(searcher.search(&const_score_query, &Count)?, 2);
+        let top_docs = searcher.search(&const_score_query, &TopDocs::with_limit(10))?;
+        assert_eq!(
+            top_docs,
+            vec![(2.0f32, DocAddress(0, 0)), (2.0f32, DocAddress(0, 1))]
+        );
+        let explanation = const_score_query.explain(&searcher, DocAddress(0, 1))?;
+        assert_eq!(explanation.value(), 2.0f32);
+        assert!(const_score_query
+            .explain(&searcher, DocAddress(0, 2))
+            .is_err());
+
+        // As a filter, the const score query does not change the ranking
+        // of the other clauses.
+        let filtered_query = BooleanQuery::from(vec![
+            (Occur::Must, term_query("c")),
+            (
+                Occur::Must,
+                Box::new(ConstScoreQuery::new(term_query("a"), 0.0f32)) as Box<dyn Query>,
+            ),
+        ]);
+        let filtered_top_docs = searcher.search(&filtered_query, &TopDocs::with_limit(10))?;
+        let unfiltered_top_docs = searcher.search(&term_query("c"), &TopDocs::with_limit(10))?;
+        assert_eq!(filtered_top_docs.len(), 1);
+        assert!(unfiltered_top_docs.contains(&filtered_top_docs[0]));
+        Ok(())
+    }
+}
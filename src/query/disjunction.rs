@@ -0,0 +1,218 @@
+use crate::docset::{DocSet, TERMINATED};
+use crate::query::score_combiner::ScoreCombiner;
+use crate::query::Scorer;
+use crate::DocId;
+use crate::Score;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Wraps a scorer so that the `BinaryHeap` pops
+/// the scorer with the lowest current doc first.
+struct ScorerWrapper<TScorer> {
+    scorer: TScorer,
+}
+
+impl<TScorer: Scorer> PartialEq for ScorerWrapper<TScorer> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scorer.doc() == other.scorer.doc()
+    }
+}
+
+impl<TScorer: Scorer> Eq for ScorerWrapper<TScorer> {}
+
+impl<TScorer: Scorer> PartialOrd for ScorerWrapper<TScorer> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<TScorer: Scorer> Ord for ScorerWrapper<TScorer> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.scorer.doc().cmp(&self.scorer.doc())
+    }
+}
+
+/// Creates a `DocSet` that iterates through the documents matching
+/// at least `minimum_matches_required` of the given `Scorer`s.
+///
+/// The score of a document is given by the `ScoreCombiner`
+/// over the matching scorers.
+pub struct Disjunction<TScorer, TScoreCombiner> {
+    scorers: BinaryHeap<ScorerWrapper<TScorer>>,
+    minimum_matches_required: usize,
+    score_combiner: TScoreCombiner,
+    doc: DocId,
+    score: Score,
+}
+
+impl<TScorer: Scorer, TScoreCombiner: ScoreCombiner> Disjunction<TScorer, TScoreCombiner> {
+    /// Creates a new `Disjunction`.
+    pub fn new(
+        scorers: Vec<TScorer>,
+        minimum_matches_required: usize,
+    ) -> Disjunction<TScorer, TScoreCombiner> {
+        Disjunction::with_score_combiner(
+            scorers,
+            minimum_matches_required,
+            TScoreCombiner::default(),
+        )
+    }
+
+    /// Creates a new `Disjunction`, combining the scores with the given `ScoreCombiner`.
+    pub fn with_score_combiner(
+        scorers: Vec<TScorer>,
+        minimum_matches_required: usize,
+        score_combiner: TScoreCombiner,
+    ) -> Disjunction<TScorer, TScoreCombiner> {
+        assert!(
+            minimum_matches_required >= 1,
+            "At least one scorer is required to match."
+        );
+        let scorers: BinaryHeap<ScorerWrapper<TScorer>> = scorers
+            .into_iter()
+            .filter(|scorer| scorer.doc() != TERMINATED)
+            .map(|scorer| ScorerWrapper { scorer })
+            .collect();
+        let mut disjunction = Disjunction {
+            scorers,
+            minimum_matches_required,
+            score_combiner,
+            doc: TERMINATED,
+            score: 0f32,
+        };
+        if minimum_matches_required > disjunction.scorers.len() {
+            disjunction.scorers.clear();
+            return disjunction;
+        }
+        disjunction.advance();
+        disjunction
+    }
+}
+
+impl<TScorer: Scorer, TScoreCombiner: ScoreCombiner> DocSet
+    for Disjunction<TScorer, TScoreCombiner>
+{
+    fn advance(&mut self) -> DocId {
+        let mut num_matches = 0;
+        while let Some(mut wrapper) = self.scorers.pop() {
+            let candidate = wrapper.scorer.doc();
+            if candidate != self.doc {
+                if num_matches >= self.minimum_matches_required {
+                    self.scorers.push(wrapper);
+                    self.score = self.score_combiner.score();
+                    return self.doc;
+                }
+                num_matches = 0;
+                self.doc = candidate;
+                self.score_combiner.clear();
+            }
+            num_matches += 1;
+            self.score_combiner.update(&mut wrapper.scorer);
+            if wrapper.scorer.advance() != TERMINATED {
+                self.scorers.push(wrapper);
+            }
+        }
+        if num_matches < self.minimum_matches_required {
+            self.doc = TERMINATED;
+        }
+        self.score = self.score_combiner.score();
+        self.doc
+    }
+
+    fn doc(&self) -> DocId {
+        self.doc
+    }
+
+    fn size_hint(&self) -> u32 {
+        self.scorers
+            .iter()
+            .map(|wrapper| wrapper.scorer.size_hint())
+            .max()
+            .unwrap_or(0u32)
+    }
+}
+
+impl<TScorer: Scorer, TScoreCombiner: ScoreCombiner> Scorer
+    for Disjunction<TScorer, TScoreCombiner>
+{
+    fn score(&mut self) -> Score {
+        self.score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Disjunction;
+    use crate::docset::{DocSet, TERMINATED};
+    use crate::postings::tests::test_skip_against_unoptimized;
+    use crate::query::score_combiner::{DoNothingCombiner, SumCombiner};
+    use crate::query::{ConstScorer, Scorer, VecDocSet};
+    use crate::DocId;
+    use std::collections::BTreeMap;
+
+    fn aux_test_disjunction(vals: Vec<Vec<DocId>>, minimum_matches_required: usize) {
+        let mut counts: BTreeMap<DocId, usize> = BTreeMap::new();
+        for docs in &vals {
+            for &doc in docs {
+                *counts.entry(doc).or_insert(0) += 1;
+            }
+        }
+        let expected: Vec<(DocId, f32)> = counts
+            .into_iter()
+            .filter(|&(_, count)| count >= minimum_matches_required)
+            .map(|(doc, count)| (doc, count as f32))
+            .collect();
+        let mut disjunction = Disjunction::<_, SumCombiner>::new(
+            vals.iter()
+                .cloned()
+                .map(VecDocSet::from)
+                .map(ConstScorer::from)
+                .collect::<Vec<_>>(),
+            minimum_matches_required,
+        );
+        let mut docs = Vec::new();
+        while disjunction.doc() != TERMINATED {
+            docs.push((disjunction.doc(), disjunction.score()));
+            disjunction.advance();
+        }
+        assert_eq!(docs, expected);
+        assert_eq!(disjunction.advance(), TERMINATED);
+    }
+
+    #[test]
+    fn test_disjunction() {
+        let vals = vec![
+            vec![1, 3, 5, 100],
+            vec![1, 2, 5, 99, 100],
+            vec![0, 5, 6, 100],
+            vec![],
+        ];
+        for minimum_matches_required in 1..5 {
+            aux_test_disjunction(vals.clone(), minimum_matches_required);
+        }
+    }
+
+    #[test]
+    fn test_disjunction_skip() {
+        let docs_list = vec![
+            vec![1, 3, 5, 8, 10],
+            vec![2, 3, 8, 11],
+            vec![3, 4, 9, 10, 11],
+        ];
+        test_skip_against_unoptimized(
+            || {
+                Box::new(Disjunction::<_, DoNothingCombiner>::new(
+                    docs_list
+                        .iter()
+                        .cloned()
+                        .map(VecDocSet::from)
+                        .map(ConstScorer::from)
+                        .collect::<Vec<_>>(),
+                    2,
+                ))
+            },
+            vec![0, 3, 4, 8, 9, 10, 11, 12],
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use crate::query::disjunction::Disjunction;
+use crate::query::explanation::does_not_match;
+use crate::query::score_combiner::{DisjunctionMaxCombiner, DoNothingCombiner};
+use crate::query::{EmptyScorer, Explanation, Query, Scorer, Union, Weight};
+use crate::{DocId, DocSet, Score, Searcher, SegmentReader, Term};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// `DisjunctionMaxQuery` matches the documents matched by any of its
+/// sub-queries, the disjuncts.
+///
+/// The score of a document is the maximum of the scores of the disjuncts
+/// matching it, plus the sum of the other scores multiplied by a `tie_breaker`.
+///
+/// This is the usual way to search for the same terms in several fields:
+/// unlike a `BooleanQuery`, a document matching the terms in many fields
+/// does not get a higher score than a document matching them in its best field.
+/// A small `tie_breaker` (e.g. 0.1) still favors the documents matching in several fields.
+///
+/// ```rust
+/// use tantivy::collector::Count;
+/// use tantivy::query::{DisjunctionMaxQuery, Query, TermQuery};
+/// use tantivy::schema::{IndexRecordOption, Schema, TEXT};
+/// use tantivy::{doc, Index, Term};
+///
+/// # fn main() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let body = schema_builder.add_text_field("body", TEXT);
+/// let index = Index::create_in_ram(schema_builder.build());
+/// let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+/// index_writer.add_document(doc!(title => "The Diary of Muadib", body => "dune"));
+/// index_writer.add_document(doc!(title => "A Dairy Cow", body => "diary"));
+/// index_writer.add_document(doc!(title => "The Name of the Wind"));
+/// index_writer.commit()?;
+/// let searcher = index.reader()?.searcher();
+///
+/// let disjuncts: Vec<Box<dyn Query>> = vec![title, body]
+///     .into_iter()
+///     .map(|field| -> Box<dyn Query> {
+///         Box::new(TermQuery::new(
+///             Term::from_field_text(field, "diary"),
+///             IndexRecordOption::WithFreqs,
+///         ))
+///     })
+///     .collect();
+/// let query = DisjunctionMaxQuery::with_tie_breaker(disjuncts, 0.1);
+/// assert_eq!(searcher.search(&query, &Count)?, 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct DisjunctionMaxQuery {
+    disjuncts: Vec<Box<dyn Query>>,
+    tie_breaker: Score,
+}
+
+impl DisjunctionMaxQuery {
+    /// Builds a disjunction max query, scoring the documents
+    /// with the maximum score of their matching disjuncts.
+    pub fn new(disjuncts: Vec<Box<dyn Query>>) -> DisjunctionMaxQuery {
+        DisjunctionMaxQuery::with_tie_breaker(disjuncts, 0f32)
+    }
+
+    /// Builds a disjunction max query, adding the scores of the other
+    /// matching disjuncts multiplied by `tie_breaker` to the maximum score.
+    pub fn with_tie_breaker(
+        disjuncts: Vec<Box<dyn Query>>,
+        tie_breaker: Score,
+    ) -> DisjunctionMaxQuery {
+        DisjunctionMaxQuery {
+            disjuncts,
+            tie_breaker,
+        }
+    }
+}
+
+impl Clone for DisjunctionMaxQuery {
+    fn clone(&self) -> Self {
+        DisjunctionMaxQuery {
+            disjuncts: self
+                .disjuncts
+                .iter()
+                .map(|disjunct| disjunct.box_clone())
+                .collect(),
+            tie_breaker: self.tie_breaker,
+        }
+    }
+}
+
+impl fmt::Debug for DisjunctionMaxQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DisjunctionMax(disjuncts={:?}, tie_breaker={})",
+            self.disjuncts, self.tie_breaker
+        )
+    }
+}
+
+impl Query for DisjunctionMaxQuery {
+    fn weight(&self, searcher: &Searcher, scoring_enabled: bool) -> crate::Result<Box<dyn Weight>> {
+        let weights = self
+            .disjuncts
+            .iter()
+            .map(|disjunct| disjunct.weight(searcher, scoring_enabled))
+            .collect::<crate::Result<Vec<_>>>()?;
+        Ok(Box::new(DisjunctionMaxWeight {
+            weights,
+            tie_breaker: self.tie_breaker,
+            scoring_enabled,
+        }))
+    }
+
+    fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
+        for disjunct in &self.disjuncts {
+            disjunct.query_terms(term_set);
+        }
+    }
+}
+
+struct DisjunctionMaxWeight {
+    weights: Vec<Box<dyn Weight>>,
+    tie_breaker: Score,
+    scoring_enabled: bool,
+}
+
+impl Weight for DisjunctionMaxWeight {
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>> {
+        let mut scorers = self
+            .weights
+            .iter()
+            .map(|weight| weight.scorer(reader, boost))
+            .collect::<crate::Result<Vec<_>>>()?;
+        if scorers.len() <= 1 {
+            return Ok(scorers.pop().unwrap_or_else(|| Box::new(EmptyScorer)));
+        }
+        if !self.scoring_enabled {
+            return Ok(Box::new(Union::<_, DoNothingCombiner>::from(scorers)));
+        }
+        Ok(Box::new(Disjunction::with_score_combiner(
+            scorers,
+            1,
+            DisjunctionMaxCombiner::with_tie_breaker(self.tie_breaker),
+        )))
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> crate::Result<Explanation> {
+        let mut scorer = self.scorer(reader, 1.0f32)?;
+        if scorer.seek(doc) != doc {
+            return Err(does_not_match(doc));
+        }
+        if !self.scoring_enabled {
+            return Ok(Explanation::new(
+                "DisjunctionMaxQuery with no scoring",
+                1f32,
+            ));
+        }
+        let mut explanation = Explanation::new(
+            format!(
+                "DisjunctionMaxQuery. Max plus {} times the others of ...",
+                self.tie_breaker
+            ),
+            scorer.score(),
+        );
+        for weight in &self.weights {
+            if let Ok(child_explanation) = weight.explain(reader, doc) {
+                explanation.add_detail(child_explanation);
+            }
+        }
+        Ok(explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisjunctionMaxQuery;
+    use crate::collector::{Count, TopDocs};
+    use crate::query::{BooleanQuery, Occur, Query, TermQuery};
+    use crate::schema::{Field, IndexRecordOption, Schema, TEXT};
+    use crate::{DocAddress, Index, Searcher, Term};
+
+    fn term_query(field: Field, text: &str) -> Box<dyn Query> {
+        Box::new(TermQuery::new(
+            Term::from_field_text(field, text),
+            IndexRecordOption::WithFreqs,
+        ))
+    }
+
+    fn score(searcher: &Searcher, query: &dyn Query, doc_address: DocAddress) -> f32 {
+        query.explain(searcher, doc_address).unwrap().value()
+    }
+
+    #[test]
+    fn test_disjunction_max_query() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field("title", TEXT);
+        let body = schema_builder.add_text_field("body", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(title => "rust", body => "rust"));
+        index_writer.add_document(doc!(title => "rust", body => "go"));
+        index_writer.add_document(doc!(title => "go", body => "rust"));
+        index_writer.add_document(doc!(title => "go", body => "go"));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+
+        let disjuncts = || vec![term_query(title, "rust"), term_query(body, "rust")];
+        let title_score = score(&searcher, &*term_query(title, "rust"), DocAddress(0, 0));
+        let body_score = score(&searcher, &*term_query(body, "rust"), DocAddress(0, 0));
+        let max_score = title_score.max(body_score);
+        let min_score = title_score.min(body_score);
+
+        let dismax_query = DisjunctionMaxQuery::new(disjuncts());
+        assert_eq!(searcher.search(&dismax_query, &Count)?, 3);
+        assert_eq!(score(&searcher, &dismax_query, DocAddress(0, 0)), max_score);
+        assert_eq!(
+            score(&searcher, &dismax_query, DocAddress(0, 1)),
+            title_score
+        );
+        assert_eq!(
+            score(&searcher, &dismax_query, DocAddress(0, 2)),
+            body_score
+        );
+        assert!(dismax_query.explain(&searcher, DocAddress(0, 3)).is_err());
+
+        let dismax_query = DisjunctionMaxQuery::with_tie_breaker(disjuncts(), 0.5);
+        let expected_score = max_score + 0.5 * min_score;
+        assert!((score(&searcher, &dismax_query, DocAddress(0, 0)) - expected_score).abs() < 1e-5);
+        let top_docs = searcher.search(&dismax_query, &TopDocs::with_limit(10))?;
+        assert_eq!(top_docs.len(), 3);
+        assert_eq!(top_docs[0].1, DocAddress(0, 0));
+
+        // A tie breaker of 1 sums the scores, like a boolean query.
+        let dismax_query = DisjunctionMaxQuery::with_tie_breaker(disjuncts(), 1.0);
+        let boolean_query = BooleanQuery::from(
+            disjuncts()
+                .into_iter()
+                .map(|disjunct| (Occur::Should, disjunct))
+                .collect::<Vec<_>>(),
+        );
+        assert!(
+            (score(&searcher, &dismax_query, DocAddress(0, 0))
+                - score(&searcher, &boolean_query, DocAddress(0, 0)))
+            .abs()
+                < 1e-5
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_disjunction_max_query_no_disjuncts() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field("title", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(title => "rust"));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let dismax_query = DisjunctionMaxQuery::new(vec![]);
+        assert_eq!(searcher.search(&dismax_query, &Count)?, 0);
+        let dismax_query = DisjunctionMaxQuery::new(vec![term_query(title, "rust")]);
+        assert_eq!(searcher.search(&dismax_query, &Count)?, 1);
+        Ok(())
+    }
+}
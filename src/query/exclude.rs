@@ -8,12 +8,17 @@ fn is_within<TDocSetExclude: DocSet>(docset: &mut TDocSetExclude, doc: DocId) ->
     docset.doc() <= doc && docset.seek(doc) == doc
 }
 
-/// Filters a given `DocSet` by removing the docs from a given `DocSet`.
+#[inline(always)]
+fn is_within_any<TDocSetExclude: DocSet>(docsets: &mut [TDocSetExclude], doc: DocId) -> bool {
+    docsets.iter_mut().any(|docset| is_within(docset, doc))
+}
+
+/// Filters a given `DocSet` by removing the docs from one or more `DocSet`s.
 ///
-/// The excluding docset has no impact on scoring.
+/// The excluding docsets have no impact on scoring.
 pub struct Exclude<TDocSet, TDocSetExclude> {
     underlying_docset: TDocSet,
-    excluding_docset: TDocSetExclude,
+    excluding_docsets: Vec<TDocSetExclude>,
 }
 
 impl<TDocSet, TDocSetExclude> Exclude<TDocSet, TDocSetExclude>
@@ -23,19 +28,31 @@ where
 {
     /// Creates a new `ExcludeScorer`
     pub fn new(
+        underlying_docset: TDocSet,
+        excluding_docset: TDocSetExclude,
+    ) -> Exclude<TDocSet, TDocSetExclude> {
+        Exclude::with_excluding_docsets(underlying_docset, vec![excluding_docset])
+    }
+
+    /// Creates a new `ExcludeScorer` removing the docs of any
+    /// of the excluding docsets.
+    ///
+    /// Each excluding docset is simply skipped forward, which is cheaper than
+    /// building the `Union` of the excluding docsets.
+    pub fn with_excluding_docsets(
         mut underlying_docset: TDocSet,
-        mut excluding_docset: TDocSetExclude,
+        mut excluding_docsets: Vec<TDocSetExclude>,
     ) -> Exclude<TDocSet, TDocSetExclude> {
         while underlying_docset.doc() != TERMINATED {
             let target = underlying_docset.doc();
-            if !is_within(&mut excluding_docset, target) {
+            if !is_within_any(&mut excluding_docsets, target) {
                 break;
             }
             underlying_docset.advance();
         }
         Exclude {
             underlying_docset,
-            excluding_docset,
+            excluding_docsets,
         }
     }
 }
@@ -51,7 +68,7 @@ where
             if candidate == TERMINATED {
                 return TERMINATED;
             }
-            if !is_within(&mut self.excluding_docset, candidate) {
+            if !is_within_any(&mut self.excluding_docsets, candidate) {
                 return candidate;
             }
         }
@@ -62,7 +79,7 @@ where
         if candidate == TERMINATED {
             return TERMINATED;
         }
-        if !is_within(&mut self.excluding_docset, candidate) {
+        if !is_within_any(&mut self.excluding_docsets, candidate) {
             return candidate;
         }
         self.advance()
@@ -112,6 +129,40 @@ mod tests {
         assert_eq!(els, vec![5, 8, 15]);
     }
 
+    #[test]
+    fn test_exclude_several_docsets() {
+        let mut exclude_scorer = Exclude::with_excluding_docsets(
+            VecDocSet::from(vec![1, 2, 5, 8, 10, 15, 24]),
+            vec![
+                VecDocSet::from(vec![1, 3, 10]),
+                VecDocSet::from(vec![2, 16, 24]),
+                VecDocSet::from(vec![]),
+            ],
+        );
+        let mut els = vec![];
+        while exclude_scorer.doc() != TERMINATED {
+            els.push(exclude_scorer.doc());
+            exclude_scorer.advance();
+        }
+        assert_eq!(els, vec![5, 8, 15]);
+    }
+
+    #[test]
+    fn test_exclude_several_docsets_skip() {
+        test_skip_against_unoptimized(
+            || {
+                Box::new(Exclude::with_excluding_docsets(
+                    VecDocSet::from(vec![1, 2, 5, 8, 10, 15, 24]),
+                    vec![
+                        VecDocSet::from(vec![1, 3, 10]),
+                        VecDocSet::from(vec![2, 16, 24]),
+                    ],
+                ))
+            },
+            vec![5, 8, 10, 15, 24],
+        );
+    }
+
     #[test]
     fn test_exclude_skip() {
         test_skip_against_unoptimized(
@@ -0,0 +1,326 @@
+use crate::common::BitSet;
+use crate::core::Searcher;
+use crate::core::SegmentReader;
+use crate::error::TantivyError;
+use crate::indexer::json_term_writer::{JsonTermWriter, JSON_END_OF_PATH, JSON_PATH_SEGMENT_SEP};
+use crate::query::explanation::does_not_match;
+use crate::query::ConstScorer;
+use crate::query::{BitSetDocSet, Explanation};
+use crate::query::{Query, Scorer, Weight};
+use crate::schema::{Field, FieldType, IndexRecordOption};
+use crate::DocId;
+use crate::Result;
+
+/// `ExistsQuery` matches all of the documents that have at least one value
+/// for a given field.
+///
+/// Matched documents all get a constant `Score` of one.
+///
+/// # Implementation
+///
+/// If the field is a fast field, the documents are read from the fast field.
+/// Single-valued fast fields keep track of the documents without any value,
+/// and a bytes fast field is considered as missing if it is empty.
+///
+/// Otherwise, the field needs to be indexed. The query then iterates over all
+/// of the terms of the field, and appends all of their documents into a `BitSet`.
+///
+/// For json object fields, `ExistsQuery::new_json_path` matches the documents
+/// having a value at a given path, or at any path below it.
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::collector::Count;
+/// use tantivy::query::ExistsQuery;
+/// use tantivy::schema::{Schema, FAST, TEXT};
+/// use tantivy::{doc, Index};
+/// # fn test() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let rating = schema_builder.add_u64_field("rating", FAST);
+/// let schema = schema_builder.build();
+///
+/// let index = Index::create_in_ram(schema);
+/// let mut index_writer = index.writer_with_num_threads(1, 6_000_000)?;
+/// index_writer.add_document(doc!(title => "The Name of the Wind", rating => 5u64));
+/// index_writer.add_document(doc!(title => "The Diary of Muadib"));
+/// index_writer.add_document(doc!(rating => 0u64));
+/// index_writer.commit()?;
+///
+/// let searcher = index.reader()?.searcher();
+/// assert_eq!(searcher.search(&ExistsQuery::new(title), &Count)?, 2);
+/// assert_eq!(searcher.search(&ExistsQuery::new(rating), &Count)?, 2);
+/// # Ok(())
+/// # }
+/// # assert!(test().is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExistsQuery {
+    field: Field,
+    json_path: Option<String>,
+}
+
+impl ExistsQuery {
+    /// Creates a new `ExistsQuery` matching the documents that have
+    /// at least one value for the field.
+    pub fn new(field: Field) -> ExistsQuery {
+        ExistsQuery {
+            field,
+            json_path: None,
+        }
+    }
+
+    /// Creates a new `ExistsQuery` matching the documents that have a value
+    /// at the given path (e.g. `attributes.status`) of a json object field.
+    pub fn new_json_path(field: Field, json_path: &str) -> ExistsQuery {
+        ExistsQuery {
+            field,
+            json_path: Some(json_path.to_string()),
+        }
+    }
+
+    /// Field to search over.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+}
+
+fn is_fast(field_type: &FieldType) -> bool {
+    match *field_type {
+        FieldType::U64(ref options)
+        | FieldType::I64(ref options)
+        | FieldType::F64(ref options)
+        | FieldType::Date(ref options)
+        | FieldType::Bool(ref options) => options.is_fast(),
+        FieldType::Str(ref options) => options.is_fast(),
+        FieldType::Bytes(ref options) => options.is_fast(),
+        FieldType::HierarchicalFacet => true,
+        FieldType::IpAddr(_) | FieldType::JsonObject(_) => false,
+    }
+}
+
+impl Query for ExistsQuery {
+    fn weight(&self, searcher: &Searcher, _scoring_enabled: bool) -> Result<Box<dyn Weight>> {
+        let schema = searcher.schema();
+        let field_entry = schema.get_field_entry(self.field);
+        let field_type = field_entry.field_type();
+        let is_json_object = matches!(*field_type, FieldType::JsonObject(_));
+        if self.json_path.is_some() && !is_json_object {
+            return Err(TantivyError::SchemaError(format!(
+                "Field {:?} is not a json object field.",
+                field_entry.name()
+            )));
+        }
+        let use_fast_field = self.json_path.is_none() && is_fast(field_type);
+        if !use_fast_field && !field_entry.is_indexed() {
+            return Err(TantivyError::SchemaError(format!(
+                "Field {:?} is neither indexed nor a fast field.",
+                field_entry.name()
+            )));
+        }
+        let json_path_prefix = self.json_path.as_ref().map(|json_path| {
+            JsonTermWriter::from_json_path(self.field, json_path)
+                .term()
+                .value_bytes()
+                .to_vec()
+        });
+        Ok(Box::new(ExistsWeight {
+            field: self.field,
+            use_fast_field,
+            json_path_prefix,
+        }))
+    }
+}
+
+/// Weight associated to the `ExistsQuery` query.
+pub struct ExistsWeight {
+    field: Field,
+    use_fast_field: bool,
+    json_path_prefix: Option<Vec<u8>>,
+}
+
+impl ExistsWeight {
+    fn insert_fast_field_docs(&self, reader: &SegmentReader, doc_bitset: &mut BitSet) {
+        let fast_fields = reader.fast_fields();
+        let max_doc = reader.max_doc();
+        if let Some(fast_field_reader) = fast_fields.u64_lenient(self.field) {
+            for doc in (0..max_doc).filter(|&doc| fast_field_reader.has_value(doc)) {
+                doc_bitset.insert(doc);
+            }
+        } else if let Some(fast_field_reader) = fast_fields.u64s_lenient(self.field) {
+            for doc in (0..max_doc).filter(|&doc| fast_field_reader.num_vals(doc) > 0) {
+                doc_bitset.insert(doc);
+            }
+        } else if let Some(fast_field_reader) = fast_fields.bytes(self.field) {
+            for doc in (0..max_doc).filter(|&doc| !fast_field_reader.get_bytes(doc).is_empty()) {
+                doc_bitset.insert(doc);
+            }
+        }
+    }
+
+    fn insert_indexed_docs(&self, reader: &SegmentReader, doc_bitset: &mut BitSet) {
+        let inverted_index = reader.inverted_index(self.field);
+        let term_dict = inverted_index.terms();
+        let mut term_stream = match self.json_path_prefix {
+            Some(ref json_path_prefix) => {
+                // The path is followed either by the end of path marker,
+                // or by the separator of a child path.
+                let mut lower_bound = json_path_prefix.clone();
+                lower_bound.push(JSON_END_OF_PATH);
+                let mut upper_bound = json_path_prefix.clone();
+                upper_bound.push(JSON_PATH_SEGMENT_SEP + 1);
+                term_dict
+                    .range()
+                    .ge(&lower_bound)
+                    .lt(&upper_bound)
+                    .into_stream()
+            }
+            None => term_dict.stream(),
+        };
+        while term_stream.advance() {
+            let term_info = term_stream.value();
+            let mut block_segment_postings = inverted_index
+                .read_block_postings_from_terminfo(term_info, IndexRecordOption::Basic);
+            loop {
+                let docs = block_segment_postings.docs();
+                if docs.is_empty() {
+                    break;
+                }
+                for &doc in docs {
+                    doc_bitset.insert(doc);
+                }
+                block_segment_postings.advance();
+            }
+        }
+    }
+}
+
+impl Weight for ExistsWeight {
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> Result<Box<dyn Scorer>> {
+        let mut doc_bitset = BitSet::with_max_value(reader.max_doc());
+        if self.use_fast_field {
+            self.insert_fast_field_docs(reader, &mut doc_bitset);
+        } else {
+            self.insert_indexed_docs(reader, &mut doc_bitset);
+        }
+        let doc_bitset = BitSetDocSet::from(doc_bitset);
+        Ok(Box::new(ConstScorer::new(doc_bitset, boost)))
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> Result<Explanation> {
+        let mut scorer = self.scorer(reader, 1.0f32)?;
+        if scorer.seek(doc) != doc {
+            return Err(does_not_match(doc));
+        }
+        Ok(Explanation::new("ExistsQuery", 1.0f32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExistsQuery;
+    use crate::collector::{Count, TopDocs};
+    use crate::schema::{
+        Cardinality, IntOptions, Schema, Value, FAST, INDEXED, STORED, STRING, TEXT,
+    };
+    use crate::{DocAddress, Index, TantivyError};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_exists_query() {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field("title", TEXT);
+        let tag = schema_builder.add_text_field("tag", STRING | FAST);
+        let rating = schema_builder.add_u64_field("rating", FAST);
+        let year = schema_builder.add_i64_field("year", INDEXED);
+        let scores = schema_builder.add_f64_field(
+            "scores",
+            IntOptions::default().set_fast(Cardinality::MultiValues),
+        );
+        let stored = schema_builder.add_u64_field("stored", STORED);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        index_writer.add_document(doc!(title=>"a", rating=>0u64, scores=>1.0f64));
+        index_writer.add_document(doc!(tag=>"b", year=>0i64));
+        index_writer.add_document(doc!(rating=>3u64, year=>2000i64, stored=>1u64));
+        index_writer.commit().unwrap();
+        index_writer.add_document(doc!(tag=>"c", scores=>2.0f64, scores=>3.0f64));
+        index_writer.add_document(doc!(title=>"d"));
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let matching_docs = |query: &ExistsQuery| -> HashSet<(u32, u32)> {
+            searcher
+                .search(query, &TopDocs::with_limit(10))
+                .unwrap()
+                .into_iter()
+                .map(|(_, DocAddress(segment_ord, doc))| (segment_ord, doc))
+                .collect()
+        };
+        assert_eq!(
+            matching_docs(&ExistsQuery::new(title)),
+            [(0, 0), (1, 1)].iter().cloned().collect()
+        );
+        assert_eq!(
+            matching_docs(&ExistsQuery::new(tag)),
+            [(0, 1), (1, 0)].iter().cloned().collect()
+        );
+        assert_eq!(
+            matching_docs(&ExistsQuery::new(rating)),
+            [(0, 0), (0, 2)].iter().cloned().collect()
+        );
+        assert_eq!(
+            matching_docs(&ExistsQuery::new(year)),
+            [(0, 1), (0, 2)].iter().cloned().collect()
+        );
+        assert_eq!(
+            matching_docs(&ExistsQuery::new(scores)),
+            [(0, 0), (1, 0)].iter().cloned().collect()
+        );
+        assert!(matches!(
+            searcher.search(&ExistsQuery::new(stored), &Count),
+            Err(TantivyError::SchemaError(_))
+        ));
+        assert!(matches!(
+            searcher.search(&ExistsQuery::new_json_path(title, "a"), &Count),
+            Err(TantivyError::SchemaError(_))
+        ));
+    }
+
+    #[test]
+    fn test_exists_query_json_path() {
+        let mut schema_builder = Schema::builder();
+        let attributes = schema_builder.add_json_object_field("attributes", TEXT);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for json in &[
+            r#"{"status": 200, "host": {"name": "db"}}"#,
+            r#"{"status": "ok"}"#,
+            r#"{"statuses": [1, 2]}"#,
+            r#"{"host": "web"}"#,
+        ] {
+            let json_object = serde_json::from_str(json).unwrap();
+            index_writer.add_document(doc!(attributes=>Value::JsonObject(json_object)));
+        }
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let count = |json_path: &str| {
+            searcher
+                .search(&ExistsQuery::new_json_path(attributes, json_path), &Count)
+                .unwrap()
+        };
+        assert_eq!(count("status"), 2);
+        assert_eq!(count("statuses"), 1);
+        assert_eq!(count("host"), 2);
+        assert_eq!(count("host.name"), 1);
+        assert_eq!(count("missing"), 0);
+        assert_eq!(
+            searcher
+                .search(&ExistsQuery::new(attributes), &Count)
+                .unwrap(),
+            4
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use crate::common::BitSet;
+use crate::core::SegmentId;
+use crate::docset::{DocSet, TERMINATED};
+use crate::query::explanation::does_not_match;
+use crate::query::{BitSetDocSet, ConstScorer, Explanation, Query, Scorer, Weight};
+use crate::{DocId, Opstamp, Searcher, SegmentReader, Term};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+struct CacheEntry {
+    delete_opstamp: Option<Opstamp>,
+    docs: Arc<BitSet>,
+    last_access: u64,
+}
+
+struct FilterCacheInner {
+    capacity: usize,
+    entries: HashMap<(String, SegmentId), CacheEntry>,
+    num_accesses: u64,
+}
+
+impl FilterCacheInner {
+    fn get(
+        &mut self,
+        key: &(String, SegmentId),
+        delete_opstamp: Option<Opstamp>,
+    ) -> Option<Arc<BitSet>> {
+        self.num_accesses += 1;
+        let num_accesses = self.num_accesses;
+        let entry = self.entries.get_mut(key)?;
+        if entry.delete_opstamp != delete_opstamp {
+            // The deletes of the segment changed since the entry was computed.
+            self.entries.remove(key);
+            return None;
+        }
+        entry.last_access = num_accesses;
+        Some(entry.docs.clone())
+    }
+
+    fn insert(
+        &mut self,
+        key: (String, SegmentId),
+        delete_opstamp: Option<Opstamp>,
+        docs: Arc<BitSet>,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let least_recently_used_key_opt = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_access)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recently_used_key) = least_recently_used_key_opt {
+                self.entries.remove(&least_recently_used_key);
+            }
+        }
+        self.entries.insert(
+            key,
+            CacheEntry {
+                delete_opstamp,
+                docs,
+                last_access: self.num_accesses,
+            },
+        );
+    }
+}
+
+/// Cache of the documents matched by filters, per segment.
+///
+/// The cache holds at most `capacity` bitsets, one per pair of
+/// a filter and a segment, and evicts the least recently used ones.
+///
+/// The bitsets only contain the documents that are not deleted. They are
+/// recomputed when the deletes of their segment change.
+///
+/// Clones of a `FilterCache` share the same entries, so that a single cache
+/// can be used by all of the `CachedFilterQuery` of an application.
+#[derive(Clone)]
+pub struct FilterCache {
+    inner: Arc<Mutex<FilterCacheInner>>,
+}
+
+impl FilterCache {
+    /// Creates a cache holding at most `capacity` bitsets.
+    pub fn with_capacity(capacity: usize) -> FilterCache {
+        FilterCache {
+            inner: Arc::new(Mutex::new(FilterCacheInner {
+                capacity,
+                entries: HashMap::new(),
+                num_accesses: 0,
+            })),
+        }
+    }
+
+    /// Returns the number of bitsets in the cache.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    /// Returns true if the cache does not contain any bitset.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all of the bitsets from the cache.
+    pub fn clear(&self) {
+        self.inner.lock().unwrap().entries.clear();
+    }
+
+    fn get_or_compute(
+        &self,
+        filter_key: &str,
+        reader: &SegmentReader,
+        weight: &dyn Weight,
+    ) -> crate::Result<Arc<BitSet>> {
+        let key = (filter_key.to_string(), reader.segment_id());
+        let delete_opstamp = reader.segment_meta().delete_opstamp();
+        if let Some(docs) = self.inner.lock().unwrap().get(&key, delete_opstamp) {
+            return Ok(docs);
+        }
+        // The lock is not held while computing the bitset: concurrent searches
+        // may compute the same bitset, in which case the last one wins.
+        let mut docs = BitSet::with_max_value(reader.max_doc());
+        let mut scorer = weight.scorer(reader, 1.0f32)?;
+        let delete_bitset_opt = reader.delete_bitset();
+        let mut doc = scorer.doc();
+        while doc != TERMINATED {
+            if delete_bitset_opt.map_or(true, |delete_bitset| delete_bitset.is_alive(doc)) {
+                docs.insert(doc);
+            }
+            doc = scorer.advance();
+        }
+        let docs = Arc::new(docs);
+        self.inner
+            .lock()
+            .unwrap()
+            .insert(key, delete_opstamp, docs.clone());
+        Ok(docs)
+    }
+}
+
+impl fmt::Debug for FilterCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let inner = self.inner.lock().unwrap();
+        write!(
+            f,
+            "FilterCache(len={}, capacity={})",
+            inner.entries.len(),
+            inner.capacity
+        )
+    }
+}
+
+/// `CachedFilterQuery` is a wrapper over a query used as a filter, that stores the
+/// documents it matches in a `FilterCache`, so that they are not recomputed
+/// each time the query is run.
+///
+/// The documents matched by the `CachedFilterQuery` are those of the underlying
+/// query. They all get the same score, and the underlying query is never scored.
+///
+/// The entries of the cache are keyed by a `filter_key` given by the caller,
+/// together with the segment. The `CachedFilterQuery` sharing a cache must
+/// use the same key if, and only if, their underlying queries match the same documents.
+pub struct CachedFilterQuery {
+    filter_key: String,
+    query: Box<dyn Query>,
+    cache: FilterCache,
+}
+
+impl CachedFilterQuery {
+    /// Builds a cached filter query, identified in the cache by `filter_key`.
+    pub fn new(filter_key: &str, query: Box<dyn Query>, cache: FilterCache) -> CachedFilterQuery {
+        CachedFilterQuery {
+            filter_key: filter_key.to_string(),
+            query,
+            cache,
+        }
+    }
+}
+
+impl Clone for CachedFilterQuery {
+    fn clone(&self) -> Self {
+        CachedFilterQuery {
+            filter_key: self.filter_key.clone(),
+            query: self.query.box_clone(),
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for CachedFilterQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CachedFilter(filter_key={:?}, query={:?})",
+            self.filter_key, self.query
+        )
+    }
+}
+
+impl Query for CachedFilterQuery {
+    fn weight(
+        &self,
+        searcher: &Searcher,
+        _scoring_enabled: bool,
+    ) -> crate::Result<Box<dyn Weight>> {
+        let weight = self.query.weight(searcher, false)?;
+        Ok(Box::new(CachedFilterWeight {
+            filter_key: self.filter_key.clone(),
+            weight,
+            cache: self.cache.clone(),
+        }))
+    }
+
+    fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
+        self.query.query_terms(term_set)
+    }
+}
+
+struct CachedFilterWeight {
+    filter_key: String,
+    weight: Box<dyn Weight>,
+    cache: FilterCache,
+}
+
+impl Weight for CachedFilterWeight {
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>> {
+        let docs = self
+            .cache
+            .get_or_compute(&self.filter_key, reader, self.weight.as_ref())?;
+        Ok(Box::new(ConstScorer::new(BitSetDocSet::from(docs), boost)))
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> crate::Result<Explanation> {
+        let mut scorer = self.scorer(reader, 1.0f32)?;
+        if scorer.seek(doc) != doc {
+            return Err(does_not_match(doc));
+        }
+        Ok(Explanation::new("CachedFilterQuery", 1.0f32))
+    }
+
+    fn count(&self, reader: &SegmentReader) -> crate::Result<u32> {
+        let docs = self
+            .cache
+            .get_or_compute(&self.filter_key, reader, self.weight.as_ref())?;
+        Ok(docs.len() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CachedFilterQuery, FilterCache};
+    use crate::collector::{Count, TopDocs};
+    use crate::query::{BooleanQuery, Occur, Query, TermQuery};
+    use crate::schema::{IndexRecordOption, Schema, STRING, TEXT};
+    use crate::{DocAddress, Index, Term};
+
+    #[test]
+    fn test_cached_filter_query() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let status = schema_builder.add_text_field("status", STRING);
+        let text = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        for i in 0..100 {
+            index_writer.add_document(doc!(
+                status => if i % 4 == 0 { "published" } else { "draft" },
+                text => if i % 3 == 0 { "a" } else { "b" },
+            ));
+        }
+        index_writer.commit()?;
+        let reader = index.reader()?;
+
+        let cache = FilterCache::with_capacity(10);
+        let filter = || -> Box<dyn Query> {
+            Box::new(CachedFilterQuery::new(
+                "published",
+                Box::new(TermQuery::new(
+                    Term::from_field_text(status, "published"),
+                    IndexRecordOption::Basic,
+                )),
+                cache.clone(),
+            ))
+        };
+        let query = BooleanQuery::from(vec![
+            (
+                Occur::Must,
+                Box::new(TermQuery::new(
+                    Term::from_field_text(text, "a"),
+                    IndexRecordOption::WithFreqs,
+                )) as Box<dyn Query>,
+            ),
+            (Occur::Must, filter()),
+        ]);
+        assert!(cache.is_empty());
+        assert_eq!(reader.searcher().search(&query, &Count)?, 9);
+        assert_eq!(cache.len(), 1);
+        let top_docs = reader.searcher().search(&query, &TopDocs::with_limit(20))?;
+        assert_eq!(top_docs.len(), 9);
+        assert!(top_docs
+            .iter()
+            .all(|&(_, DocAddress(_, doc))| doc % 12 == 0));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(reader.searcher().search(&filter(), &Count)?, 25);
+
+        // The bitset is recomputed once the deletes of the segment changed.
+        index_writer.delete_term(Term::from_field_text(text, "a"));
+        index_writer.commit()?;
+        reader.reload()?;
+        assert_eq!(reader.searcher().search(&query, &Count)?, 0);
+        assert_eq!(reader.searcher().search(&filter(), &Count)?, 16);
+        assert_eq!(cache.len(), 1);
+        cache.clear();
+        assert!(cache.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_cache_lru_eviction() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let status = schema_builder.add_text_field("status", STRING);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        for status_val in &["a", "b", "c"] {
+            index_writer.add_document(doc!(status => *status_val));
+        }
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let cache = FilterCache::with_capacity(2);
+        let term_query = |status_val: &str| {
+            TermQuery::new(
+                Term::from_field_text(status, status_val),
+                IndexRecordOption::Basic,
+            )
+        };
+        let count = |status_val: &str| {
+            let query =
+                CachedFilterQuery::new(status_val, Box::new(term_query(status_val)), cache.clone());
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count("a"), 1);
+        assert_eq!(count("b"), 1);
+        assert_eq!(count("a"), 1);
+        // "b" is the least recently used filter, and gets evicted.
+        assert_eq!(count("c"), 1);
+        assert_eq!(cache.len(), 2);
+        let keys: Vec<String> = cache
+            .inner
+            .lock()
+            .unwrap()
+            .entries
+            .keys()
+            .map(|(filter_key, _)| filter_key.clone())
+            .collect();
+        assert!(keys.contains(&"a".to_string()));
+        assert!(keys.contains(&"c".to_string()));
+        Ok(())
+    }
+}
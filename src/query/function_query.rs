@@ -0,0 +1,245 @@
+use crate::collector::{CustomScorer, CustomSegmentScorer};
+use crate::query::explanation::does_not_match;
+use crate::query::{Explanation, Query, Scorer, Weight};
+use crate::{DocId, DocSet, Score, Searcher, SegmentReader, Term};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// `FunctionQuery` matches the documents of a filter query, and scores them
+/// with a function of their fast fields, e.g. `log(1 + views)`.
+///
+/// The score of the filter query is ignored: its weight is built with scoring disabled.
+/// The function is a [`CustomScorer`](../collector/trait.CustomScorer.html),
+/// the same trait used by `TopDocs::custom_score`. It is given each segment reader,
+/// so that it can open the fast field readers of the segment, and returns a function
+/// computing the score of a document.
+///
+/// Used as a clause of a `BooleanQuery` or a `DisjunctionMaxQuery`,
+/// it mixes document features with the relevance score of the other clauses.
+///
+/// ```rust
+/// use tantivy::collector::TopDocs;
+/// use tantivy::query::{AllQuery, BooleanQuery, FunctionQuery, Occur, Query, QueryParser};
+/// use tantivy::schema::{Schema, FAST, TEXT};
+/// use tantivy::{doc, DocAddress, DocId, Index, Score, SegmentReader};
+///
+/// # fn test() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let views = schema_builder.add_u64_field("views", FAST);
+/// let index = Index::create_in_ram(schema_builder.build());
+/// let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+/// index_writer.add_document(doc!(title => "The Diary of Muadib", views => 10u64));
+/// index_writer.add_document(doc!(title => "The Diary of a Young Girl", views => 10_000u64));
+/// index_writer.commit()?;
+///
+/// let query_parser = QueryParser::for_index(&index, vec![title]);
+/// let log_views = FunctionQuery::new(
+///     Box::new(AllQuery),
+///     move |segment_reader: &SegmentReader| {
+///         let views_reader = segment_reader.fast_fields().u64(views).unwrap();
+///         move |doc: DocId| ((1u64 + views_reader.get(doc)) as Score).ln()
+///     },
+/// );
+/// let query = BooleanQuery::from(vec![
+///     (Occur::Must, query_parser.parse_query("diary")?),
+///     (Occur::Should, Box::new(log_views) as Box<dyn Query>),
+/// ]);
+/// let searcher = index.reader()?.searcher();
+/// let top_docs = searcher.search(&query, &TopDocs::with_limit(2))?;
+/// assert_eq!(top_docs[0].1, DocAddress(0, 1));
+/// # Ok(())
+/// # }
+/// # assert!(test().is_ok());
+/// ```
+pub struct FunctionQuery<TCustomScorer> {
+    filter: Box<dyn Query>,
+    custom_scorer: Arc<TCustomScorer>,
+}
+
+impl<TCustomScorer> FunctionQuery<TCustomScorer>
+where
+    TCustomScorer: CustomScorer<Score> + Send + Sync + 'static,
+{
+    /// Builds a function query, scoring the documents matching `filter`
+    /// with `custom_scorer`.
+    pub fn new(filter: Box<dyn Query>, custom_scorer: TCustomScorer) -> Self {
+        FunctionQuery {
+            filter,
+            custom_scorer: Arc::new(custom_scorer),
+        }
+    }
+}
+
+impl<TCustomScorer> Clone for FunctionQuery<TCustomScorer> {
+    fn clone(&self) -> Self {
+        FunctionQuery {
+            filter: self.filter.box_clone(),
+            custom_scorer: self.custom_scorer.clone(),
+        }
+    }
+}
+
+impl<TCustomScorer> fmt::Debug for FunctionQuery<TCustomScorer> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Function(filter={:?})", self.filter)
+    }
+}
+
+impl<TCustomScorer> Query for FunctionQuery<TCustomScorer>
+where
+    TCustomScorer: CustomScorer<Score> + Send + Sync + 'static,
+{
+    fn weight(&self, searcher: &Searcher, scoring_enabled: bool) -> crate::Result<Box<dyn Weight>> {
+        let weight_without_scoring = self.filter.weight(searcher, false)?;
+        if !scoring_enabled {
+            return Ok(weight_without_scoring);
+        }
+        Ok(Box::new(FunctionWeight {
+            weight: weight_without_scoring,
+            custom_scorer: self.custom_scorer.clone(),
+        }))
+    }
+
+    fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
+        self.filter.query_terms(term_set)
+    }
+}
+
+struct FunctionWeight<TCustomScorer> {
+    weight: Box<dyn Weight>,
+    custom_scorer: Arc<TCustomScorer>,
+}
+
+impl<TCustomScorer> Weight for FunctionWeight<TCustomScorer>
+where
+    TCustomScorer: CustomScorer<Score> + Send + Sync + 'static,
+{
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>> {
+        Ok(Box::new(FunctionScorer {
+            docset: self.weight.scorer(reader, 1.0f32)?,
+            segment_scorer: self.custom_scorer.segment_scorer(reader)?,
+            boost,
+        }))
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> crate::Result<Explanation> {
+        let mut scorer = self.scorer(reader, 1.0f32)?;
+        if scorer.seek(doc) != doc {
+            return Err(does_not_match(doc));
+        }
+        Ok(Explanation::new("FunctionQuery", scorer.score()))
+    }
+
+    fn count(&self, reader: &SegmentReader) -> crate::Result<u32> {
+        self.weight.count(reader)
+    }
+}
+
+struct FunctionScorer<TSegmentScorer> {
+    docset: Box<dyn Scorer>,
+    segment_scorer: TSegmentScorer,
+    boost: Score,
+}
+
+impl<TSegmentScorer: CustomSegmentScorer<Score>> DocSet for FunctionScorer<TSegmentScorer> {
+    fn advance(&mut self) -> DocId {
+        self.docset.advance()
+    }
+
+    fn seek(&mut self, target: DocId) -> DocId {
+        self.docset.seek(target)
+    }
+
+    fn fill_buffer(&mut self, buffer: &mut [DocId]) -> usize {
+        self.docset.fill_buffer(buffer)
+    }
+
+    fn doc(&self) -> DocId {
+        self.docset.doc()
+    }
+
+    fn size_hint(&self) -> u32 {
+        self.docset.size_hint()
+    }
+}
+
+impl<TSegmentScorer: CustomSegmentScorer<Score>> Scorer for FunctionScorer<TSegmentScorer> {
+    fn score(&mut self) -> Score {
+        let doc = self.docset.doc();
+        self.segment_scorer.score(doc) * self.boost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FunctionQuery;
+    use crate::collector::{Count, TopDocs};
+    use crate::query::{BooleanQuery, BoostQuery, DisjunctionMaxQuery, Occur, Query, TermQuery};
+    use crate::schema::{Field, IndexRecordOption, Schema, FAST, TEXT};
+    use crate::{DocAddress, DocId, Index, Score, SegmentReader, Term};
+
+    fn term_query(field: Field, text: &str) -> Box<dyn Query> {
+        Box::new(TermQuery::new(
+            Term::from_field_text(field, text),
+            IndexRecordOption::WithFreqs,
+        ))
+    }
+
+    #[test]
+    fn test_function_query() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let views = schema_builder.add_u64_field("views", FAST);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(text => "a", views => 0u64));
+        index_writer.add_document(doc!(text => "a b", views => 99u64));
+        index_writer.add_document(doc!(text => "b", views => 9u64));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+
+        let log_views = || {
+            FunctionQuery::new(
+                term_query(text, "a"),
+                move |segment_reader: &SegmentReader| {
+                    let views_reader = segment_reader.fast_fields().u64(views).unwrap();
+                    move |doc: DocId| ((1u64 + views_reader.get(doc)) as Score).log10()
+                },
+            )
+        };
+        let function_query = log_views();
+        assert_eq!(searcher.search(&function_query, &Count)?, 2);
+        let top_docs = searcher.search(&function_query, &TopDocs::with_limit(3))?;
+        assert_eq!(
+            top_docs,
+            vec![(2.0, DocAddress(0, 1)), (0.0, DocAddress(0, 0))]
+        );
+        let explanation = function_query.explain(&searcher, DocAddress(0, 1))?;
+        assert_eq!(explanation.value(), 2.0);
+        assert!(function_query.explain(&searcher, DocAddress(0, 2)).is_err());
+
+        let boost_query = BoostQuery::new(Box::new(log_views()), 3.0);
+        let explanation = boost_query.explain(&searcher, DocAddress(0, 1))?;
+        assert!((explanation.value() - 6.0).abs() < 1e-5);
+
+        // The function query is a feature mixed with the other clauses.
+        let boolean_query = BooleanQuery::from(vec![
+            (Occur::Should, term_query(text, "b")),
+            (Occur::Should, Box::new(log_views()) as Box<dyn Query>),
+        ]);
+        let top_docs = searcher.search(&boolean_query, &TopDocs::with_limit(3))?;
+        assert_eq!(top_docs.len(), 3);
+        assert_eq!(top_docs[0].1, DocAddress(0, 1));
+
+        let dismax_query =
+            DisjunctionMaxQuery::new(vec![term_query(text, "b"), Box::new(log_views())]);
+        let explanation = dismax_query.explain(&searcher, DocAddress(0, 1))?;
+        let b_score = term_query(text, "b")
+            .explain(&searcher, DocAddress(0, 1))?
+            .value();
+        assert!((explanation.value() - b_score.max(2.0)).abs() < 1e-5);
+        Ok(())
+    }
+}
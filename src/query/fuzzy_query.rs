@@ -129,7 +129,7 @@ impl FuzzyTermQuery {
 
     fn specialized_weight(&self) -> crate::Result<AutomatonWeight<DFAWrapper>> {
         // LEV_BUILDER is a HashMap, whose `get` method returns an Option
-        match LEV_BUILDER.get(&(self.distance, false)) {
+        match LEV_BUILDER.get(&(self.distance, self.transposition_cost_one)) {
             // Unwrap the option and build the Ok(AutomatonWeight)
             Some(automaton_builder) => {
                 let automaton = if self.prefix {
@@ -225,5 +225,22 @@ mod test {
             let (score, _) = top_docs[0];
             assert_nearly_equals!(1f32, score);
         }
+
+        // transposing 'a' and 'p' costs 1 only if `transposition_cost_one` is set.
+        {
+            let term = Term::from_field_text(country_field, "jpaan");
+
+            let fuzzy_query = FuzzyTermQuery::new(term.clone(), 1, true);
+            let top_docs = searcher
+                .search(&fuzzy_query, &TopDocs::with_limit(2))
+                .unwrap();
+            assert_eq!(top_docs.len(), 1, "Expected only 1 document");
+
+            let fuzzy_query = FuzzyTermQuery::new(term, 1, false);
+            let top_docs = searcher
+                .search(&fuzzy_query, &TopDocs::with_limit(2))
+                .unwrap();
+            assert_eq!(top_docs.len(), 0, "Expected no document");
+        }
     }
 }
@@ -1,5 +1,9 @@
+use crate::common::BitSet;
 use crate::docset::{DocSet, TERMINATED};
+use crate::postings::compression::COMPRESSION_BLOCK_SIZE;
 use crate::query::term_query::TermScorer;
+use crate::query::BitSetDocSet;
+use crate::query::ConstScorer;
 use crate::query::EmptyScorer;
 use crate::query::Scorer;
 use crate::DocId;
@@ -12,8 +16,11 @@ use crate::Score;
 ///
 /// For better performance, the function uses a
 /// specialized implementation if the two
-/// shortest scorers are `TermScorer`s.
-pub fn intersect_scorers(mut scorers: Vec<Box<dyn Scorer>>) -> Box<dyn Scorer> {
+/// shortest scorers are `TermScorer`s, and the
+/// scorers backed by a bitset are intersected
+/// with a bitwise AND.
+pub fn intersect_scorers(scorers: Vec<Box<dyn Scorer>>) -> Box<dyn Scorer> {
+    let mut scorers = intersect_bitset_scorers(scorers);
     if scorers.is_empty() {
         return Box::new(EmptyScorer);
     }
@@ -45,6 +52,99 @@ pub fn intersect_scorers(mut scorers: Vec<Box<dyn Scorer>>) -> Box<dyn Scorer> {
     })
 }
 
+/// When scoring is disabled, the postings of the `TermScorer`s matching at least
+/// `1 / DENSE_TERM_RATIO` of the documents of a segment are loaded in a `BitSet`,
+/// so that their intersections can be computed with bitwise ANDs.
+///
+/// Terms with less than a block of postings are never loaded in a `BitSet`.
+const DENSE_TERM_RATIO: u32 = 8;
+
+/// Loading a term in a `BitSet` costs a pass over all of its postings,
+/// while leapfrogging only visits the postings around the documents of the
+/// shortest scorer. Dense terms matching more than `DENSE_TERM_SPREAD` times
+/// the documents of the shortest scorer are therefore left as they are.
+const DENSE_TERM_SPREAD: u32 = 4;
+
+fn is_dense_term_scorer(scorer: &dyn Scorer, min_doc_freq: u32) -> bool {
+    scorer.is::<TermScorer>() && scorer.size_hint() <= min_doc_freq * DENSE_TERM_SPREAD
+}
+
+/// Returns the intersection scorer, for a query with scoring disabled.
+///
+/// If all of the scorers are dense, and two or more of them are `TermScorer`s
+/// with a similar number of documents, their postings are loaded in bitsets,
+/// so that `intersect_scorers` intersects them with a bitwise AND.
+/// The scores of these scorers are lost.
+pub(crate) fn intersect_scorers_without_scoring(
+    scorers: Vec<Box<dyn Scorer>>,
+    max_doc: DocId,
+) -> Box<dyn Scorer> {
+    let min_doc_freq = scorers
+        .iter()
+        .map(|scorer| scorer.size_hint())
+        .min()
+        .unwrap_or(0);
+    if min_doc_freq < COMPRESSION_BLOCK_SIZE as u32 || min_doc_freq < max_doc / DENSE_TERM_RATIO {
+        return intersect_scorers(scorers);
+    }
+    let (dense_term_scorers, mut scorers): (Vec<Box<dyn Scorer>>, Vec<Box<dyn Scorer>>) = scorers
+        .into_iter()
+        .partition(|scorer| is_dense_term_scorer(scorer.as_ref(), min_doc_freq));
+    if dense_term_scorers.len() < 2 {
+        scorers.extend(dense_term_scorers);
+        return intersect_scorers(scorers);
+    }
+    for mut term_scorer in dense_term_scorers {
+        let mut bitset = BitSet::with_max_value(max_doc);
+        let mut doc = term_scorer.doc();
+        while doc != TERMINATED {
+            bitset.insert(doc);
+            doc = term_scorer.advance();
+        }
+        scorers.push(Box::new(ConstScorer::new(
+            BitSetDocSet::from(bitset),
+            1.0f32,
+        )));
+    }
+    intersect_scorers(scorers)
+}
+
+/// Replaces the scorers backed by a bitset, if there are two or more of them,
+/// by a single scorer over the AND of their bitsets.
+///
+/// The score of the resulting scorer is the sum of their scores.
+fn intersect_bitset_scorers(scorers: Vec<Box<dyn Scorer>>) -> Vec<Box<dyn Scorer>> {
+    let (bitset_scorers, mut scorers): (Vec<Box<dyn Scorer>>, Vec<Box<dyn Scorer>>) = scorers
+        .into_iter()
+        .partition(|scorer| scorer.is::<ConstScorer<BitSetDocSet>>());
+    if bitset_scorers.len() < 2 {
+        scorers.extend(bitset_scorers);
+        return scorers;
+    }
+    let mut score = 0f32;
+    let mut bitset_opt: Option<BitSet> = None;
+    for bitset_scorer in bitset_scorers {
+        let mut bitset_scorer = *(bitset_scorer
+            .downcast::<ConstScorer<BitSetDocSet>>()
+            .map_err(|_| ())
+            .unwrap());
+        score += bitset_scorer.score();
+        let bitset = bitset_scorer.into_docset().into_bitset();
+        if let Some(intersection) = bitset_opt.as_mut() {
+            intersection.intersect_update(&bitset);
+        } else {
+            bitset_opt = Some(bitset);
+        }
+    }
+    if let Some(bitset) = bitset_opt {
+        scorers.push(Box::new(ConstScorer::new(
+            BitSetDocSet::from(bitset),
+            score,
+        )));
+    }
+    scorers
+}
+
 /// Creates a `DocSet` that iterate through the intersection of two or more `DocSet`s.
 pub struct Intersection<TDocSet: DocSet, TOtherDocSet: DocSet = Box<dyn Scorer>> {
     left: TDocSet,
@@ -249,3 +349,90 @@ mod tests {
         assert_eq!(intersection.doc(), TERMINATED);
     }
 }
+
+#[cfg(all(test, feature = "unstable"))]
+mod bench {
+
+    use super::{intersect_scorers, intersect_scorers_without_scoring};
+    use crate::query::{Query, Scorer, TermQuery};
+    use crate::schema::{Field, IndexRecordOption, Schema, STRING};
+    use crate::{DocSet, Document, Index, SegmentReader, Term, TERMINATED};
+    use once_cell::sync::Lazy;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use test::Bencher;
+
+    // "a" and "b" are dense terms, with similar doc freqs.
+    // "c" is in every document.
+    static INDEX: Lazy<Index> = Lazy::new(|| {
+        let mut schema_builder = Schema::builder();
+        let text_field = schema_builder.add_text_field("text", STRING);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut rng: StdRng = StdRng::from_seed([1u8; 32]);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for _ in 0..1_000_000 {
+            let mut doc = Document::default();
+            if rng.gen_bool(1f64 / 3f64) {
+                doc.add_text(text_field, "a");
+            }
+            if rng.gen_bool(1f64 / 4f64) {
+                doc.add_text(text_field, "b");
+            }
+            doc.add_text(text_field, "c");
+            index_writer.add_document(doc);
+        }
+        index_writer.commit().unwrap();
+        index
+    });
+
+    fn term_scorer(reader: &SegmentReader, text: &str) -> Box<dyn Scorer> {
+        let searcher = INDEX.reader().unwrap().searcher();
+        let term = Term::from_field_text(Field::from_field_id(0), text);
+        TermQuery::new(term, IndexRecordOption::Basic)
+            .weight(&searcher, false)
+            .unwrap()
+            .scorer(reader, 1.0f32)
+            .unwrap()
+    }
+
+    fn bench_intersection(
+        b: &mut Bencher,
+        terms: &[&str],
+        intersect: impl Fn(Vec<Box<dyn Scorer>>, &SegmentReader) -> Box<dyn Scorer>,
+    ) {
+        let searcher = INDEX.reader().unwrap().searcher();
+        let segment_reader = searcher.segment_reader(0);
+        b.iter(|| {
+            let scorers = terms
+                .iter()
+                .map(|text| term_scorer(segment_reader, text))
+                .collect();
+            let mut intersection = intersect(scorers, segment_reader);
+            while intersection.advance() != TERMINATED {}
+        });
+    }
+
+    #[bench]
+    fn bench_intersection_dense_terms(b: &mut Bencher) {
+        bench_intersection(b, &["a", "b"], |scorers, _| intersect_scorers(scorers));
+    }
+
+    #[bench]
+    fn bench_intersection_dense_terms_without_scoring(b: &mut Bencher) {
+        bench_intersection(b, &["a", "b"], |scorers, reader| {
+            intersect_scorers_without_scoring(scorers, reader.max_doc())
+        });
+    }
+
+    #[bench]
+    fn bench_intersection_unbalanced_dense_terms(b: &mut Bencher) {
+        bench_intersection(b, &["b", "c"], |scorers, _| intersect_scorers(scorers));
+    }
+
+    #[bench]
+    fn bench_intersection_unbalanced_dense_terms_without_scoring(b: &mut Bencher) {
+        bench_intersection(b, &["b", "c"], |scorers, reader| {
+            intersect_scorers_without_scoring(scorers, reader.max_doc())
+        });
+    }
+}
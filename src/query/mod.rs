@@ -3,29 +3,41 @@
 mod all_query;
 mod automaton_weight;
 mod bitset;
-mod bm25;
 mod boolean_query;
 mod boost_query;
+mod const_score_query;
+mod disjunction;
+mod disjunction_max_query;
 mod empty_query;
 mod exclude;
+mod exists_query;
 mod explanation;
+mod filter_cache;
+mod function_query;
 mod fuzzy_query;
 mod intersection;
+mod more_like_this_query;
 mod phrase_query;
+mod prefix_query;
 mod query;
 mod query_parser;
 mod range_query;
 mod regex_query;
 mod reqopt_scorer;
 mod scorer;
+mod similarity;
 mod term_query;
+mod term_set_query;
+mod tweaked_score_query;
 mod union;
 mod weight;
+mod wildcard_query;
 
 #[cfg(test)]
 mod vec_docset;
 
 pub(crate) mod score_combiner;
+pub use self::score_combiner::{CountCombiner, ScoreCombiner};
 pub use self::intersection::Intersection;
 pub use self::union::Union;
 
@@ -37,14 +49,21 @@ pub use self::automaton_weight::AutomatonWeight;
 pub use self::bitset::BitSetDocSet;
 pub use self::boolean_query::BooleanQuery;
 pub use self::boost_query::BoostQuery;
+pub use self::const_score_query::ConstScoreQuery;
+pub use self::disjunction_max_query::DisjunctionMaxQuery;
 pub use self::empty_query::{EmptyQuery, EmptyScorer, EmptyWeight};
 pub use self::exclude::Exclude;
+pub use self::exists_query::{ExistsQuery, ExistsWeight};
 pub use self::explanation::Explanation;
+pub use self::filter_cache::{CachedFilterQuery, FilterCache};
+pub use self::function_query::FunctionQuery;
 #[cfg(test)]
 pub(crate) use self::fuzzy_query::DFAWrapper;
 pub use self::fuzzy_query::FuzzyTermQuery;
 pub use self::intersection::intersect_scorers;
+pub use self::more_like_this_query::{MoreLikeThisQuery, MoreLikeThisQueryBuilder};
 pub use self::phrase_query::PhraseQuery;
+pub use self::prefix_query::PrefixQuery;
 pub use self::query::Query;
 pub use self::query_parser::QueryParser;
 pub use self::query_parser::QueryParserError;
@@ -53,9 +72,14 @@ pub use self::regex_query::RegexQuery;
 pub use self::reqopt_scorer::RequiredOptionalScorer;
 pub use self::scorer::ConstScorer;
 pub use self::scorer::Scorer;
+pub use self::similarity::{BM25Similarity, NormTable, Similarity, TfIdfSimilarity};
 pub use self::term_query::TermQuery;
+pub use self::term_set_query::TermSetQuery;
+pub use self::tweaked_score_query::TweakedScoreQuery;
 pub use self::weight::Weight;
+pub use self::wildcard_query::WildcardQuery;
 pub use tantivy_query_grammar::Occur;
+pub use tantivy_query_grammar::{UserInputAST, UserInputBound, UserInputLeaf, UserInputLiteral};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,383 @@
+use crate::query::{BooleanQuery, BoostQuery, EmptyQuery, Occur, Query, TermQuery, Weight};
+use crate::schema::{Field, IndexRecordOption, Term, Value};
+use crate::tokenizer::Token;
+use crate::{DocAddress, Score, Searcher};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// The document whose similar documents are searched.
+#[derive(Debug, Clone)]
+enum TargetDocument {
+    DocumentAddress(DocAddress),
+    DocumentFields(Vec<(Field, Vec<Value>)>),
+}
+
+/// `MoreLikeThisQuery` matches the documents similar to a target document.
+///
+/// The target document is either a document of the index, in which case its
+/// stored fields are used, or a set of field values (e.g. some raw text).
+/// Its text values are tokenized with the tokenizer of their field.
+///
+/// The most significant terms of the target document are selected by their tf-idf,
+/// i.e. the number of times they appear in the target document multiplied by their
+/// inverse document frequency in the index. The query is then a disjunction over the
+/// selected terms, each of them being boosted by its tf-idf relative to the best term.
+///
+/// When the target is a document of the index, it usually is the top hit of the query.
+///
+/// ```rust
+/// use tantivy::collector::TopDocs;
+/// use tantivy::query::MoreLikeThisQuery;
+/// use tantivy::schema::{Schema, STORED, TEXT};
+/// use tantivy::{doc, DocAddress, Index};
+///
+/// # fn test() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT | STORED);
+/// let index = Index::create_in_ram(schema_builder.build());
+/// let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+/// index_writer.add_document(doc!(title => "The Name of the Wind"));
+/// index_writer.add_document(doc!(title => "The Diary of Muadib"));
+/// index_writer.add_document(doc!(title => "A Dairy Cow"));
+/// index_writer.add_document(doc!(title => "The Diary of a Young Girl"));
+/// index_writer.commit()?;
+/// let searcher = index.reader()?.searcher();
+///
+/// let query = MoreLikeThisQuery::builder()
+///     .min_doc_frequency(1)
+///     .min_term_frequency(1)
+///     .with_document(DocAddress(0, 1));
+/// let top_docs = searcher.search(&query, &TopDocs::with_limit(2))?;
+/// assert_eq!(top_docs[0].1, DocAddress(0, 1));
+/// assert_eq!(top_docs[1].1, DocAddress(0, 3));
+/// # Ok(())
+/// # }
+/// # assert!(test().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoreLikeThisQuery {
+    builder: MoreLikeThisQueryBuilder,
+    target: TargetDocument,
+}
+
+impl MoreLikeThisQuery {
+    /// Creates a `MoreLikeThisQueryBuilder`, with the default parameters.
+    pub fn builder() -> MoreLikeThisQueryBuilder {
+        MoreLikeThisQueryBuilder::default()
+    }
+
+    fn target_field_values(&self, searcher: &Searcher) -> crate::Result<Vec<(Field, Vec<Value>)>> {
+        match self.target {
+            TargetDocument::DocumentAddress(doc_address) => {
+                let doc = searcher.doc(doc_address)?;
+                Ok(doc
+                    .get_sorted_field_values()
+                    .into_iter()
+                    .map(|(field, field_values)| {
+                        let values = field_values
+                            .into_iter()
+                            .map(|field_value| field_value.value().clone())
+                            .collect();
+                        (field, values)
+                    })
+                    .collect())
+            }
+            TargetDocument::DocumentFields(ref field_values) => Ok(field_values.clone()),
+        }
+    }
+
+    fn term_frequencies(
+        &self,
+        searcher: &Searcher,
+        field_values: &[(Field, Vec<Value>)],
+    ) -> crate::Result<HashMap<Term, usize>> {
+        let schema = searcher.schema();
+        let mut term_frequencies: HashMap<Term, usize> = HashMap::new();
+        for &(field, ref values) in field_values {
+            if !schema.get_field_entry(field).is_indexed() {
+                continue;
+            }
+            let mut add_token = |token: &Token| {
+                let num_chars = token.text.chars().count();
+                if self.builder.is_valid_word_length(num_chars) {
+                    let term = Term::from_field_text(field, &token.text);
+                    *term_frequencies.entry(term).or_insert(0) += 1;
+                }
+            };
+            for value in values {
+                match value {
+                    Value::Str(text) => {
+                        let tokenizer = searcher.index().tokenizer_for_field(field)?;
+                        tokenizer.token_stream(text).process(&mut add_token);
+                    }
+                    Value::PreTokStr(pre_tokenized_text) => {
+                        pre_tokenized_text.tokens.iter().for_each(&mut add_token);
+                    }
+                    _ => {}
+                }
+            }
+            for value in values {
+                let term_opt = match *value {
+                    Value::U64(val) => Some(Term::from_field_u64(field, val)),
+                    Value::I64(val) => Some(Term::from_field_i64(field, val)),
+                    Value::F64(val) => Some(Term::from_field_f64(field, val)),
+                    Value::Date(ref date) => Some(Term::from_field_date(field, date)),
+                    Value::Bool(val) => Some(Term::from_field_bool(field, val)),
+                    _ => None,
+                };
+                if let Some(term) = term_opt {
+                    *term_frequencies.entry(term).or_insert(0) += 1;
+                }
+            }
+        }
+        Ok(term_frequencies)
+    }
+
+    /// Builds the disjunction over the most significant terms of the target document.
+    fn build_query(&self, searcher: &Searcher) -> crate::Result<Box<dyn Query>> {
+        let field_values = self.target_field_values(searcher)?;
+        let num_docs = searcher.num_docs();
+        let mut scored_terms: Vec<(Score, Term)> = Vec::new();
+        for (term, term_freq) in self.term_frequencies(searcher, &field_values)? {
+            if term_freq < self.builder.min_term_frequency {
+                continue;
+            }
+            let doc_freq = searcher.doc_freq(&term);
+            if doc_freq == 0 || !self.builder.is_valid_doc_frequency(doc_freq) {
+                continue;
+            }
+            let idf = searcher
+                .index()
+                .similarity_for_field(term.field())
+                .idf(doc_freq, num_docs);
+            scored_terms.push((term_freq as Score * idf, term));
+        }
+        scored_terms.sort_by(|(left_score, left_term), (right_score, right_term)| {
+            right_score
+                .partial_cmp(left_score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| left_term.cmp(right_term))
+        });
+        scored_terms.truncate(self.builder.max_query_terms);
+        let best_score = match scored_terms.first() {
+            Some(&(best_score, _)) if best_score > 0.0 => best_score,
+            _ => return Ok(Box::new(EmptyQuery)),
+        };
+        let sub_queries: Vec<(Occur, Box<dyn Query>)> = scored_terms
+            .into_iter()
+            .map(|(score, term)| {
+                let term_query = TermQuery::new(term, IndexRecordOption::WithFreqs);
+                let boost = self.builder.boost_factor * score / best_score;
+                let sub_query: Box<dyn Query> =
+                    Box::new(BoostQuery::new(Box::new(term_query), boost));
+                (Occur::Should, sub_query)
+            })
+            .collect();
+        Ok(Box::new(BooleanQuery::from(sub_queries)))
+    }
+}
+
+impl Query for MoreLikeThisQuery {
+    fn weight(&self, searcher: &Searcher, scoring_enabled: bool) -> crate::Result<Box<dyn Weight>> {
+        self.build_query(searcher)?
+            .weight(searcher, scoring_enabled)
+    }
+}
+
+/// Builder of a `MoreLikeThisQuery`, holding the parameters
+/// used to select the terms of the target document.
+#[derive(Debug, Clone)]
+pub struct MoreLikeThisQueryBuilder {
+    min_doc_frequency: u64,
+    max_doc_frequency: Option<u64>,
+    min_term_frequency: usize,
+    max_query_terms: usize,
+    min_word_length: usize,
+    max_word_length: Option<usize>,
+    boost_factor: Score,
+}
+
+impl Default for MoreLikeThisQueryBuilder {
+    fn default() -> Self {
+        MoreLikeThisQueryBuilder {
+            min_doc_frequency: 5,
+            max_doc_frequency: None,
+            min_term_frequency: 2,
+            max_query_terms: 25,
+            min_word_length: 0,
+            max_word_length: None,
+            boost_factor: 1.0,
+        }
+    }
+}
+
+impl MoreLikeThisQueryBuilder {
+    /// Ignores the terms appearing in less than `min_doc_frequency` documents of the index.
+    ///
+    /// Defaults to 5.
+    pub fn min_doc_frequency(mut self, min_doc_frequency: u64) -> MoreLikeThisQueryBuilder {
+        self.min_doc_frequency = min_doc_frequency;
+        self
+    }
+
+    /// Ignores the terms appearing in more than `max_doc_frequency` documents of the index.
+    ///
+    /// By default, there is no maximum.
+    pub fn max_doc_frequency(mut self, max_doc_frequency: u64) -> MoreLikeThisQueryBuilder {
+        self.max_doc_frequency = Some(max_doc_frequency);
+        self
+    }
+
+    /// Ignores the terms appearing less than `min_term_frequency` times in the target document.
+    ///
+    /// Defaults to 2.
+    pub fn min_term_frequency(mut self, min_term_frequency: usize) -> MoreLikeThisQueryBuilder {
+        self.min_term_frequency = min_term_frequency;
+        self
+    }
+
+    /// Sets the maximum number of terms of the query.
+    ///
+    /// Defaults to 25.
+    pub fn max_query_terms(mut self, max_query_terms: usize) -> MoreLikeThisQueryBuilder {
+        self.max_query_terms = max_query_terms;
+        self
+    }
+
+    /// Ignores the words shorter than `min_word_length` chars.
+    pub fn min_word_length(mut self, min_word_length: usize) -> MoreLikeThisQueryBuilder {
+        self.min_word_length = min_word_length;
+        self
+    }
+
+    /// Ignores the words longer than `max_word_length` chars.
+    pub fn max_word_length(mut self, max_word_length: usize) -> MoreLikeThisQueryBuilder {
+        self.max_word_length = Some(max_word_length);
+        self
+    }
+
+    /// Sets the boost of the most significant term. The other terms are
+    /// boosted proportionally to their tf-idf.
+    ///
+    /// Defaults to 1.
+    pub fn boost_factor(mut self, boost_factor: Score) -> MoreLikeThisQueryBuilder {
+        self.boost_factor = boost_factor;
+        self
+    }
+
+    /// Builds a query matching the documents similar to the document
+    /// of the index at `doc_address`, using its stored fields.
+    pub fn with_document(self, doc_address: DocAddress) -> MoreLikeThisQuery {
+        MoreLikeThisQuery {
+            builder: self,
+            target: TargetDocument::DocumentAddress(doc_address),
+        }
+    }
+
+    /// Builds a query matching the documents similar to a document
+    /// made of the given field values.
+    pub fn with_document_fields(self, field_values: Vec<(Field, Vec<Value>)>) -> MoreLikeThisQuery {
+        MoreLikeThisQuery {
+            builder: self,
+            target: TargetDocument::DocumentFields(field_values),
+        }
+    }
+
+    fn is_valid_doc_frequency(&self, doc_freq: u64) -> bool {
+        doc_freq >= self.min_doc_frequency
+            && self
+                .max_doc_frequency
+                .map_or(true, |max_doc_frequency| doc_freq <= max_doc_frequency)
+    }
+
+    fn is_valid_word_length(&self, num_chars: usize) -> bool {
+        num_chars >= self.min_word_length
+            && self
+                .max_word_length
+                .map_or(true, |max_word_length| num_chars <= max_word_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoreLikeThisQuery;
+    use crate::collector::{Count, TopDocs};
+    use crate::schema::{Schema, Value, FAST, INDEXED, STORED, TEXT};
+    use crate::{DocAddress, Index};
+
+    fn create_index() -> crate::Result<Index> {
+        let mut schema_builder = Schema::builder();
+        let body = schema_builder.add_text_field("body", TEXT | STORED);
+        let year = schema_builder.add_u64_field("year", INDEXED | STORED | FAST);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer
+            .add_document(doc!(body => "rust borrow checker borrow rules", year => 2015u64));
+        index_writer.add_document(doc!(body => "the borrow checker of rust", year => 2018u64));
+        index_writer.add_document(doc!(body => "python garbage collector", year => 2015u64));
+        index_writer.add_document(doc!(body => "the rust compiler", year => 2020u64));
+        index_writer.add_document(doc!(body => "java garbage collector tuning", year => 2001u64));
+        index_writer.commit()?;
+        Ok(index)
+    }
+
+    #[test]
+    fn test_more_like_this_query_document() -> crate::Result<()> {
+        let index = create_index()?;
+        let searcher = index.reader()?.searcher();
+        let query = MoreLikeThisQuery::builder()
+            .min_doc_frequency(1)
+            .min_term_frequency(1)
+            .with_document(DocAddress(0, 0));
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(10))?;
+        let docs: Vec<DocAddress> = top_docs.into_iter().map(|(_, doc)| doc).collect();
+        assert_eq!(docs.len(), 4);
+        assert_eq!(&docs[..2], &[DocAddress(0, 0), DocAddress(0, 1)]);
+        // The doc 2 only shares the year 2015 with the target document.
+        assert!(docs.contains(&DocAddress(0, 2)));
+        assert!(!docs.contains(&DocAddress(0, 4)));
+
+        // Terms appearing once in the document, or in a single document, are ignored.
+        let query = MoreLikeThisQuery::builder()
+            .min_doc_frequency(2)
+            .min_term_frequency(2)
+            .with_document(DocAddress(0, 0));
+        assert_eq!(searcher.search(&query, &Count)?, 2);
+
+        // No term is frequent enough.
+        let query = MoreLikeThisQuery::builder().with_document(DocAddress(0, 0));
+        assert_eq!(searcher.search(&query, &Count)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_more_like_this_query_document_fields() -> crate::Result<()> {
+        let index = create_index()?;
+        let body = index.schema().get_field("body").unwrap();
+        let searcher = index.reader()?.searcher();
+        let query = MoreLikeThisQuery::builder()
+            .min_doc_frequency(1)
+            .min_term_frequency(1)
+            .min_word_length(4)
+            .max_doc_frequency(2)
+            .with_document_fields(vec![(
+                body,
+                vec![Value::Str("The garbage collector of the JVM".to_string())],
+            )]);
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(10))?;
+        let docs: Vec<DocAddress> = top_docs.into_iter().map(|(_, doc)| doc).collect();
+        assert_eq!(docs.len(), 2);
+        assert!(docs.contains(&DocAddress(0, 2)));
+        assert!(docs.contains(&DocAddress(0, 4)));
+
+        let query = MoreLikeThisQuery::builder()
+            .min_doc_frequency(1)
+            .min_term_frequency(1)
+            .max_query_terms(1)
+            .with_document_fields(vec![(body, vec![Value::Str("rust java java".to_string())])]);
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(10))?;
+        assert_eq!(top_docs.len(), 1);
+        assert_eq!(top_docs[0].1, DocAddress(0, 4));
+        Ok(())
+    }
+}
@@ -85,6 +85,34 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    pub fn test_phrase_query_with_slop() -> crate::Result<()> {
+        let index = create_index(&["a b c", "a x b c", "a x y b c", "b a c", "c b a"]);
+        let text_field = index.schema().get_field("text").unwrap();
+        let searcher = index.reader()?.searcher();
+        let test_query = |texts: Vec<&str>, slop: u32| {
+            let terms: Vec<Term> = texts
+                .iter()
+                .map(|text| Term::from_field_text(text_field, text))
+                .collect();
+            let mut phrase_query = PhraseQuery::new(terms);
+            phrase_query.set_slop(slop);
+            searcher
+                .search(&phrase_query, &TEST_COLLECTOR_WITH_SCORE)
+                .expect("search should succeed")
+                .docs()
+                .iter()
+                .map(|docaddr| docaddr.1)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(test_query(vec!["a", "b", "c"], 0), vec![0]);
+        assert_eq!(test_query(vec!["a", "b", "c"], 1), vec![0, 1]);
+        assert_eq!(test_query(vec!["a", "b", "c"], 2), vec![0, 1, 2]);
+        assert_eq!(test_query(vec!["a", "c"], 1), vec![0, 3]);
+        assert_eq!(test_query(vec!["c", "a"], 3), vec![4]);
+        Ok(())
+    }
+
     #[test]
     pub fn test_phrase_query_no_score() {
         let index = create_index(&[
@@ -1,7 +1,7 @@
 use crate::docset::{DocSet, TERMINATED};
 use crate::fieldnorm::FieldNormReader;
 use crate::postings::Postings;
-use crate::query::bm25::BM25Weight;
+use crate::query::similarity::SimilarityWeight;
 use crate::query::{Intersection, Scorer};
 use crate::DocId;
 use std::cmp::Ordering;
@@ -49,7 +49,8 @@ pub struct PhraseScorer<TPostings: Postings> {
     right: Vec<u32>,
     phrase_count: u32,
     fieldnorm_reader: FieldNormReader,
-    similarity_weight: BM25Weight,
+    similarity_weight: SimilarityWeight,
+    slop: u32,
     score_needed: bool,
 }
 
@@ -130,11 +131,49 @@ fn intersection(left: &mut [u32], right: &[u32]) -> usize {
     count
 }
 
+/// Intersect twos sorted arrays `left` and `right`, allowing for a given `slop`,
+/// and outputs the resulting array in left.
+///
+/// A value of `left` matches a value of `right` if
+/// `right - slop <= left <= right`. The matched `right` value is
+/// the one written in `left`, so that the following term is
+/// compared against the position of the latest matched term.
+///
+/// Returns the length of the intersection
+fn intersection_with_slop(left: &mut [u32], right: &[u32], slop: u32) -> usize {
+    let mut left_i = 0;
+    let mut right_i = 0;
+    let mut count = 0;
+    let left_len = left.len();
+    let right_len = right.len();
+    while left_i < left_len && right_i < right_len {
+        let left_val = left[left_i];
+        let right_val = right[right_i];
+        let right_slop = right_val.saturating_sub(slop);
+        if left_val < right_slop {
+            left_i += 1;
+        } else if left_val <= right_val {
+            // Skip to the closest matching left value.
+            while left_i + 1 < left_len && left[left_i + 1] <= right_val {
+                left_i += 1;
+            }
+            left[count] = right_val;
+            count += 1;
+            left_i += 1;
+            right_i += 1;
+        } else {
+            right_i += 1;
+        }
+    }
+    count
+}
+
 impl<TPostings: Postings> PhraseScorer<TPostings> {
     pub fn new(
         term_postings: Vec<(usize, TPostings)>,
-        similarity_weight: BM25Weight,
+        similarity_weight: SimilarityWeight,
         fieldnorm_reader: FieldNormReader,
+        slop: u32,
         score_needed: bool,
     ) -> PhraseScorer<TPostings> {
         let max_offset = term_postings
@@ -157,6 +196,7 @@ impl<TPostings: Postings> PhraseScorer<TPostings> {
             phrase_count: 0u32,
             similarity_weight,
             fieldnorm_reader,
+            slop,
             score_needed,
         };
         if scorer.doc() != TERMINATED && !scorer.phrase_match() {
@@ -180,6 +220,9 @@ impl<TPostings: Postings> PhraseScorer<TPostings> {
     }
 
     fn phrase_exists(&mut self) -> bool {
+        if self.slop > 0 {
+            return self.compute_sloppy_phrase_count() > 0;
+        }
         self.intersection_docset
             .docset_mut_specialized(0)
             .positions(&mut self.left);
@@ -203,6 +246,9 @@ impl<TPostings: Postings> PhraseScorer<TPostings> {
     }
 
     fn compute_phrase_count(&mut self) -> u32 {
+        if self.slop > 0 {
+            return self.compute_sloppy_phrase_count();
+        }
         {
             self.intersection_docset
                 .docset_mut_specialized(0)
@@ -226,6 +272,29 @@ impl<TPostings: Postings> PhraseScorer<TPostings> {
             .positions(&mut self.right);
         intersection_count(&self.left[..intersection_len], &self.right[..]) as u32
     }
+
+    fn compute_sloppy_phrase_count(&mut self) -> u32 {
+        self.intersection_docset
+            .docset_mut_specialized(0)
+            .positions(&mut self.left);
+        let mut intersection_len = self.left.len();
+        for i in 1..self.num_terms {
+            {
+                self.intersection_docset
+                    .docset_mut_specialized(i)
+                    .positions(&mut self.right);
+            }
+            intersection_len = intersection_with_slop(
+                &mut self.left[..intersection_len],
+                &self.right[..],
+                self.slop,
+            );
+            if intersection_len == 0 {
+                return 0u32;
+            }
+        }
+        intersection_len as u32
+    }
 }
 
 impl<TPostings: Postings> DocSet for PhraseScorer<TPostings> {
@@ -267,7 +336,7 @@ impl<TPostings: Postings> Scorer for PhraseScorer<TPostings> {
 
 #[cfg(test)]
 mod tests {
-    use super::{intersection, intersection_count};
+    use super::{intersection, intersection_count, intersection_with_slop};
 
     fn test_intersection_sym(left: &[u32], right: &[u32], expected: &[u32]) {
         test_intersection_aux(left, right, expected);
@@ -290,6 +359,24 @@ mod tests {
         test_intersection_sym(&[5, 7], &[1, 5, 10, 12], &[5]);
         test_intersection_sym(&[1, 5, 6, 9, 10, 12], &[6, 8, 9, 12], &[6, 9, 12]);
     }
+
+    fn test_intersection_with_slop_aux(left: &[u32], right: &[u32], slop: u32, expected: &[u32]) {
+        let mut left_vec = Vec::from(left);
+        let left_mut = &mut left_vec[..];
+        let count = intersection_with_slop(left_mut, right, slop);
+        assert_eq!(&left_mut[..count], expected);
+    }
+
+    #[test]
+    fn test_intersection_with_slop() {
+        test_intersection_with_slop_aux(&[20, 75, 77], &[18, 21, 60], 1, &[21]);
+        test_intersection_with_slop_aux(&[20, 75, 77], &[18, 21, 60], 0, &[]);
+        test_intersection_with_slop_aux(&[1, 2, 3], &[4], 2, &[4]);
+        test_intersection_with_slop_aux(&[5], &[4, 5], 1, &[5]);
+        test_intersection_with_slop_aux(&[], &[4, 5], 3, &[]);
+        test_intersection_with_slop_aux(&[10], &[1, 12, 30], 1, &[]);
+        test_intersection_with_slop_aux(&[10], &[1, 12, 30], 2, &[12]);
+    }
 }
 
 #[cfg(all(test, feature = "unstable"))]
@@ -2,8 +2,8 @@ use super::PhraseScorer;
 use crate::core::SegmentReader;
 use crate::fieldnorm::FieldNormReader;
 use crate::postings::SegmentPostings;
-use crate::query::bm25::BM25Weight;
 use crate::query::explanation::does_not_match;
+use crate::query::similarity::SimilarityWeight;
 use crate::query::Scorer;
 use crate::query::Weight;
 use crate::query::{EmptyScorer, Explanation};
@@ -14,7 +14,8 @@ use crate::{DocId, DocSet};
 
 pub struct PhraseWeight {
     phrase_terms: Vec<(usize, Term)>,
-    similarity_weight: BM25Weight,
+    similarity_weight: SimilarityWeight,
+    slop: u32,
     score_needed: bool,
 }
 
@@ -22,12 +23,14 @@ impl PhraseWeight {
     /// Creates a new phrase weight.
     pub fn new(
         phrase_terms: Vec<(usize, Term)>,
-        similarity_weight: BM25Weight,
+        similarity_weight: SimilarityWeight,
+        slop: u32,
         score_needed: bool,
     ) -> PhraseWeight {
         PhraseWeight {
             phrase_terms,
             similarity_weight,
+            slop,
             score_needed,
         }
     }
@@ -60,6 +63,7 @@ impl PhraseWeight {
                 term_postings_list,
                 similarity_weight,
                 fieldnorm_reader,
+                self.slop,
                 self.score_needed,
             )))
         } else {
@@ -78,6 +82,7 @@ impl PhraseWeight {
                 term_postings_list,
                 similarity_weight,
                 fieldnorm_reader,
+                self.slop,
                 self.score_needed,
             )))
         }
@@ -0,0 +1,189 @@
+use crate::query::{AutomatonWeight, Query, Weight};
+use crate::schema::Term;
+use crate::Searcher;
+use tantivy_fst::Automaton;
+
+/// Automaton matching all of the terms starting with a given prefix.
+#[derive(Debug, Clone)]
+pub(crate) struct PrefixAutomaton {
+    prefix: Vec<u8>,
+}
+
+impl Automaton for PrefixAutomaton {
+    /// Number of bytes of the prefix matched so far,
+    /// or `None` if the input diverged from the prefix.
+    type State = Option<usize>;
+
+    fn start(&self) -> Self::State {
+        Some(0)
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        *state == Some(self.prefix.len())
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        state.is_some()
+    }
+
+    fn will_always_match(&self, state: &Self::State) -> bool {
+        self.is_match(state)
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        match *state {
+            Some(len) if len == self.prefix.len() => Some(len),
+            Some(len) if self.prefix[len] == byte => Some(len + 1),
+            _ => None,
+        }
+    }
+}
+
+/// A Prefix Query matches all of the documents
+/// containing a term starting with a given prefix.
+///
+/// The prefix is expanded against the term dictionary of each segment.
+/// The number of expanded terms can be capped using
+/// [`set_max_expansions`](#method.set_max_expansions).
+///
+/// ```rust
+/// use tantivy::collector::Count;
+/// use tantivy::query::PrefixQuery;
+/// use tantivy::schema::{Schema, TEXT};
+/// use tantivy::{doc, Index, Term};
+///
+/// # fn test() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let schema = schema_builder.build();
+/// let index = Index::create_in_ram(schema);
+/// {
+///     let mut index_writer = index.writer(3_000_000)?;
+///     index_writer.add_document(doc!(
+///         title => "The Name of the Wind",
+///     ));
+///     index_writer.add_document(doc!(
+///         title => "The Diary of Muadib",
+///     ));
+///     index_writer.add_document(doc!(
+///         title => "A Dairy Cow",
+///     ));
+///     index_writer.add_document(doc!(
+///         title => "The Diary of a Young Girl",
+///     ));
+///     index_writer.commit().unwrap();
+/// }
+///
+/// let reader = index.reader()?;
+/// let searcher = reader.searcher();
+///
+/// let query = PrefixQuery::new(Term::from_field_text(title, "dia"));
+/// let count = searcher.search(&query, &Count)?;
+/// assert_eq!(count, 2);
+/// Ok(())
+/// # }
+/// # assert!(test().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrefixQuery {
+    prefix: Term,
+    max_expansions: Option<usize>,
+}
+
+impl PrefixQuery {
+    /// Creates a new PrefixQuery matching the terms starting with
+    /// the value of the given term.
+    pub fn new(prefix: Term) -> PrefixQuery {
+        PrefixQuery {
+            prefix,
+            max_expansions: None,
+        }
+    }
+
+    /// Caps the number of terms the prefix may expand to within a segment.
+    ///
+    /// Searching fails with an `InvalidArgument` error if the cap is exceeded.
+    pub fn set_max_expansions(&mut self, max_expansions: usize) {
+        self.max_expansions = Some(max_expansions);
+    }
+
+    fn specialized_weight(&self) -> AutomatonWeight<PrefixAutomaton> {
+        let automaton = PrefixAutomaton {
+            prefix: self.prefix.value_bytes().to_vec(),
+        };
+        let mut weight = AutomatonWeight::new(self.prefix.field(), automaton);
+        if let Some(max_expansions) = self.max_expansions {
+            weight.set_max_expansions(max_expansions);
+        }
+        weight
+    }
+}
+
+impl Query for PrefixQuery {
+    fn weight(
+        &self,
+        _searcher: &Searcher,
+        _scoring_enabled: bool,
+    ) -> crate::Result<Box<dyn Weight>> {
+        Ok(Box::new(self.specialized_weight()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PrefixQuery;
+    use crate::collector::{Count, TopDocs};
+    use crate::schema::{Field, Schema, TEXT};
+    use crate::{DocAddress, Index, IndexReader, Term};
+
+    fn build_test_index() -> (IndexReader, Field) {
+        let mut schema_builder = Schema::builder();
+        let country_field = schema_builder.add_text_field("country", TEXT);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        {
+            let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+            index_writer.add_document(doc!(country_field => "japan"));
+            index_writer.add_document(doc!(country_field => "jamaica"));
+            index_writer.add_document(doc!(country_field => "korea"));
+            index_writer.commit().unwrap();
+        }
+        (index.reader().unwrap(), country_field)
+    }
+
+    #[test]
+    pub fn test_prefix_query() {
+        let (reader, field) = build_test_index();
+        let searcher = reader.searcher();
+        let count = |prefix: &str| {
+            let query = PrefixQuery::new(Term::from_field_text(field, prefix));
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count("ja"), 2);
+        assert_eq!(count("jap"), 1);
+        assert_eq!(count("japan"), 1);
+        assert_eq!(count("japanese"), 0);
+        assert_eq!(count("k"), 1);
+        assert_eq!(count("z"), 0);
+        assert_eq!(count(""), 3);
+        let top_docs = searcher
+            .search(
+                &PrefixQuery::new(Term::from_field_text(field, "jam")),
+                &TopDocs::with_limit(2),
+            )
+            .unwrap();
+        assert_eq!(top_docs.len(), 1);
+        assert_eq!(top_docs[0].1, DocAddress(0u32, 1u32));
+    }
+
+    #[test]
+    pub fn test_prefix_query_max_expansions() {
+        let (reader, field) = build_test_index();
+        let searcher = reader.searcher();
+        let mut query = PrefixQuery::new(Term::from_field_text(field, "ja"));
+        query.set_max_expansions(2);
+        assert_eq!(searcher.search(&query, &Count).unwrap(), 2);
+        query.set_max_expansions(1);
+        assert!(searcher.search(&query, &Count).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use super::logical_ast::*;
 use crate::core::Index;
+use crate::indexer::json_term_writer::JsonTermWriter;
 use crate::query::BooleanQuery;
 use crate::query::EmptyQuery;
 use crate::query::Occur;
@@ -10,21 +11,25 @@ use crate::query::TermQuery;
 use crate::query::{AllQuery, BoostQuery};
 use crate::schema::{Facet, IndexRecordOption};
 use crate::schema::{Field, Schema};
-use crate::schema::{FieldType, Term};
+use crate::schema::{FieldType, Term, Type};
 use crate::tokenizer::TokenizerManager;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::net::{IpAddr, Ipv6Addr};
 use std::num::{ParseFloatError, ParseIntError};
 use std::ops::Bound;
 use std::str::FromStr;
+use std::str::ParseBoolError;
 use tantivy_query_grammar::{UserInputAST, UserInputBound, UserInputLeaf};
 
 /// Possible error that may happen when parsing a query.
 #[derive(Debug, PartialEq, Eq, Fail)]
 pub enum QueryParserError {
-    /// Error in the query syntax
-    #[fail(display = "Syntax Error")]
-    SyntaxError,
+    /// Error in the query syntax.
+    /// The argument is the byte offset within the query at which
+    /// the parser stopped.
+    #[fail(display = "Syntax Error at byte offset {}", _0)]
+    SyntaxError(usize),
     /// `FieldDoesNotExist(field_name: String)`
     /// The query references a field that is not in the schema
     #[fail(display = "File does not exists: '{:?}'", _0)]
@@ -37,6 +42,14 @@ pub enum QueryParserError {
     /// is not a f64.
     #[fail(display = "Invalid query: Only excluding terms given")]
     ExpectedFloat(ParseFloatError),
+    /// The query contains a term for a `bool`-field, but the value
+    /// is neither `true` nor `false`.
+    #[fail(display = "Expected a bool value: '{:?}'", _0)]
+    ExpectedBool(ParseBoolError),
+    /// The query contains a term for an ip address field, but the value
+    /// is neither an ip address nor a CIDR block (e.g. `10.0.0.0/8`).
+    #[fail(display = "Expected an ip address or a CIDR block: '{:?}'", _0)]
+    ExpectedIpAddr(String),
     /// It is forbidden queries that are only "excluding". (e.g. -title:pop)
     #[fail(display = "Invalid query: Only excluding terms given")]
     AllButQueryForbidden,
@@ -80,12 +93,37 @@ impl From<ParseFloatError> for QueryParserError {
     }
 }
 
+impl From<ParseBoolError> for QueryParserError {
+    fn from(err: ParseBoolError) -> QueryParserError {
+        QueryParserError::ExpectedBool(err)
+    }
+}
+
 impl From<chrono::ParseError> for QueryParserError {
     fn from(err: chrono::ParseError) -> QueryParserError {
         QueryParserError::DateFormatError(err)
     }
 }
 
+/// Parses a CIDR block (e.g. `192.168.0.0/16` or `2001:db8::/32`)
+/// and returns the first and the last address of the block.
+///
+/// IPv4 addresses are mapped to IPv6.
+fn parse_ip_cidr(phrase: &str) -> Option<(Ipv6Addr, Ipv6Addr)> {
+    let slash_pos = phrase.find('/')?;
+    let ip_addr = IpAddr::from_str(&phrase[..slash_pos]).ok()?;
+    let prefix_len = u32::from_str(&phrase[slash_pos + 1..]).ok()?;
+    let (ip_addr, prefix_len) = match ip_addr {
+        IpAddr::V4(ipv4_addr) if prefix_len <= 32 => (ipv4_addr.to_ipv6_mapped(), prefix_len + 96),
+        IpAddr::V6(ipv6_addr) if prefix_len <= 128 => (ipv6_addr, prefix_len),
+        _ => return None,
+    };
+    let mask = (!0u128).checked_shl(128 - prefix_len).unwrap_or(0u128);
+    let first = u128::from(ip_addr) & mask;
+    let last = first | !mask;
+    Some((Ipv6Addr::from(first), Ipv6Addr::from(last)))
+}
+
 /// Recursively remove empty clause from the AST
 ///
 /// Returns `None` iff the `logical_ast` ended up being empty.
@@ -156,6 +194,12 @@ fn trim_ast(logical_ast: LogicalAST) -> Option<LogicalAST> {
 ///   Inclusive bounds are `[]`, exclusive are `{}`.
 ///
 /// * date values: The query parser supports rfc3339 formatted dates. For example "2002-10-02T15:00:00.05Z"
+///   Plain dates such as `2002-10-02` are also accepted and stand for midnight UTC,
+///   which makes it possible to write date ranges, e.g. `date:>=2002-10-02`.
+///
+/// * ip address values: Ip address fields accept IPv4 and IPv6 addresses, as well as
+///   CIDR blocks, e.g. `ip:10.0.0.0/8`. IPv6 addresses contain `:` and need to be quoted,
+///   e.g. `ip:"2001:db8::/32"`.
 ///
 /// *  all docs query: A plain `*` will match all documents in the index.
 ///
@@ -175,6 +219,102 @@ pub struct QueryParser {
     boost: HashMap<Field, f32>,
 }
 
+/// Parses a date value given in a query.
+///
+/// Dates are expected to be rfc3339 formatted. Plain calendar dates
+/// (e.g. `2020-01-01`) are also accepted, and interpreted as midnight UTC.
+fn parse_date(phrase: &str) -> Result<chrono::DateTime<chrono::Utc>, QueryParserError> {
+    match chrono::DateTime::parse_from_rfc3339(phrase) {
+        Ok(date) => Ok(date.with_timezone(&chrono::Utc)),
+        Err(rfc3339_err) => chrono::NaiveDate::parse_from_str(phrase, "%Y-%m-%d")
+            .map(|date| chrono::DateTime::from_utc(date.and_hms(0, 0, 0), chrono::Utc))
+            .map_err(|_| QueryParserError::DateFormatError(rfc3339_err)),
+    }
+}
+
+/// Parses the user query into a `UserInputAST`.
+///
+/// If the query is not syntactically valid, the longest prefix of the query
+/// ending on a whitespace that can be parsed is kept, and the parsing goes on
+/// after it. When no such prefix exists, the offending token is dropped
+/// and a syntax error is reported at the offset at which the parser stopped.
+fn parse_user_input_ast_lenient(query: &str, errors: &mut Vec<QueryParserError>) -> UserInputAST {
+    if let Ok(user_input_ast) = tantivy_query_grammar::parse_query(query) {
+        return user_input_ast;
+    }
+    let mut sub_queries: Vec<(Option<Occur>, UserInputAST)> = Vec::new();
+    let mut remaining = query.trim_start();
+    while !remaining.is_empty() {
+        let offset = query.len() - remaining.len();
+        if let Some(operator_len) = leading_binary_operator_len(remaining) {
+            // The left operand of this operator has already been parsed, or dropped.
+            remaining = remaining[operator_len..].trim_start();
+            continue;
+        }
+        let err = match tantivy_query_grammar::parse_query(remaining) {
+            Ok(sub_ast) => {
+                sub_queries.push((None, sub_ast));
+                break;
+            }
+            Err(err) => err,
+        };
+        let valid_prefix = remaining
+            .char_indices()
+            .rev()
+            .filter(|&(pos, c)| c.is_whitespace() && !ends_with_operator(&remaining[..pos]))
+            .flat_map(|(pos, _)| {
+                let sub_ast = tantivy_query_grammar::parse_query(&remaining[..pos]).ok()?;
+                Some((pos, sub_ast))
+            })
+            .next();
+        let consumed_len = if let Some((prefix_len, sub_ast)) = valid_prefix {
+            sub_queries.push((None, sub_ast));
+            prefix_len
+        } else {
+            errors.push(QueryParserError::SyntaxError(offset + err.offset));
+            offending_token_len(remaining)
+        };
+        remaining = remaining[consumed_len..].trim_start();
+    }
+    match sub_queries.len() {
+        1 if matches!(sub_queries[0].1, UserInputAST::Clause(_)) => sub_queries.pop().unwrap().1,
+        _ => UserInputAST::Clause(sub_queries),
+    }
+}
+
+fn is_operator(token: &str) -> bool {
+    token == "AND" || token == "OR" || token == "NOT"
+}
+
+fn ends_with_operator(query: &str) -> bool {
+    query
+        .split_whitespace()
+        .next_back()
+        .map_or(false, is_operator)
+}
+
+fn leading_binary_operator_len(query: &str) -> Option<usize> {
+    let token = query.split_whitespace().next()?;
+    if token == "AND" || token == "OR" {
+        Some(token.len())
+    } else {
+        None
+    }
+}
+
+/// Returns the length of the token dropped when no clause can be parsed
+/// at the beginning of `query`: a grouping character, possibly preceded
+/// by an occur symbol, or the whole word otherwise.
+fn offending_token_len(query: &str) -> usize {
+    let unsigned_query = query.trim_start_matches(|c| c == '+' || c == '-');
+    match unsigned_query.chars().next() {
+        Some(c) if "()[]{}\"".contains(c) => query.len() - unsigned_query.len() + c.len_utf8(),
+        _ => query
+            .find(char::is_whitespace)
+            .unwrap_or_else(|| query.len()),
+    }
+}
+
 fn all_negative(ast: &LogicalAST) -> bool {
     match ast {
         LogicalAST::Leaf(_) => false,
@@ -237,29 +377,137 @@ impl QueryParser {
     /// Note that `parse_query` returns an error if the input
     /// is not a valid query.
     ///
-    /// There is currently no lenient mode for the query parser
-    /// which makes it a bad choice for a public/broad user search engine.
-    ///
-    /// Implementing a lenient mode for this query parser is tracked
-    /// in [Issue 5](https://github.com/fulmicoton/tantivy/issues/5)
+    /// If the query comes from end users of a public/broad search engine,
+    /// consider using [`parse_query_lenient`](#method.parse_query_lenient)
+    /// instead.
     pub fn parse_query(&self, query: &str) -> Result<Box<dyn Query>, QueryParserError> {
         let logical_ast = self.parse_query_to_logical_ast(query)?;
         Ok(convert_to_query(logical_ast))
     }
 
+    /// Parses a query into its `UserInputAST`, without resolving it against the schema.
+    ///
+    /// The `UserInputAST` implements `Serialize` and `Deserialize`, so that a query
+    /// can be parsed by a service and shipped to another one, which builds it
+    /// with [`build_query_from_user_input_ast`](#method.build_query_from_user_input_ast).
+    pub fn parse_query_to_user_input_ast(
+        &self,
+        query: &str,
+    ) -> Result<UserInputAST, QueryParserError> {
+        tantivy_query_grammar::parse_query(query)
+            .map_err(|err| QueryParserError::SyntaxError(err.offset))
+    }
+
+    /// Builds a query from a `UserInputAST`, resolving its fields
+    /// and terms against the schema.
+    pub fn build_query_from_user_input_ast(
+        &self,
+        user_input_ast: UserInputAST,
+    ) -> Result<Box<dyn Query>, QueryParserError> {
+        let logical_ast = self.compute_logical_ast(user_input_ast)?;
+        Ok(convert_to_query(logical_ast))
+    }
+
     /// Parse the user query into an AST.
     fn parse_query_to_logical_ast(&self, query: &str) -> Result<LogicalAST, QueryParserError> {
-        let user_input_ast =
-            tantivy_query_grammar::parse_query(query).map_err(|_| QueryParserError::SyntaxError)?;
+        let user_input_ast = self.parse_query_to_user_input_ast(query)?;
         self.compute_logical_ast(user_input_ast)
     }
 
+    /// Parse a query, without ever failing.
+    ///
+    /// Unlike `parse_query`, the clauses that cannot be parsed or
+    /// cannot be resolved against the schema are dropped from the
+    /// resulting query. The errors encountered along the way are returned
+    /// alongside the query, so that they can be reported to the user as warnings.
+    ///
+    /// If the query syntax is invalid, the clauses that can be parsed are kept,
+    /// and the offending tokens are dropped. Syntax errors are then reported
+    /// with the byte offset relative to the entire query.
+    ///
+    /// A query that only contains excluding clauses yields an `EmptyQuery`
+    /// along with a `QueryParserError::AllButQueryForbidden` warning.
+    pub fn parse_query_lenient(&self, query: &str) -> (Box<dyn Query>, Vec<QueryParserError>) {
+        let mut errors = Vec::new();
+        let user_input_ast = parse_user_input_ast_lenient(query, &mut errors);
+        let logical_ast = self.compute_logical_ast_lenient(user_input_ast, &mut errors);
+        let query: Box<dyn Query> = match logical_ast {
+            Some(LogicalAST::Clause(ref children)) if children.is_empty() => Box::new(EmptyQuery),
+            Some(ref ast) if all_negative(ast) => {
+                errors.push(QueryParserError::AllButQueryForbidden);
+                Box::new(EmptyQuery)
+            }
+            Some(ast) => convert_to_query(ast),
+            None => Box::new(EmptyQuery),
+        };
+        (query, errors)
+    }
+
+    /// Same as `compute_logical_ast_with_occur`, except that the leaves
+    /// that cannot be resolved are dropped and their error is
+    /// appended to `errors`.
+    fn compute_logical_ast_lenient(
+        &self,
+        user_input_ast: UserInputAST,
+        errors: &mut Vec<QueryParserError>,
+    ) -> Option<LogicalAST> {
+        match user_input_ast {
+            UserInputAST::Clause(sub_queries) => {
+                let default_occur = self.default_occur();
+                let logical_sub_queries: Vec<(Occur, LogicalAST)> = sub_queries
+                    .into_iter()
+                    .flat_map(|(occur_opt, sub_ast)| {
+                        let occur = occur_opt.unwrap_or(default_occur);
+                        self.compute_logical_ast_lenient(sub_ast, errors)
+                            .map(|sub_ast| (occur, sub_ast))
+                    })
+                    .collect();
+                if logical_sub_queries.is_empty() {
+                    return None;
+                }
+                Some(LogicalAST::Clause(logical_sub_queries))
+            }
+            UserInputAST::Boost(ast, boost) => self
+                .compute_logical_ast_lenient(*ast, errors)
+                .map(|ast| ast.boost(boost)),
+            UserInputAST::Leaf(leaf) => match self.compute_logical_ast_from_leaf(*leaf) {
+                Ok(ast) => Some(ast),
+                Err(err) => {
+                    errors.push(err);
+                    None
+                }
+            },
+        }
+    }
+
     fn resolve_field_name(&self, field_name: &str) -> Result<Field, QueryParserError> {
         self.schema
             .get_field(field_name)
             .ok_or_else(|| QueryParserError::FieldDoesNotExist(String::from(field_name)))
     }
 
+    /// Resolves a field name that may point within a json object field
+    /// (e.g. `attributes.color`).
+    ///
+    /// Returns the field and the path within the json object. The path
+    /// is empty if the field name matches a field of the schema exactly.
+    fn resolve_field_and_json_path<'a>(
+        &self,
+        full_path: &'a str,
+    ) -> Result<(Field, &'a str), QueryParserError> {
+        if let Some(field) = self.schema.get_field(full_path) {
+            return Ok((field, ""));
+        }
+        for (sep_pos, _) in full_path.rmatch_indices('.') {
+            if let Some(field) = self.schema.get_field(&full_path[..sep_pos]) {
+                if let FieldType::JsonObject(_) = self.schema.get_field_entry(field).field_type() {
+                    return Ok((field, &full_path[sep_pos + 1..]));
+                }
+            }
+        }
+        Err(QueryParserError::FieldDoesNotExist(String::from(full_path)))
+    }
+
     fn compute_logical_ast(
         &self,
         user_input_ast: UserInputAST,
@@ -279,6 +527,7 @@ impl QueryParser {
     fn compute_terms_for_string(
         &self,
         field: Field,
+        json_path: &str,
         phrase: &str,
     ) -> Result<Vec<(usize, Term)>, QueryParserError> {
         let field_entry = self.schema.get_field_entry(field);
@@ -298,18 +547,25 @@ impl QueryParser {
                 let term = Term::from_field_f64(field, val);
                 Ok(vec![(0, term)])
             }
-            FieldType::Date(_) => match chrono::DateTime::parse_from_rfc3339(phrase) {
-                Ok(x) => Ok(vec![(
-                    0,
-                    Term::from_field_date(field, &x.with_timezone(&chrono::Utc)),
-                )]),
-                Err(e) => Err(QueryParserError::DateFormatError(e)),
-            },
+            FieldType::Date(ref options) => {
+                let date = options.get_precision().truncate(&parse_date(phrase)?);
+                Ok(vec![(0, Term::from_field_date(field, &date))])
+            }
             FieldType::U64(_) => {
                 let val: u64 = u64::from_str(phrase)?;
                 let term = Term::from_field_u64(field, val);
                 Ok(vec![(0, term)])
             }
+            FieldType::IpAddr(_) => {
+                let ip_addr = IpAddr::from_str(phrase)
+                    .map_err(|_| QueryParserError::ExpectedIpAddr(phrase.to_string()))?;
+                Ok(vec![(0, Term::from_field_ip_addr(field, ip_addr))])
+            }
+            FieldType::Bool(_) => {
+                let val: bool = bool::from_str(phrase)?;
+                let term = Term::from_field_bool(field, val);
+                Ok(vec![(0, term)])
+            }
             FieldType::Str(ref str_options) => {
                 if let Some(option) = str_options.get_indexing_options() {
                     let tokenizer =
@@ -359,24 +615,127 @@ impl QueryParser {
                 let facet = Facet::from_text(phrase);
                 Ok(vec![(0, Term::from_field_text(field, facet.encoded_str()))])
             }
-            FieldType::Bytes => {
+            FieldType::Bytes(_) => {
                 let field_name = self.schema.get_field_name(field).to_string();
                 Err(QueryParserError::FieldNotIndexed(field_name))
             }
+            FieldType::JsonObject(_) => self.compute_terms_for_json(field, json_path, phrase),
+        }
+    }
+
+    /// Infers the type of the value from the phrase, in the same order
+    /// as the one used at indexing time: `i64`, `u64`, `f64`, `bool`,
+    /// and text otherwise.
+    fn compute_terms_for_json(
+        &self,
+        field: Field,
+        json_path: &str,
+        phrase: &str,
+    ) -> Result<Vec<(usize, Term)>, QueryParserError> {
+        if let Some(term) = self.compute_typed_term_for_json(field, json_path, phrase) {
+            return Ok(vec![(0, term)]);
+        }
+        self.compute_text_terms_for_json(field, json_path, phrase)
+    }
+
+    /// Returns the term of the phrase interpreted as a `i64`, `u64`, `f64` or `bool`,
+    /// in this order, or `None` if the phrase is not a json number or boolean.
+    fn compute_typed_term_for_json(
+        &self,
+        field: Field,
+        json_path: &str,
+        phrase: &str,
+    ) -> Option<Term> {
+        let mut json_term_writer = JsonTermWriter::from_json_path(field, json_path);
+        if let Ok(val) = i64::from_str(phrase) {
+            json_term_writer.set_i64(val);
+        } else if let Ok(val) = u64::from_str(phrase) {
+            json_term_writer.set_u64(val);
+        } else if let Some(val) = f64::from_str(phrase).ok().filter(|val| val.is_finite()) {
+            // `inf` or `NaN` are not json numbers, and are searched as text.
+            json_term_writer.set_f64(val);
+        } else if let Ok(val) = bool::from_str(phrase) {
+            json_term_writer.set_bool(val);
+        } else {
+            return None;
         }
+        Some(json_term_writer.term().clone())
+    }
+
+    /// Returns the terms of the phrase interpreted as text.
+    fn compute_text_terms_for_json(
+        &self,
+        field: Field,
+        json_path: &str,
+        phrase: &str,
+    ) -> Result<Vec<(usize, Term)>, QueryParserError> {
+        let field_entry = self.schema.get_field_entry(field);
+        let indexing_options = match field_entry.field_type() {
+            FieldType::JsonObject(json_options) => json_options.get_indexing_options(),
+            _ => None,
+        }
+        .ok_or_else(|| QueryParserError::FieldNotIndexed(field_entry.name().to_string()))?;
+        let tokenizer = self
+            .tokenizer_manager
+            .get(indexing_options.tokenizer())
+            .ok_or_else(|| {
+                QueryParserError::UnknownTokenizer(
+                    field_entry.name().to_string(),
+                    indexing_options.tokenizer().to_string(),
+                )
+            })?;
+        let mut json_term_writer = JsonTermWriter::from_json_path(field, json_path);
+        let mut terms: Vec<(usize, Term)> = Vec::new();
+        tokenizer.token_stream(phrase).process(&mut |token| {
+            json_term_writer.set_text(&token.text);
+            terms.push((token.position, json_term_writer.term().clone()));
+        });
+        if terms.len() > 1 && !indexing_options.index_option().has_positions() {
+            return Err(QueryParserError::FieldDoesNotHavePositionsIndexed(
+                field_entry.name().to_string(),
+            ));
+        }
+        Ok(terms)
     }
 
     fn compute_logical_ast_for_leaf(
         &self,
         field: Field,
+        json_path: &str,
         phrase: &str,
-    ) -> Result<Option<LogicalLiteral>, QueryParserError> {
-        let terms = self.compute_terms_for_string(field, phrase)?;
-        match &terms[..] {
-            [] => Ok(None),
-            [(_, term)] => Ok(Some(LogicalLiteral::Term(term.clone()))),
-            _ => Ok(Some(LogicalLiteral::Phrase(terms.clone()))),
+    ) -> Result<Option<LogicalAST>, QueryParserError> {
+        let field_entry = self.schema.get_field_entry(field);
+        match field_entry.field_type() {
+            FieldType::IpAddr(_) if field_entry.is_indexed() && phrase.contains('/') => {
+                let (first, last) = parse_ip_cidr(phrase)
+                    .ok_or_else(|| QueryParserError::ExpectedIpAddr(phrase.to_string()))?;
+                return Ok(Some(LogicalAST::from(LogicalLiteral::Range {
+                    field,
+                    value_type: Type::IpAddr,
+                    lower: Bound::Included(Term::from_field_ip_addr(field, IpAddr::V6(first))),
+                    upper: Bound::Included(Term::from_field_ip_addr(field, IpAddr::V6(last))),
+                })));
+            }
+            FieldType::JsonObject(_) if field_entry.is_indexed() => {
+                // A json leaf may have been indexed as text even though it looks like
+                // a number or a boolean, e.g. `{"status": "503"}`: both are searched.
+                let text_terms = self.compute_text_terms_for_json(field, json_path, phrase)?;
+                let text_ast = logical_ast_for_terms(text_terms);
+                let typed_ast = self
+                    .compute_typed_term_for_json(field, json_path, phrase)
+                    .map(|term| LogicalAST::from(LogicalLiteral::Term(term)));
+                return Ok(match (typed_ast, text_ast) {
+                    (Some(typed_ast), Some(text_ast)) => Some(LogicalAST::Clause(vec![
+                        (Occur::Should, typed_ast),
+                        (Occur::Should, text_ast),
+                    ])),
+                    (typed_ast, text_ast) => typed_ast.or(text_ast),
+                });
+            }
+            _ => {}
         }
+        let terms = self.compute_terms_for_string(field, json_path, phrase)?;
+        Ok(logical_ast_for_terms(terms))
     }
 
     fn default_occur(&self) -> Occur {
@@ -395,7 +754,7 @@ impl QueryParser {
         if bound.term_str() == "*" {
             return Ok(Bound::Unbounded);
         }
-        let terms = self.compute_terms_for_string(field, bound.term_str())?;
+        let terms = self.compute_terms_for_string(field, "", bound.term_str())?;
         if terms.len() != 1 {
             return Err(QueryParserError::RangeMustNotHavePhrase);
         }
@@ -456,10 +815,10 @@ impl QueryParser {
     ) -> Result<LogicalAST, QueryParserError> {
         match leaf {
             UserInputLeaf::Literal(literal) => {
-                let term_phrases: Vec<(Field, String)> = match literal.field_name {
+                let term_phrases: Vec<(Field, &str, String)> = match literal.field_name {
                     Some(ref field_name) => {
-                        let field = self.resolve_field_name(field_name)?;
-                        vec![(field, literal.phrase.clone())]
+                        let (field, json_path) = self.resolve_field_and_json_path(field_name)?;
+                        vec![(field, json_path, literal.phrase.clone())]
                     }
                     None => {
                         if self.default_fields.is_empty() {
@@ -467,17 +826,19 @@ impl QueryParser {
                         } else {
                             self.default_fields
                                 .iter()
-                                .map(|default_field| (*default_field, literal.phrase.clone()))
-                                .collect::<Vec<(Field, String)>>()
+                                .map(|default_field| (*default_field, "", literal.phrase.clone()))
+                                .collect::<Vec<(Field, &str, String)>>()
                         }
                     }
                 };
                 let mut asts: Vec<LogicalAST> = Vec::new();
-                for (field, phrase) in term_phrases {
-                    if let Some(ast) = self.compute_logical_ast_for_leaf(field, &phrase)? {
+                for (field, json_path, phrase) in term_phrases {
+                    if let Some(ast) =
+                        self.compute_logical_ast_for_leaf(field, json_path, &phrase)?
+                    {
                         // Apply some field specific boost defined at the query parser level.
                         let boost = self.field_boost(field);
-                        asts.push(LogicalAST::Leaf(Box::new(ast)).boost(boost));
+                        asts.push(ast.boost(boost));
                     }
                 }
                 let result_ast: LogicalAST = if asts.len() == 1 {
@@ -525,6 +886,15 @@ impl QueryParser {
     }
 }
 
+fn logical_ast_for_terms(mut terms: Vec<(usize, Term)>) -> Option<LogicalAST> {
+    let literal = match terms.len() {
+        0 => return None,
+        1 => LogicalLiteral::Term(terms.pop().unwrap().1),
+        _ => LogicalLiteral::Phrase(terms),
+    };
+    Some(LogicalAST::from(literal))
+}
+
 fn convert_literal_to_query(logical_literal: LogicalLiteral) -> Box<dyn Query> {
     match logical_literal {
         LogicalLiteral::Term(term) => Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs)),
@@ -582,6 +952,7 @@ mod test {
     };
     use crate::Index;
     use matches::assert_matches;
+    use tantivy_query_grammar::UserInputAST;
 
     fn make_schema() -> Schema {
         let mut schema_builder = Schema::builder();
@@ -603,6 +974,9 @@ mod test {
         schema_builder.add_date_field("date", INDEXED);
         schema_builder.add_f64_field("float", INDEXED);
         schema_builder.add_facet_field("facet");
+        schema_builder.add_bool_field("bool", INDEXED);
+        schema_builder.add_ip_addr_field("ip", INDEXED);
+        schema_builder.add_json_object_field("json", TEXT);
         schema_builder.build()
     }
 
@@ -904,6 +1278,117 @@ mod test {
         );
     }
 
+    #[test]
+    pub fn test_query_parser_syntax_error() {
+        let query_parser = make_query_parser();
+        assert_eq!(
+            query_parser.parse_query("title:").unwrap_err(),
+            QueryParserError::SyntaxError(6)
+        );
+        assert_eq!(
+            query_parser.parse_query("title:a (").unwrap_err(),
+            QueryParserError::SyntaxError(9)
+        );
+    }
+
+    fn parse_query_lenient_to_debug(query: &str) -> (String, Vec<QueryParserError>) {
+        let query_parser = make_query_parser();
+        let (query, errors) = query_parser.parse_query_lenient(query);
+        (format!("{:?}", query), errors)
+    }
+
+    #[test]
+    pub fn test_parse_query_lenient() {
+        let (query, errors) = parse_query_lenient_to_debug("title:a");
+        assert_eq!(query, "TermQuery(Term(field=0,bytes=[97]))");
+        assert!(errors.is_empty());
+        let (query, errors) = parse_query_lenient_to_debug("title:a (");
+        assert_eq!(
+            query,
+            "BooleanQuery { subqueries: [\
+             (Should, TermQuery(Term(field=0,bytes=[97])))], \
+             minimum_should_match: 0 }"
+        );
+        assert_eq!(errors, vec![QueryParserError::SyntaxError(9)]);
+        let (query, errors) = parse_query_lenient_to_debug("title:a -title:b title:");
+        assert_eq!(
+            query,
+            "BooleanQuery { subqueries: [\
+             (Should, TermQuery(Term(field=0,bytes=[97]))), \
+             (MustNot, TermQuery(Term(field=0,bytes=[98])))], \
+             minimum_should_match: 0 }"
+        );
+        assert_eq!(errors, vec![QueryParserError::SyntaxError(23)]);
+        let (query, errors) = parse_query_lenient_to_debug("title:a signed:abc");
+        assert_eq!(
+            query,
+            "BooleanQuery { subqueries: [\
+             (Should, TermQuery(Term(field=0,bytes=[97])))], \
+             minimum_should_match: 0 }"
+        );
+        assert_matches!(&errors[..], [QueryParserError::ExpectedInt(_)]);
+        let (query, errors) = parse_query_lenient_to_debug("title:a doesnotexist:b");
+        assert_eq!(
+            query,
+            "BooleanQuery { subqueries: [\
+             (Should, TermQuery(Term(field=0,bytes=[97])))], \
+             minimum_should_match: 0 }"
+        );
+        assert_eq!(
+            errors,
+            vec![QueryParserError::FieldDoesNotExist(
+                "doesnotexist".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_query_lenient_keeps_valid_clauses() {
+        let (query, errors) = parse_query_lenient_to_debug("title:\"a b\" AND (c OR");
+        assert_eq!(
+            query,
+            "BooleanQuery { subqueries: [\
+             (Should, PhraseQuery { field: Field(0), phrase_terms: [\
+             (0, Term(field=0,bytes=[97])), (1, Term(field=0,bytes=[98]))], slop: 0 }), \
+             (Should, BooleanQuery { subqueries: [\
+             (Should, TermQuery(Term(field=0,bytes=[99]))), \
+             (Should, TermQuery(Term(field=1,bytes=[99])))], minimum_should_match: 0 })], \
+             minimum_should_match: 0 }"
+        );
+        assert_eq!(errors, vec![QueryParserError::SyntaxError(21)]);
+        let (query, errors) = parse_query_lenient_to_debug("(title:a OR title:b) ( title:\"a b\"");
+        assert_eq!(
+            query,
+            "BooleanQuery { subqueries: [\
+             (Should, BooleanQuery { subqueries: [\
+             (Should, TermQuery(Term(field=0,bytes=[97]))), \
+             (Should, TermQuery(Term(field=0,bytes=[98])))], minimum_should_match: 0 }), \
+             (Should, PhraseQuery { field: Field(0), phrase_terms: [\
+             (0, Term(field=0,bytes=[97])), (1, Term(field=0,bytes=[98]))], slop: 0 })], \
+             minimum_should_match: 0 }"
+        );
+        assert_eq!(errors, vec![QueryParserError::SyntaxError(34)]);
+    }
+
+    #[test]
+    pub fn test_parse_query_lenient_all_but_forbidden() {
+        let (query, errors) = parse_query_lenient_to_debug("-title:a");
+        assert_eq!(query, "EmptyQuery");
+        assert_eq!(errors, vec![QueryParserError::AllButQueryForbidden]);
+        let (query, errors) = parse_query_lenient_to_debug("-title:a signed:abc");
+        assert_eq!(query, "EmptyQuery");
+        assert_matches!(
+            &errors[..],
+            [
+                QueryParserError::ExpectedInt(_),
+                QueryParserError::AllButQueryForbidden
+            ]
+        );
+        let (query, errors) = parse_query_lenient_to_debug("");
+        assert_eq!(query, "EmptyQuery");
+        assert!(errors.is_empty());
+    }
+
     #[test]
     pub fn test_query_parser_field_not_indexed() {
         let query_parser = make_query_parser();
@@ -970,6 +1455,108 @@ mod test {
         );
     }
 
+    #[test]
+    pub fn test_query_parser_expected_bool() {
+        let query_parser = make_query_parser();
+        assert_matches!(
+            query_parser.parse_query("bool:yes"),
+            Err(QueryParserError::ExpectedBool(_))
+        );
+        test_parse_query_to_logical_ast_helper(
+            "bool:true",
+            &format!(
+                "{:?}",
+                Term::from_field_bool(Field::from_field_id(12u32), true)
+            ),
+            false,
+        );
+        test_parse_query_to_logical_ast_helper(
+            "bool:false",
+            &format!(
+                "{:?}",
+                Term::from_field_bool(Field::from_field_id(12u32), false)
+            ),
+            false,
+        );
+    }
+
+    #[test]
+    pub fn test_query_parser_expected_ip_addr() {
+        let query_parser = make_query_parser();
+        assert!(query_parser.parse_query("ip:127.0.0.1").is_ok());
+        assert!(query_parser.parse_query("ip:\"::1\"").is_ok());
+        assert!(query_parser.parse_query("ip:127.0.0.0/8").is_ok());
+        assert_matches!(
+            query_parser.parse_query("ip:127.0.0"),
+            Err(QueryParserError::ExpectedIpAddr(_))
+        );
+        assert_matches!(
+            query_parser.parse_query("ip:127.0.0.0/33"),
+            Err(QueryParserError::ExpectedIpAddr(_))
+        );
+        assert_matches!(
+            query_parser.parse_query("ip:127.0.0.0/a"),
+            Err(QueryParserError::ExpectedIpAddr(_))
+        );
+    }
+
+    #[test]
+    pub fn test_parse_query_json_object() {
+        test_parse_query_to_logical_ast_helper(
+            "json.a.b:hello",
+            "Term(field=14,bytes=[97, 1, 98, 0, 115, 104, 101, 108, 108, 111])",
+            false,
+        );
+        test_parse_query_to_logical_ast_helper(
+            "json.a:-1",
+            "(Term(field=14,bytes=[97, 0, 105, 127, 255, 255, 255, 255, 255, 255, 255]) \
+             Term(field=14,bytes=[97, 0, 115, 49]))",
+            false,
+        );
+        test_parse_query_to_logical_ast_helper(
+            "json.a:true",
+            "(Term(field=14,bytes=[97, 0, 111, 0, 0, 0, 0, 0, 0, 0, 1]) \
+             Term(field=14,bytes=[97, 0, 115, 116, 114, 117, 101]))",
+            false,
+        );
+        test_parse_query_to_logical_ast_helper(
+            "json.a:\"hello happy\"",
+            "\"[(0, Term(field=14,bytes=[97, 0, 115, 104, 101, 108, 108, 111])), \
+             (1, Term(field=14,bytes=[97, 0, 115, 104, 97, 112, 112, 121]))]\"",
+            false,
+        );
+        assert_matches!(
+            make_query_parser().parse_query("title.a:hello"),
+            Err(QueryParserError::FieldDoesNotExist(_))
+        );
+    }
+
+    #[test]
+    fn test_parse_ip_cidr() {
+        let parse = |phrase: &str| {
+            super::parse_ip_cidr(phrase).map(|(first, last)| (first.to_string(), last.to_string()))
+        };
+        assert_eq!(
+            parse("10.1.2.3/16"),
+            Some((
+                "::ffff:10.1.0.0".to_string(),
+                "::ffff:10.1.255.255".to_string()
+            ))
+        );
+        assert_eq!(
+            parse("2001:db8::1/128"),
+            Some(("2001:db8::1".to_string(), "2001:db8::1".to_string()))
+        );
+        assert_eq!(
+            parse("::/0"),
+            Some((
+                "::".to_string(),
+                "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".to_string()
+            ))
+        );
+        assert_eq!(parse("10.1.2.3"), None);
+    }
+
     #[test]
     pub fn test_query_parser_expected_date() {
         let query_parser = make_query_parser();
@@ -980,6 +1567,26 @@ mod test {
         assert!(query_parser
             .parse_query("date:\"1985-04-12T23:20:50.52Z\"")
             .is_ok());
+        assert!(query_parser.parse_query("date:1985-04-12").is_ok());
+        assert_matches!(
+            query_parser.parse_query("date:1985-13-12"),
+            Err(QueryParserError::DateFormatError(_))
+        );
+    }
+
+    #[test]
+    pub fn test_parse_query_date_range() {
+        test_parse_query_to_logical_ast_helper(
+            "date:>=2020-01-01",
+            "(Included(Term(field=9,bytes=[128, 0, 0, 0, 94, 11, 225, 0])) TO Unbounded)",
+            false,
+        );
+        test_parse_query_to_logical_ast_helper(
+            "date:[2020-01-01 TO 2020-01-02}",
+            "(Included(Term(field=9,bytes=[128, 0, 0, 0, 94, 11, 225, 0])) TO \
+             Excluded(Term(field=9,bytes=[128, 0, 0, 0, 94, 13, 50, 128])))",
+            false,
+        );
     }
 
     #[test]
@@ -1065,4 +1672,26 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_user_input_ast_serde() {
+        let query_parser = make_query_parser();
+        let query = "+title:a -text:\"b c\" signed:[-3 TO 5}^2";
+        let user_input_ast = query_parser.parse_query_to_user_input_ast(query).unwrap();
+        let json = serde_json::to_string(&user_input_ast).unwrap();
+        let deserialized_ast: UserInputAST = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            format!("{:?}", deserialized_ast),
+            format!("{:?}", user_input_ast)
+        );
+        let built_query = query_parser
+            .build_query_from_user_input_ast(deserialized_ast)
+            .unwrap();
+        let parsed_query = query_parser.parse_query(query).unwrap();
+        assert_eq!(format!("{:?}", built_query), format!("{:?}", parsed_query));
+        assert_matches!(
+            query_parser.parse_query_to_user_input_ast("title:"),
+            Err(QueryParserError::SyntaxError(6))
+        );
+    }
 }
@@ -12,6 +12,7 @@ use crate::termdict::{TermDictionary, TermStreamer};
 use crate::DocId;
 use crate::Result;
 use std::collections::Bound;
+use std::net::IpAddr;
 use std::ops::Range;
 
 fn map_bound<TFrom, TTo, Transform: Fn(&TFrom) -> TTo>(
@@ -196,6 +197,31 @@ impl RangeQuery {
         )
     }
 
+    /// Create a new `RangeQuery` over an ip address field.
+    ///
+    /// The two `Bound` arguments make it possible to create more complex
+    /// ranges than semi-inclusive range.
+    ///
+    /// If the field is not of the type `IpAddr`, tantivy
+    /// will panic when the `Weight` object is created.
+    pub fn new_ip_addr_bounds(
+        field: Field,
+        left_bound: Bound<IpAddr>,
+        right_bound: Bound<IpAddr>,
+    ) -> RangeQuery {
+        let make_term_val = |val: &IpAddr| {
+            Term::from_field_ip_addr(field, *val)
+                .value_bytes()
+                .to_owned()
+        };
+        RangeQuery {
+            field,
+            value_type: Type::IpAddr,
+            left_bound: map_bound(&left_bound, &make_term_val),
+            right_bound: map_bound(&right_bound, &make_term_val),
+        }
+    }
+
     /// Create a new `RangeQuery` over a `Str` field.
     ///
     /// The two `Bound` arguments make it possible to create more complex
@@ -333,6 +359,7 @@ mod tests {
     use crate::schema::{Document, Field, Schema, INDEXED, TEXT};
     use crate::Index;
     use std::collections::Bound;
+    use std::net::IpAddr;
 
     #[test]
     fn test_range_query_simple() {
@@ -478,6 +505,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_range_ip_addr() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let ip_field = schema_builder.add_ip_addr_field("ip", INDEXED);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000)?;
+        for i in 0u8..=255u8 {
+            let mut doc = Document::new();
+            doc.add_ip_addr(ip_field, IpAddr::from([10, 0, i, 1]));
+            index_writer.add_document(doc);
+        }
+        for ip in &["192.168.1.1", "2001:db8::1", "2001:db8:1::1", "::1"] {
+            let mut doc = Document::new();
+            doc.add_ip_addr(ip_field, ip.parse().unwrap());
+            index_writer.add_document(doc);
+        }
+        index_writer.commit()?;
+        let reader = index.reader()?;
+        let searcher = reader.searcher();
+        let count = |range_query: RangeQuery| searcher.search(&range_query, &Count).unwrap();
+        assert_eq!(
+            count(RangeQuery::new_ip_addr_bounds(
+                ip_field,
+                Bound::Included(IpAddr::from([10, 0, 10, 0])),
+                Bound::Excluded(IpAddr::from([10, 0, 20, 1]))
+            )),
+            10
+        );
+        let query_parser = QueryParser::for_index(&index, vec![]);
+        let count_query = |query: &str| {
+            let query = query_parser.parse_query(query).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count_query("ip:10.0.3.1"), 1);
+        assert_eq!(count_query("ip:10.0.0.0/8"), 256);
+        assert_eq!(count_query("ip:10.0.0.0/23"), 2);
+        assert_eq!(count_query("ip:192.168.0.0/16"), 1);
+        assert_eq!(count_query("ip:\"2001:db8::/32\""), 2);
+        assert_eq!(count_query("ip:\"2001:db8::/48\""), 1);
+        assert_eq!(count_query("ip:\"::/0\""), 260);
+        assert_eq!(count_query("ip:[10.0.0.1 TO 10.0.9.1]"), 10);
+        Ok(())
+    }
+
     #[test]
     fn test_bug_reproduce_range_query() -> crate::Result<()> {
         let mut schema_builder = Schema::builder();
@@ -53,6 +53,7 @@ use tantivy_fst::Regex;
 pub struct RegexQuery {
     regex: Arc<Regex>,
     field: Field,
+    max_expansions: Option<usize>,
 }
 
 impl RegexQuery {
@@ -68,11 +69,23 @@ impl RegexQuery {
         RegexQuery {
             regex: regex.into(),
             field,
+            max_expansions: None,
         }
     }
 
+    /// Caps the number of terms the regex may expand to within a segment.
+    ///
+    /// Searching fails with an `InvalidArgument` error if the cap is exceeded.
+    pub fn set_max_expansions(&mut self, max_expansions: usize) {
+        self.max_expansions = Some(max_expansions);
+    }
+
     fn specialized_weight(&self) -> AutomatonWeight<Regex> {
-        AutomatonWeight::new(self.field, self.regex.clone())
+        let mut weight = AutomatonWeight::new(self.field, self.regex.clone());
+        if let Some(max_expansions) = self.max_expansions {
+            weight.set_max_expansions(max_expansions);
+        }
+        weight
     }
 }
 
@@ -90,7 +103,7 @@ impl Query for RegexQuery {
 mod test {
     use super::RegexQuery;
     use crate::assert_nearly_equals;
-    use crate::collector::TopDocs;
+    use crate::collector::{Count, TopDocs};
     use crate::schema::TEXT;
     use crate::schema::{Field, Schema};
     use crate::{Index, IndexReader};
@@ -174,4 +187,15 @@ mod test {
 
         verify_regex_query(matching_one, matching_zero, reader.clone());
     }
+
+    #[test]
+    pub fn test_regex_query_max_expansions() {
+        let (reader, field) = build_test_index();
+        let searcher = reader.searcher();
+        let mut query = RegexQuery::from_pattern("[jk].*", field).unwrap();
+        query.set_max_expansions(2);
+        assert_eq!(searcher.search(&query, &Count).unwrap(), 2);
+        query.set_max_expansions(1);
+        assert!(searcher.search(&query, &Count).is_err());
+    }
 }
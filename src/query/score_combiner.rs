@@ -78,3 +78,100 @@ impl ScoreCombiner for SumWithCoordsCombiner {
         self.score
     }
 }
+
+/// Counts the number of scorers which matched.
+///
+/// The scorers `.score()` function is never called, and the
+/// aggregate score is simply the number of matching scorers.
+/// It is useful to compute coordination factors, or to
+/// enforce a minimum number of matching clauses.
+///
+/// ```rust
+/// use tantivy::query::{CountCombiner, Query, Scorer, TermQuery, Union};
+/// use tantivy::schema::{IndexRecordOption, Schema, TEXT};
+/// use tantivy::{doc, DocSet, Index, Term, TERMINATED};
+///
+/// # fn main() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let text = schema_builder.add_text_field("text", TEXT);
+/// let index = Index::create_in_ram(schema_builder.build());
+/// let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+/// index_writer.add_document(doc!(text => "a b c"));
+/// index_writer.add_document(doc!(text => "b"));
+/// index_writer.commit()?;
+/// let searcher = index.reader()?.searcher();
+///
+/// let mut scorers = Vec::new();
+/// for word in &["a", "b", "c"] {
+///     let query = TermQuery::new(Term::from_field_text(text, word), IndexRecordOption::Basic);
+///     let weight = query.weight(&searcher, false)?;
+///     scorers.push(weight.scorer(searcher.segment_reader(0), 1.0f32)?);
+/// }
+/// // The score of each document is the number of terms it contains.
+/// let mut union = Union::<_, CountCombiner>::from(scorers);
+/// let mut counts = Vec::new();
+/// while union.doc() != TERMINATED {
+///     counts.push((union.doc(), union.score()));
+///     union.advance();
+/// }
+/// assert_eq!(counts, vec![(0, 3.0), (1, 1.0)]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Clone, Copy)]
+pub struct CountCombiner {
+    count: u32,
+}
+
+impl ScoreCombiner for CountCombiner {
+    fn update<TScorer: Scorer>(&mut self, _scorer: &mut TScorer) {
+        self.count += 1;
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    fn score(&self) -> Score {
+        self.count as Score
+    }
+}
+
+/// Takes the maximum score of the matching scorers, plus
+/// the sum of the other scores multiplied by a tie breaker.
+///
+/// This is the score combiner of the `DisjunctionMaxQuery`.
+#[derive(Default, Clone, Copy)]
+pub struct DisjunctionMaxCombiner {
+    tie_breaker: Score,
+    max: Score,
+    sum: Score,
+}
+
+impl DisjunctionMaxCombiner {
+    /// Creates a `DisjunctionMaxCombiner` with the given tie breaker.
+    pub fn with_tie_breaker(tie_breaker: Score) -> DisjunctionMaxCombiner {
+        DisjunctionMaxCombiner {
+            tie_breaker,
+            max: 0f32,
+            sum: 0f32,
+        }
+    }
+}
+
+impl ScoreCombiner for DisjunctionMaxCombiner {
+    fn update<TScorer: Scorer>(&mut self, scorer: &mut TScorer) {
+        let score = scorer.score();
+        self.max = self.max.max(score);
+        self.sum += score;
+    }
+
+    fn clear(&mut self) {
+        self.max = 0f32;
+        self.sum = 0f32;
+    }
+
+    fn score(&self) -> Score {
+        self.max + self.tie_breaker * (self.sum - self.max)
+    }
+}
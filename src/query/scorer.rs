@@ -38,6 +38,10 @@ impl<TDocSet: DocSet> ConstScorer<TDocSet> {
     pub fn new(docset: TDocSet, score: f32) -> ConstScorer<TDocSet> {
         ConstScorer { docset, score }
     }
+
+    pub(crate) fn into_docset(self) -> TDocSet {
+        self.docset
+    }
 }
 
 impl<TDocSet: DocSet> From<TDocSet> for ConstScorer<TDocSet> {
@@ -0,0 +1,439 @@
+use crate::fieldnorm::FieldNormReader;
+use crate::query::Explanation;
+use crate::Score;
+use crate::Searcher;
+use crate::Term;
+use std::sync::Arc;
+
+/// A `Similarity` defines how the score of a term within a document
+/// is computed.
+///
+/// The score of a term is computed as the product of an inverse document
+/// frequency component, computed once per query, and of a term frequency
+/// component, computed for each document and normalized by the length
+/// of the field.
+///
+/// tantivy ships with [`BM25Similarity`](./struct.BM25Similarity.html), used by default,
+/// and the classic [`TfIdfSimilarity`](./struct.TfIdfSimilarity.html).
+/// The similarity used for a given field can be configured on the `Index`.
+pub trait Similarity: Send + Sync + 'static {
+    /// Returns the inverse document frequency component of the score,
+    /// given the number of documents containing the term and the total
+    /// number of documents.
+    fn idf(&self, doc_freq: u64, doc_count: u64) -> Score;
+
+    /// Returns the term frequency component of the score, given the number
+    /// of occurrences of the term within the document, the length of the field
+    /// in this document, and the average length of the field.
+    fn tf(&self, term_freq: u32, fieldnorm: u32, average_fieldnorm: Score) -> Score;
+
+    /// Returns the score of a term given its (possibly boosted) inverse document
+    /// frequency component.
+    ///
+    /// By default, this is simply `idf * tf`.
+    fn score(&self, idf: Score, term_freq: u32, fieldnorm: u32, average_fieldnorm: Score) -> Score {
+        idf * self.tf(term_freq, fieldnorm, average_fieldnorm)
+    }
+
+    /// Returns an upper bound of the score of a term in the documents
+    /// in which it appears at most `max_term_freq` times.
+    ///
+    /// It is used to skip the documents that cannot make it to the top K
+    /// when searching with `TopDocs`.
+    ///
+    /// By default, it is the score of an empty field containing the term
+    /// `max_term_freq` times, which is correct as long as the score does not
+    /// decrease with the term frequency and does not increase with the field length.
+    /// Similarities for which this does not hold must override this method.
+    fn max_score(&self, idf: Score, max_term_freq: u32, average_fieldnorm: Score) -> Score {
+        self.score(idf, max_term_freq, 0u32, average_fieldnorm)
+    }
+
+    /// Returns the length normalization of each fieldnorm id, if the score
+    /// can be computed from a `NormTable`, as for BM25.
+    ///
+    /// The table is computed once per query, so that scoring a document
+    /// only requires a table lookup. It must give the same scores as `score`.
+    ///
+    /// By default, this returns `None` and `score` is called for each document.
+    fn norm_table(&self, _average_fieldnorm: Score) -> Option<NormTable> {
+        None
+    }
+
+    /// Explains the inverse document frequency component of the score.
+    fn explain_idf(&self, doc_freq: u64, doc_count: u64) -> Explanation {
+        let mut idf_explain = Explanation::new("idf", self.idf(doc_freq, doc_count));
+        idf_explain.add_const("n, number of docs containing this term", doc_freq as Score);
+        idf_explain.add_const("N, total number of docs", doc_count as Score);
+        idf_explain
+    }
+
+    /// Explains the score of a term within a document.
+    fn explain(
+        &self,
+        score: Score,
+        idf_explain: &Explanation,
+        term_freq: u32,
+        fieldnorm: u32,
+        average_fieldnorm: Score,
+    ) -> Explanation {
+        let mut tf_explanation =
+            Explanation::new("tf", self.tf(term_freq, fieldnorm, average_fieldnorm));
+        tf_explanation.add_const(
+            "freq, occurrences of term within document",
+            term_freq as Score,
+        );
+        tf_explanation.add_const("dl, length of field", fieldnorm as Score);
+        tf_explanation.add_const("avgdl, average length of field", average_fieldnorm);
+
+        let mut explanation = Explanation::new("TermQuery, product of...", score);
+        explanation.add_detail(idf_explain.clone());
+        explanation.add_detail(tf_explanation);
+        explanation
+    }
+}
+
+/// Length normalization of a similarity saturating the term frequency,
+/// precomputed for each fieldnorm id.
+///
+/// The score of a term is `idf * scale * term_freq / (term_freq + norms[fieldnorm_id])`.
+#[derive(Clone)]
+pub struct NormTable {
+    /// Factor applied to the inverse document frequency component.
+    pub scale: Score,
+    /// Normalization of the term frequency, indexed by fieldnorm id.
+    pub norms: [Score; 256],
+}
+
+/// Okapi BM25 similarity.
+///
+/// This is the similarity used by default.
+#[derive(Clone, Copy, Debug)]
+pub struct BM25Similarity {
+    k1: Score,
+    b: Score,
+}
+
+impl BM25Similarity {
+    fn norm(&self, fieldnorm: u32, average_fieldnorm: Score) -> Score {
+        self.k1 * (1f32 - self.b + self.b * fieldnorm as f32 / average_fieldnorm)
+    }
+
+    /// Creates a `BM25Similarity` with the given parameters.
+    ///
+    /// - `k1` controls the saturation of the term frequency
+    /// - `b` controls how much the field length normalizes the term frequency
+    pub fn new(k1: Score, b: Score) -> BM25Similarity {
+        BM25Similarity { k1, b }
+    }
+}
+
+impl Default for BM25Similarity {
+    fn default() -> BM25Similarity {
+        BM25Similarity::new(1.2, 0.75)
+    }
+}
+
+impl Similarity for BM25Similarity {
+    fn idf(&self, doc_freq: u64, doc_count: u64) -> Score {
+        let x = ((doc_count - doc_freq) as f32 + 0.5) / (doc_freq as f32 + 0.5);
+        (1f32 + x).ln()
+    }
+
+    fn tf(&self, term_freq: u32, fieldnorm: u32, average_fieldnorm: Score) -> Score {
+        self.score(1f32, term_freq, fieldnorm, average_fieldnorm)
+    }
+
+    #[inline(always)]
+    fn score(&self, idf: Score, term_freq: u32, fieldnorm: u32, average_fieldnorm: Score) -> Score {
+        let term_freq = term_freq as f32;
+        let norm = self.norm(fieldnorm, average_fieldnorm);
+        idf * (1f32 + self.k1) * term_freq / (term_freq + norm)
+    }
+
+    fn norm_table(&self, average_fieldnorm: Score) -> Option<NormTable> {
+        let mut norms = [0f32; 256];
+        for (fieldnorm_id, norm) in norms.iter_mut().enumerate() {
+            let fieldnorm = FieldNormReader::id_to_fieldnorm(fieldnorm_id as u8);
+            *norm = self.norm(fieldnorm, average_fieldnorm);
+        }
+        Some(NormTable {
+            scale: 1f32 + self.k1,
+            norms,
+        })
+    }
+
+    fn explain_idf(&self, doc_freq: u64, doc_count: u64) -> Explanation {
+        let mut idf_explain = Explanation::new(
+            "idf, computed as log(1 + (N - n + 0.5) / (n + 0.5))",
+            self.idf(doc_freq, doc_count),
+        );
+        idf_explain.add_const("n, number of docs containing this term", doc_freq as f32);
+        idf_explain.add_const("N, total number of docs", doc_count as f32);
+        idf_explain
+    }
+
+    fn explain(
+        &self,
+        score: Score,
+        idf_explain: &Explanation,
+        term_freq: u32,
+        fieldnorm: u32,
+        average_fieldnorm: Score,
+    ) -> Explanation {
+        // The explain format is directly copied from Lucene's.
+        // (So, Kudos to Lucene)
+        let norm = self.norm(fieldnorm, average_fieldnorm);
+        let term_freq = term_freq as f32;
+        let right_factor = term_freq / (term_freq + norm);
+
+        let mut tf_explanation = Explanation::new(
+            "freq / (freq + k1 * (1 - b + b * dl / avgdl))",
+            right_factor,
+        );
+
+        tf_explanation.add_const("freq, occurrences of term within document", term_freq);
+        tf_explanation.add_const("k1, term saturation parameter", self.k1);
+        tf_explanation.add_const("b, length normalization parameter", self.b);
+        tf_explanation.add_const("dl, length of field", fieldnorm as f32);
+        tf_explanation.add_const("avgdl, average length of field", average_fieldnorm);
+
+        let mut explanation = Explanation::new("TermQuery, product of...", score);
+        explanation.add_detail(Explanation::new("(K1+1)", self.k1 + 1f32));
+        explanation.add_detail(idf_explain.clone());
+        explanation.add_detail(tf_explanation);
+        explanation
+    }
+}
+
+/// Classic TF-IDF similarity.
+///
+/// The score is computed as `idf * sqrt(freq) / sqrt(dl)`, with
+/// `idf = 1 + log(N / (n + 1))`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TfIdfSimilarity;
+
+impl Similarity for TfIdfSimilarity {
+    fn idf(&self, doc_freq: u64, doc_count: u64) -> Score {
+        1f32 + (doc_count as f32 / (doc_freq as f32 + 1f32)).ln()
+    }
+
+    fn tf(&self, term_freq: u32, fieldnorm: u32, _average_fieldnorm: Score) -> Score {
+        let norm = if fieldnorm == 0 {
+            1f32
+        } else {
+            (fieldnorm as f32).sqrt()
+        };
+        (term_freq as f32).sqrt() / norm
+    }
+
+    fn explain_idf(&self, doc_freq: u64, doc_count: u64) -> Explanation {
+        let mut idf_explain = Explanation::new(
+            "idf, computed as 1 + log(N / (n + 1))",
+            self.idf(doc_freq, doc_count),
+        );
+        idf_explain.add_const("n, number of docs containing this term", doc_freq as f32);
+        idf_explain.add_const("N, total number of docs", doc_count as f32);
+        idf_explain
+    }
+}
+
+/// The part of the scoring computed once per query and per field.
+pub struct SimilarityWeight {
+    similarity: Arc<dyn Similarity>,
+    idf_explain: Explanation,
+    weight: f32,
+    average_fieldnorm: f32,
+    norm_table: Option<Arc<NormTable>>,
+    // `weight * norm_table.scale`
+    norm_weight: f32,
+}
+
+impl SimilarityWeight {
+    pub fn boost_by(&self, boost: f32) -> SimilarityWeight {
+        let weight = self.weight * boost;
+        SimilarityWeight {
+            similarity: self.similarity.clone(),
+            idf_explain: self.idf_explain.clone(),
+            weight,
+            average_fieldnorm: self.average_fieldnorm,
+            norm_table: self.norm_table.clone(),
+            norm_weight: norm_weight(weight, self.norm_table.as_deref()),
+        }
+    }
+
+    pub fn for_terms(searcher: &Searcher, terms: &[Term]) -> SimilarityWeight {
+        assert!(!terms.is_empty(), "Scoring requires at least one term");
+        let field = terms[0].field();
+        for term in &terms[1..] {
+            assert_eq!(
+                term.field(),
+                field,
+                "All terms must belong to the same field."
+            );
+        }
+        let similarity = searcher.index().similarity_for_field(field);
+
+        let total_num_tokens = searcher.total_num_tokens(field);
+        let total_num_docs: u64 = searcher
+            .segment_readers()
+            .iter()
+            .map(|segment_reader| u64::from(segment_reader.max_doc()))
+            .sum();
+        let average_fieldnorm = total_num_tokens as f32 / total_num_docs as f32;
+
+        let idf_explain: Explanation;
+        if terms.len() == 1 {
+            let term_doc_freq = searcher.doc_freq(&terms[0]);
+            idf_explain = similarity.explain_idf(term_doc_freq, total_num_docs);
+        } else {
+            let idf = terms
+                .iter()
+                .map(|term| {
+                    let term_doc_freq = searcher.doc_freq(term);
+                    similarity.idf(term_doc_freq, total_num_docs)
+                })
+                .sum::<f32>();
+            idf_explain = Explanation::new("idf", idf);
+        }
+        SimilarityWeight::new(similarity, idf_explain, average_fieldnorm)
+    }
+
+    fn new(
+        similarity: Arc<dyn Similarity>,
+        idf_explain: Explanation,
+        average_fieldnorm: f32,
+    ) -> SimilarityWeight {
+        let weight = idf_explain.value();
+        let norm_table = similarity.norm_table(average_fieldnorm).map(Arc::new);
+        let norm_weight = norm_weight(weight, norm_table.as_deref());
+        SimilarityWeight {
+            similarity,
+            idf_explain,
+            weight,
+            average_fieldnorm,
+            norm_table,
+            norm_weight,
+        }
+    }
+
+    #[inline(always)]
+    pub fn score(&self, fieldnorm_id: u8, term_freq: u32) -> Score {
+        if let Some(norm_table) = self.norm_table.as_ref() {
+            let term_freq = term_freq as f32;
+            let norm = norm_table.norms[fieldnorm_id as usize];
+            return self.norm_weight * term_freq / (term_freq + norm);
+        }
+        let fieldnorm = FieldNormReader::id_to_fieldnorm(fieldnorm_id);
+        self.similarity
+            .score(self.weight, term_freq, fieldnorm, self.average_fieldnorm)
+    }
+
+    /// Returns an upper bound of the score of the documents in which the term
+    /// appears at most `max_term_freq` times.
+    pub fn max_score(&self, max_term_freq: u32) -> Score {
+        self.similarity
+            .max_score(self.weight, max_term_freq, self.average_fieldnorm)
+    }
+
+    pub fn explain(&self, fieldnorm_id: u8, term_freq: u32) -> Explanation {
+        let score = self.score(fieldnorm_id, term_freq);
+        let fieldnorm = FieldNormReader::id_to_fieldnorm(fieldnorm_id);
+        self.similarity.explain(
+            score,
+            &self.idf_explain,
+            term_freq,
+            fieldnorm,
+            self.average_fieldnorm,
+        )
+    }
+}
+
+fn norm_weight(weight: f32, norm_table: Option<&NormTable>) -> f32 {
+    norm_table
+        .map(|norm_table| weight * norm_table.scale)
+        .unwrap_or(weight)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{BM25Similarity, Similarity, SimilarityWeight, TfIdfSimilarity};
+    use crate::assert_nearly_equals;
+    use crate::collector::TopDocs;
+    use crate::fieldnorm::FieldNormReader;
+    use crate::query::{Explanation, TermQuery};
+    use crate::schema::{IndexRecordOption, Schema, TEXT};
+    use crate::{Index, Term};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_idf() {
+        assert_nearly_equals!(BM25Similarity::default().idf(1, 2), 0.6931472);
+    }
+
+    #[test]
+    fn test_bm25_tf() {
+        let similarity = BM25Similarity::default();
+        // With an average length field, the tf component is `(k1 + 1) * tf / (tf + k1)`.
+        assert_nearly_equals!(similarity.tf(1, 4, 4f32), 1f32);
+        assert_nearly_equals!(similarity.tf(2, 4, 4f32), 2.2f32 * 2f32 / 3.2f32);
+        assert!(similarity.tf(1, 8, 4f32) < similarity.tf(1, 4, 4f32));
+    }
+
+    #[test]
+    fn test_bm25_norm_table() {
+        let similarity = BM25Similarity::default();
+        let similarity_weight =
+            SimilarityWeight::new(Arc::new(similarity), Explanation::new("idf", 1.3f32), 7f32)
+                .boost_by(2f32);
+        for fieldnorm_id in 0..=255u8 {
+            let fieldnorm = FieldNormReader::id_to_fieldnorm(fieldnorm_id);
+            for term_freq in 1..10 {
+                assert_eq!(
+                    similarity_weight.score(fieldnorm_id, term_freq),
+                    similarity.score(1.3f32 * 2f32, term_freq, fieldnorm, 7f32)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tfidf() {
+        let similarity = TfIdfSimilarity;
+        assert_nearly_equals!(similarity.idf(9, 10), 1f32);
+        assert_nearly_equals!(similarity.tf(4, 4, 1f32), 1f32);
+        assert_nearly_equals!(similarity.tf(4, 0, 1f32), 2f32);
+    }
+
+    #[test]
+    fn test_field_similarity() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field("title", TEXT);
+        let body = schema_builder.add_text_field("body", TEXT);
+        let schema = schema_builder.build();
+        let mut index = Index::create_in_ram(schema);
+        {
+            let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+            index_writer.add_document(doc!(title=>"a b b b", body=>"a b b b"));
+            index_writer.add_document(doc!(title=>"c", body=>"c"));
+            index_writer.add_document(doc!(title=>"c", body=>"c"));
+            index_writer.commit()?;
+        }
+        index.set_field_similarity(body, TfIdfSimilarity);
+        let searcher = index.reader()?.searcher();
+        let score = |field| -> crate::Result<f32> {
+            let query = TermQuery::new(
+                Term::from_field_text(field, "b"),
+                IndexRecordOption::WithFreqs,
+            );
+            let top_docs = searcher.search(&query, &TopDocs::with_limit(1))?;
+            Ok(top_docs[0].0)
+        };
+        let bm25 = BM25Similarity::default();
+        assert_nearly_equals!(score(title)?, bm25.idf(1, 3) * bm25.tf(3, 4, 2f32));
+        let tfidf = TfIdfSimilarity;
+        assert_nearly_equals!(score(body)?, tfidf.idf(1, 3) * tfidf.tf(3, 4, 2f32));
+        Ok(())
+    }
+}
@@ -10,9 +10,10 @@ pub use self::term_weight::TermWeight;
 mod tests {
 
     use crate::assert_nearly_equals;
-    use crate::collector::TopDocs;
+    use crate::collector::{Count, TopDocs};
     use crate::docset::DocSet;
     use crate::postings::compression::COMPRESSION_BLOCK_SIZE;
+    use crate::query::term_query::TermScorer;
     use crate::query::{Query, QueryParser, Scorer, TermQuery};
     use crate::schema::{Field, IndexRecordOption, Schema, STRING, TEXT};
     use crate::Term;
@@ -148,6 +149,57 @@ mod tests {
         assert_eq!(term_query.count(&*reader.searcher()).unwrap(), 1);
     }
 
+    #[test]
+    fn test_term_query_dense_terms_intersection() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text_field = schema_builder.add_text_field("text", TEXT);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        for doc in 0..1_000 {
+            let mut tokens = vec!["z"];
+            if doc % 2 == 0 {
+                tokens.push("a");
+            }
+            if doc % 3 == 0 {
+                tokens.push("b");
+            }
+            if doc % 100 == 0 {
+                tokens.push("c");
+            }
+            index_writer.add_document(doc!(text_field => tokens.join(" ")));
+        }
+        index_writer.delete_term(Term::from_field_text(text_field, "c"));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let query_parser = QueryParser::for_index(&index, vec![text_field]);
+        let count = |query: &str| {
+            let query = query_parser.parse_query(query).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        // "a", "b" and "z" are dense, with similar doc freqs, and are intersected as bitsets.
+        assert_eq!(count("+a +b"), 167 - 4);
+        assert_eq!(count("+a +b +z"), 167 - 4);
+        assert_eq!(count("+a +b -z"), 0);
+        // "c" only matches deleted documents.
+        assert_eq!(count("+a +b +c"), 0);
+        // With scoring, the terms are not turned into bitsets.
+        let query = query_parser.parse_query("+a +b")?;
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(1_000))?;
+        assert_eq!(top_docs.len(), 167 - 4);
+        // A single dense term is not loaded in a bitset either.
+        let term_query = TermQuery::new(
+            Term::from_field_text(text_field, "a"),
+            IndexRecordOption::Basic,
+        );
+        let term_scorer = term_query
+            .weight(&searcher, false)?
+            .scorer(searcher.segment_reader(0), 1.0f32)?;
+        assert!(term_scorer.is::<TermScorer>());
+        assert_eq!(count("a"), 500 - 10);
+        Ok(())
+    }
+
     #[test]
     fn test_term_query_simple_seek() -> crate::Result<()> {
         let mut schema_builder = Schema::builder();
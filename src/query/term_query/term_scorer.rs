@@ -6,19 +6,19 @@ use crate::Score;
 use crate::fieldnorm::FieldNormReader;
 use crate::postings::Postings;
 use crate::postings::SegmentPostings;
-use crate::query::bm25::BM25Weight;
+use crate::query::similarity::SimilarityWeight;
 
 pub struct TermScorer {
     postings: SegmentPostings,
     fieldnorm_reader: FieldNormReader,
-    similarity_weight: BM25Weight,
+    similarity_weight: SimilarityWeight,
 }
 
 impl TermScorer {
     pub fn new(
         postings: SegmentPostings,
         fieldnorm_reader: FieldNormReader,
-        similarity_weight: BM25Weight,
+        similarity_weight: SimilarityWeight,
     ) -> TermScorer {
         TermScorer {
             postings,
@@ -37,6 +37,35 @@ impl TermScorer {
         self.fieldnorm_reader.fieldnorm_id(self.doc())
     }
 
+    /// Returns an upper bound of the score of the term in any document.
+    pub(crate) fn max_score(&self) -> Score {
+        if self.postings.reads_freqs() {
+            self.similarity_weight.max_score(std::u32::MAX)
+        } else {
+            self.similarity_weight.max_score(1u32)
+        }
+    }
+
+    /// Positions the skip reader on the block that may contain `target`,
+    /// without decoding it. See `SegmentPostings::shallow_seek`.
+    pub(crate) fn shallow_seek(&mut self, target: DocId) {
+        self.postings.shallow_seek(target);
+    }
+
+    /// Returns the last doc of the block the skip reader is positioned on.
+    pub(crate) fn last_doc_in_block(&self) -> DocId {
+        self.postings.last_doc_in_block()
+    }
+
+    /// Returns an upper bound of the score of the term in the documents
+    /// of the block the skip reader is positioned on.
+    pub(crate) fn block_max_score(&self) -> Score {
+        match self.postings.block_max_term_freq() {
+            Some(block_max_tf) => self.similarity_weight.max_score(block_max_tf),
+            None => self.max_score(),
+        }
+    }
+
     pub fn explain(&self) -> Explanation {
         let fieldnorm_id = self.fieldnorm_id();
         let term_freq = self.term_freq();
@@ -2,9 +2,9 @@ use super::term_scorer::TermScorer;
 use crate::core::SegmentReader;
 use crate::docset::DocSet;
 use crate::postings::SegmentPostings;
-use crate::query::bm25::BM25Weight;
 use crate::query::explanation::does_not_match;
-use crate::query::weight::{for_each_pruning_scorer, for_each_scorer};
+use crate::query::similarity::SimilarityWeight;
+use crate::query::weight::{for_each_pruning_scorer, for_each_scorer, for_each_until_scorer};
 use crate::query::Weight;
 use crate::query::{Explanation, Scorer};
 use crate::schema::IndexRecordOption;
@@ -15,7 +15,7 @@ use crate::{DocId, Score};
 pub struct TermWeight {
     term: Term,
     index_record_option: IndexRecordOption,
-    similarity_weight: BM25Weight,
+    similarity_weight: SimilarityWeight,
 }
 
 impl Weight for TermWeight {
@@ -34,7 +34,9 @@ impl Weight for TermWeight {
 
     fn count(&self, reader: &SegmentReader) -> Result<u32> {
         if let Some(delete_bitset) = reader.delete_bitset() {
-            Ok(self.scorer(reader, 1.0f32)?.count(delete_bitset))
+            Ok(self
+                .specialized_scorer(reader, 1.0f32)?
+                .count(delete_bitset))
         } else {
             let field = self.term.field();
             Ok(reader
@@ -57,6 +59,16 @@ impl Weight for TermWeight {
         Ok(())
     }
 
+    fn for_each_until(
+        &self,
+        reader: &SegmentReader,
+        callback: &mut dyn FnMut(DocId, Score) -> bool,
+    ) -> crate::Result<()> {
+        let mut scorer = self.specialized_scorer(reader, 1.0f32)?;
+        for_each_until_scorer(&mut scorer, callback);
+        Ok(())
+    }
+
     /// Calls `callback` with all of the `(doc, score)` for which score
     /// is exceeding a given threshold.
     ///
@@ -83,7 +95,7 @@ impl TermWeight {
     pub fn new(
         term: Term,
         index_record_option: IndexRecordOption,
-        similarity_weight: BM25Weight,
+        similarity_weight: SimilarityWeight,
     ) -> TermWeight {
         TermWeight {
             term,
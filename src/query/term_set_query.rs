@@ -0,0 +1,252 @@
+use crate::query::explanation::does_not_match;
+use crate::query::score_combiner::DoNothingCombiner;
+use crate::query::{AutomatonWeight, ConstScorer, Explanation, Query, Scorer, Union, Weight};
+use crate::schema::{Field, Term};
+use crate::{DocId, DocSet, Searcher, SegmentReader};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use tantivy_fst::Automaton;
+
+/// Automaton matching exactly the terms of a sorted set.
+#[derive(Debug, Clone)]
+pub(crate) struct TermSetAutomaton {
+    terms: Vec<Vec<u8>>,
+}
+
+impl TermSetAutomaton {
+    fn new(mut terms: Vec<Vec<u8>>) -> TermSetAutomaton {
+        terms.sort();
+        terms.dedup();
+        TermSetAutomaton { terms }
+    }
+}
+
+impl Automaton for TermSetAutomaton {
+    /// Range of the terms starting with the bytes read so far,
+    /// and the number of bytes read, or `None` if no term starts with them.
+    type State = Option<(usize, usize, usize)>;
+
+    fn start(&self) -> Self::State {
+        if self.terms.is_empty() {
+            None
+        } else {
+            Some((0, self.terms.len(), 0))
+        }
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        match *state {
+            Some((start, _, len)) => self.terms[start].len() == len,
+            None => false,
+        }
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        state.is_some()
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        let (start, end, len) = (*state)?;
+        // The terms of the range share their first `len` bytes, so that
+        // they are sorted by their byte at `len`, the shortest one first.
+        let terms = &self.terms[start..end];
+        let from = terms.partition_point(|term| term.len() <= len || term[len] < byte);
+        let to = terms.partition_point(|term| term.len() <= len || term[len] <= byte);
+        if from == to {
+            return None;
+        }
+        Some((start + from, start + to, len + 1))
+    }
+}
+
+/// A Term Set Query matches all of the documents containing
+/// any of the terms of a set.
+///
+/// It is meant for large sets of terms, like a list of allowed ids:
+/// the sorted terms are intersected with the term dictionary of each segment
+/// in a single pass, and their documents are collected into a bitset,
+/// instead of running a `BooleanQuery` with one clause per term.
+///
+/// The terms may belong to different fields.
+/// The matched documents all get a constant score of one.
+///
+/// ```rust
+/// use tantivy::collector::Count;
+/// use tantivy::query::TermSetQuery;
+/// use tantivy::schema::{Schema, INDEXED};
+/// use tantivy::{doc, Index, Term};
+///
+/// # fn test() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let user_id = schema_builder.add_u64_field("user_id", INDEXED);
+/// let index = Index::create_in_ram(schema_builder.build());
+/// let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+/// for id in 0u64..1_000u64 {
+///     index_writer.add_document(doc!(user_id => id));
+/// }
+/// index_writer.commit()?;
+///
+/// let searcher = index.reader()?.searcher();
+/// let allowed_ids = (0u64..2_000u64)
+///     .step_by(4)
+///     .map(|id| Term::from_field_u64(user_id, id));
+/// let query = TermSetQuery::new(allowed_ids);
+/// assert_eq!(searcher.search(&query, &Count)?, 250);
+/// Ok(())
+/// # }
+/// # assert!(test().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TermSetQuery {
+    terms_map: BTreeMap<Field, Arc<TermSetAutomaton>>,
+}
+
+impl TermSetQuery {
+    /// Creates a new TermSetQuery matching the documents
+    /// containing any of the given terms.
+    pub fn new<T: IntoIterator<Item = Term>>(terms: T) -> TermSetQuery {
+        let mut terms_per_field: BTreeMap<Field, Vec<Vec<u8>>> = BTreeMap::new();
+        for term in terms {
+            terms_per_field
+                .entry(term.field())
+                .or_insert_with(Vec::new)
+                .push(term.value_bytes().to_vec());
+        }
+        let terms_map = terms_per_field
+            .into_iter()
+            .map(|(field, terms)| (field, Arc::new(TermSetAutomaton::new(terms))))
+            .collect();
+        TermSetQuery { terms_map }
+    }
+}
+
+impl Query for TermSetQuery {
+    fn weight(
+        &self,
+        _searcher: &Searcher,
+        _scoring_enabled: bool,
+    ) -> crate::Result<Box<dyn Weight>> {
+        let mut weights: Vec<AutomatonWeight<TermSetAutomaton>> = self
+            .terms_map
+            .iter()
+            .map(|(&field, automaton)| AutomatonWeight::new(field, automaton.clone()))
+            .collect();
+        if weights.len() == 1 {
+            return Ok(Box::new(weights.pop().unwrap()));
+        }
+        Ok(Box::new(TermSetWeight { weights }))
+    }
+
+    fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
+        for (&field, automaton) in &self.terms_map {
+            for term_bytes in &automaton.terms {
+                term_set.insert(Term::from_field_bytes(field, term_bytes));
+            }
+        }
+    }
+}
+
+/// Weight of a `TermSetQuery` over several fields.
+struct TermSetWeight {
+    weights: Vec<AutomatonWeight<TermSetAutomaton>>,
+}
+
+impl Weight for TermSetWeight {
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>> {
+        let scorers = self
+            .weights
+            .iter()
+            .map(|weight| weight.scorer(reader, boost))
+            .collect::<crate::Result<Vec<_>>>()?;
+        let union = Union::<_, DoNothingCombiner>::from(scorers);
+        Ok(Box::new(ConstScorer::new(union, boost)))
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> crate::Result<Explanation> {
+        let mut scorer = self.scorer(reader, 1.0f32)?;
+        if scorer.seek(doc) != doc {
+            return Err(does_not_match(doc));
+        }
+        Ok(Explanation::new("TermSetQuery", 1.0f32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TermSetAutomaton, TermSetQuery};
+    use crate::collector::{Count, TopDocs};
+    use crate::query::Query;
+    use crate::schema::{Schema, STRING};
+    use crate::{DocAddress, Index, Term};
+    use std::collections::BTreeSet;
+    use tantivy_fst::Automaton;
+
+    fn is_match(automaton: &TermSetAutomaton, text: &str) -> bool {
+        let state = text.bytes().fold(automaton.start(), |state, byte| {
+            automaton.accept(&state, byte)
+        });
+        automaton.is_match(&state)
+    }
+
+    #[test]
+    fn test_term_set_automaton() {
+        let terms = vec!["ab", "abc", "b", "abd", "ab", "ba"];
+        let automaton =
+            TermSetAutomaton::new(terms.iter().map(|term| term.as_bytes().to_vec()).collect());
+        for term in &terms {
+            assert!(is_match(&automaton, term));
+        }
+        for term in &["", "a", "abcd", "abe", "bb", "c"] {
+            assert!(!is_match(&automaton, term));
+        }
+        let empty_automaton = TermSetAutomaton::new(vec![]);
+        assert!(!is_match(&empty_automaton, ""));
+        assert!(!empty_automaton.can_match(&empty_automaton.start()));
+    }
+
+    #[test]
+    fn test_term_set_query() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let id = schema_builder.add_text_field("id", STRING);
+        let tag = schema_builder.add_text_field("tag", STRING);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        for i in 0..100 {
+            index_writer.add_document(doc!(
+                id => format!("id{}", i),
+                tag => if i % 10 == 0 { "even_ten" } else { "other" },
+            ));
+        }
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+
+        let id_terms = |ids: &[u32]| -> Vec<Term> {
+            ids.iter()
+                .map(|i| Term::from_field_text(id, &format!("id{}", i)))
+                .collect()
+        };
+        let query = TermSetQuery::new(id_terms(&[3, 1, 200, 42, 3]));
+        assert_eq!(searcher.search(&query, &Count)?, 3);
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(10))?;
+        let docs: BTreeSet<DocAddress> = top_docs.into_iter().map(|(_, doc)| doc).collect();
+        assert_eq!(
+            docs,
+            vec![DocAddress(0, 1), DocAddress(0, 3), DocAddress(0, 42)]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(searcher.search(&TermSetQuery::new(vec![]), &Count)?, 0);
+
+        // Terms over several fields
+        let mut terms = id_terms(&[1, 10]);
+        terms.push(Term::from_field_text(tag, "even_ten"));
+        let query = TermSetQuery::new(terms);
+        assert_eq!(searcher.search(&query, &Count)?, 11);
+        assert!(query.explain(&searcher, DocAddress(0, 20)).is_ok());
+        assert!(query.explain(&searcher, DocAddress(0, 21)).is_err());
+        let mut query_terms = BTreeSet::new();
+        query.query_terms(&mut query_terms);
+        assert_eq!(query_terms.len(), 3);
+        Ok(())
+    }
+}
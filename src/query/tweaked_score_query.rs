@@ -0,0 +1,240 @@
+use crate::collector::{ScoreSegmentTweaker, ScoreTweaker};
+use crate::query::explanation::does_not_match;
+use crate::query::{Explanation, Query, Scorer, Weight};
+use crate::{DocId, DocSet, Score, Searcher, SegmentReader, Term};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// `TweakedScoreQuery` is a wrapper over a query, modifying the score of the
+/// documents it matches with a [`ScoreTweaker`](../collector/trait.ScoreTweaker.html).
+///
+/// The document set matched by the `TweakedScoreQuery` is strictly the same as
+/// the underlying query. The score tweaker is given each segment reader,
+/// so that it can read the fast fields of the segment, and returns a function computing
+/// the new score of a document given its original score.
+///
+/// Unlike `TopDocs::tweak_score`, the tweaked score is the score of the query itself:
+/// the query can be part of a `BooleanQuery`, and used with any collector.
+///
+/// ```rust
+/// use tantivy::collector::TopDocs;
+/// use tantivy::query::{QueryParser, TweakedScoreQuery};
+/// use tantivy::schema::{Schema, FAST, TEXT};
+/// use tantivy::{doc, DocAddress, DocId, Index, Score, SegmentReader};
+///
+/// # fn test() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let popularity = schema_builder.add_u64_field("popularity", FAST);
+/// let index = Index::create_in_ram(schema_builder.build());
+/// let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+/// index_writer.add_document(doc!(title => "The Diary of Muadib", popularity => 1u64));
+/// index_writer.add_document(doc!(title => "The Diary of a Young Girl", popularity => 15u64));
+/// index_writer.commit()?;
+///
+/// let query_parser = QueryParser::for_index(&index, vec![title]);
+/// let query = TweakedScoreQuery::new(
+///     query_parser.parse_query("diary")?,
+///     move |segment_reader: &SegmentReader| {
+///         let popularity_reader = segment_reader.fast_fields().u64(popularity).unwrap();
+///         move |doc: DocId, original_score: Score| {
+///             let popularity = popularity_reader.get(doc);
+///             original_score * ((2u64 + popularity) as Score).log2()
+///         }
+///     },
+/// );
+/// let searcher = index.reader()?.searcher();
+/// let top_docs = searcher.search(&query, &TopDocs::with_limit(2))?;
+/// assert_eq!(top_docs[0].1, DocAddress(0, 1));
+/// # Ok(())
+/// # }
+/// # assert!(test().is_ok());
+/// ```
+pub struct TweakedScoreQuery<TScoreTweaker> {
+    query: Box<dyn Query>,
+    score_tweaker: Arc<TScoreTweaker>,
+}
+
+impl<TScoreTweaker> TweakedScoreQuery<TScoreTweaker>
+where
+    TScoreTweaker: ScoreTweaker<Score> + Send + Sync + 'static,
+{
+    /// Builds a tweaked score query.
+    pub fn new(query: Box<dyn Query>, score_tweaker: TScoreTweaker) -> Self {
+        TweakedScoreQuery {
+            query,
+            score_tweaker: Arc::new(score_tweaker),
+        }
+    }
+}
+
+impl<TScoreTweaker> Clone for TweakedScoreQuery<TScoreTweaker> {
+    fn clone(&self) -> Self {
+        TweakedScoreQuery {
+            query: self.query.box_clone(),
+            score_tweaker: self.score_tweaker.clone(),
+        }
+    }
+}
+
+impl<TScoreTweaker> fmt::Debug for TweakedScoreQuery<TScoreTweaker> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TweakedScore(query={:?})", self.query)
+    }
+}
+
+impl<TScoreTweaker> Query for TweakedScoreQuery<TScoreTweaker>
+where
+    TScoreTweaker: ScoreTweaker<Score> + Send + Sync + 'static,
+{
+    fn weight(&self, searcher: &Searcher, scoring_enabled: bool) -> crate::Result<Box<dyn Weight>> {
+        let weight = self.query.weight(searcher, scoring_enabled)?;
+        if !scoring_enabled {
+            return Ok(weight);
+        }
+        Ok(Box::new(TweakedScoreWeight {
+            weight,
+            score_tweaker: self.score_tweaker.clone(),
+        }))
+    }
+
+    fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
+        self.query.query_terms(term_set)
+    }
+}
+
+struct TweakedScoreWeight<TScoreTweaker> {
+    weight: Box<dyn Weight>,
+    score_tweaker: Arc<TScoreTweaker>,
+}
+
+impl<TScoreTweaker> Weight for TweakedScoreWeight<TScoreTweaker>
+where
+    TScoreTweaker: ScoreTweaker<Score> + Send + Sync + 'static,
+{
+    fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>> {
+        Ok(Box::new(TweakedScorer {
+            scorer: self.weight.scorer(reader, boost)?,
+            segment_tweaker: self.score_tweaker.segment_tweaker(reader)?,
+        }))
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> crate::Result<Explanation> {
+        let mut scorer = self.scorer(reader, 1.0f32)?;
+        if scorer.seek(doc) != doc {
+            return Err(does_not_match(doc));
+        }
+        let mut explanation = Explanation::new("TweakedScoreQuery, tweak of ...", scorer.score());
+        explanation.add_detail(self.weight.explain(reader, doc)?);
+        Ok(explanation)
+    }
+
+    fn count(&self, reader: &SegmentReader) -> crate::Result<u32> {
+        self.weight.count(reader)
+    }
+}
+
+struct TweakedScorer<TSegmentScoreTweaker> {
+    scorer: Box<dyn Scorer>,
+    segment_tweaker: TSegmentScoreTweaker,
+}
+
+impl<TSegmentScoreTweaker: ScoreSegmentTweaker<Score>> DocSet
+    for TweakedScorer<TSegmentScoreTweaker>
+{
+    fn advance(&mut self) -> DocId {
+        self.scorer.advance()
+    }
+
+    fn seek(&mut self, target: DocId) -> DocId {
+        self.scorer.seek(target)
+    }
+
+    fn fill_buffer(&mut self, buffer: &mut [DocId]) -> usize {
+        self.scorer.fill_buffer(buffer)
+    }
+
+    fn doc(&self) -> DocId {
+        self.scorer.doc()
+    }
+
+    fn size_hint(&self) -> u32 {
+        self.scorer.size_hint()
+    }
+}
+
+impl<TSegmentScoreTweaker: ScoreSegmentTweaker<Score>> Scorer
+    for TweakedScorer<TSegmentScoreTweaker>
+{
+    fn score(&mut self) -> Score {
+        let doc = self.scorer.doc();
+        let score = self.scorer.score();
+        self.segment_tweaker.score(doc, score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TweakedScoreQuery;
+    use crate::collector::{Count, TopDocs};
+    use crate::query::{AllQuery, BooleanQuery, Occur, Query, TermQuery};
+    use crate::schema::{IndexRecordOption, Schema, FAST, TEXT};
+    use crate::{DocAddress, DocId, Index, Score, SegmentReader, Term};
+
+    #[test]
+    fn test_tweaked_score_query() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let popularity = schema_builder.add_u64_field("popularity", FAST);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(text => "a", popularity => 3u64));
+        index_writer.add_document(doc!(text => "a b", popularity => 1u64));
+        index_writer.add_document(doc!(text => "b", popularity => 2u64));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+
+        let by_popularity = move |segment_reader: &SegmentReader| {
+            let popularity_reader = segment_reader.fast_fields().u64(popularity).unwrap();
+            move |doc: DocId, _original_score: Score| popularity_reader.get(doc) as Score
+        };
+        let query = TweakedScoreQuery::new(Box::new(AllQuery), by_popularity);
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(3))?;
+        assert_eq!(
+            top_docs,
+            vec![
+                (3.0, DocAddress(0, 0)),
+                (2.0, DocAddress(0, 2)),
+                (1.0, DocAddress(0, 1))
+            ]
+        );
+        assert_eq!(searcher.search(&query, &Count)?, 3);
+        let explanation = query.explain(&searcher, DocAddress(0, 2))?;
+        assert_eq!(explanation.value(), 2.0);
+
+        // The tweaked query is scored as a clause of a boolean query.
+        let boolean_query = BooleanQuery::from(vec![
+            (
+                Occur::Must,
+                Box::new(TermQuery::new(
+                    Term::from_field_text(text, "a"),
+                    IndexRecordOption::Basic,
+                )) as Box<dyn Query>,
+            ),
+            (
+                Occur::Should,
+                Box::new(TweakedScoreQuery::new(
+                    Box::new(AllQuery),
+                    move |_: &SegmentReader| {
+                        move |doc: DocId, _: Score| if doc == 1 { 10.0 } else { 0.0 }
+                    },
+                )),
+            ),
+        ]);
+        let top_docs = searcher.search(&boolean_query, &TopDocs::with_limit(3))?;
+        assert_eq!(top_docs.len(), 2);
+        assert_eq!(top_docs[0].1, DocAddress(0, 1));
+        Ok(())
+    }
+}
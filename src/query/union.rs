@@ -253,8 +253,9 @@ mod tests {
     use super::HORIZON;
     use crate::docset::{DocSet, TERMINATED};
     use crate::postings::tests::test_skip_against_unoptimized;
-    use crate::query::score_combiner::DoNothingCombiner;
+    use crate::query::score_combiner::{CountCombiner, DoNothingCombiner};
     use crate::query::ConstScorer;
+    use crate::query::Scorer;
     use crate::query::VecDocSet;
     use crate::tests;
     use crate::DocId;
@@ -310,6 +311,24 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_union_count_combiner() {
+        let mut union = Union::<_, CountCombiner>::from(vec![
+            ConstScorer::from(VecDocSet::from(vec![1u32, 3u32, 5u32])),
+            ConstScorer::from(VecDocSet::from(vec![1u32, 5u32])),
+            ConstScorer::from(VecDocSet::from(vec![5u32, 7u32])),
+        ]);
+        let mut doc_counts = Vec::new();
+        while union.doc() != TERMINATED {
+            doc_counts.push((union.doc(), union.score()));
+            union.advance();
+        }
+        assert_eq!(
+            doc_counts,
+            vec![(1u32, 2f32), (3u32, 1f32), (5u32, 3f32), (7u32, 1f32)]
+        );
+    }
+
     fn test_aux_union_skip(docs_list: &[Vec<DocId>], skip_targets: Vec<DocId>) {
         let mut btree_set = BTreeSet::new();
         for docs in docs_list {
@@ -30,6 +30,31 @@ impl DocSet for VecDocSet {
         self.doc()
     }
 
+    /// Seeks by probing exponentially growing offsets from the cursor,
+    /// followed by a binary search within the last probed interval.
+    ///
+    /// Seeking to a target at a distance `d` in the list
+    /// is therefore `O(log d)` rather than `O(d)`.
+    fn seek(&mut self, target: DocId) -> DocId {
+        let len = self.doc_ids.len();
+        if self.cursor >= len {
+            return TERMINATED;
+        }
+        let mut begin = self.cursor;
+        let mut step = 1;
+        let mut end = begin + step;
+        while end < len && self.doc_ids[end] < target {
+            begin = end;
+            step *= 2;
+            end = begin + step;
+        }
+        let end = end.min(len);
+        self.cursor = match self.doc_ids[begin..end].binary_search(&target) {
+            Ok(offset) | Err(offset) => begin + offset,
+        };
+        self.doc()
+    }
+
     fn doc(&self) -> DocId {
         if self.cursor == self.doc_ids.len() {
             return TERMINATED;
@@ -69,6 +94,26 @@ pub mod tests {
         assert_eq!(postings.seek(6000u32), TERMINATED);
     }
 
+    #[test]
+    pub fn test_vec_postings_seek() {
+        let doc_ids: Vec<DocId> = (0u32..1024u32).map(|e| e * 3).collect();
+        for start in 0..20u32 {
+            for target in start * 3..3_100u32 {
+                let mut postings = VecDocSet::from(doc_ids.clone());
+                postings.seek(start * 3);
+                let expected = doc_ids
+                    .iter()
+                    .cloned()
+                    .find(|&doc| doc >= target)
+                    .unwrap_or(TERMINATED);
+                assert_eq!(postings.seek(target), expected);
+                assert_eq!(postings.doc(), expected);
+            }
+        }
+        let mut postings = VecDocSet::from(vec![]);
+        assert_eq!(postings.seek(3u32), TERMINATED);
+    }
+
     #[test]
     pub fn test_fill_buffer() {
         let doc_ids: Vec<DocId> = (1u32..210u32).collect();
@@ -16,6 +16,22 @@ pub(crate) fn for_each_scorer<TScorer: Scorer + ?Sized>(
     }
 }
 
+/// Iterates through all of the document matched by the DocSet
+/// `DocSet` and push the scored documents to the collector,
+/// until `callback` returns true.
+pub(crate) fn for_each_until_scorer<TScorer: Scorer + ?Sized>(
+    scorer: &mut TScorer,
+    callback: &mut dyn FnMut(DocId, Score) -> bool,
+) {
+    let mut doc = scorer.doc();
+    while doc != TERMINATED {
+        if callback(doc, scorer.score()) {
+            return;
+        }
+        doc = scorer.advance();
+    }
+}
+
 /// Calls `callback` with all of the `(doc, score)` for which score
 /// is exceeding a given threshold.
 ///
@@ -53,6 +69,24 @@ pub trait Weight: Send + Sync + 'static {
     /// See [`Query`](./trait.Query.html).
     fn scorer(&self, reader: &SegmentReader, boost: f32) -> crate::Result<Box<dyn Scorer>>;
 
+    /// Returns the scorer for the given segment, or `None` if the search
+    /// was interrupted before the scorer could be built.
+    ///
+    /// Weights doing significant work to build their scorer, like the expansion
+    /// of an automaton over the term dictionary, call `is_interrupted` along the way.
+    /// By default, it is only called before calling `scorer`.
+    fn interruptible_scorer(
+        &self,
+        reader: &SegmentReader,
+        boost: f32,
+        is_interrupted: &dyn Fn() -> bool,
+    ) -> crate::Result<Option<Box<dyn Scorer>>> {
+        if is_interrupted() {
+            return Ok(None);
+        }
+        self.scorer(reader, boost).map(Some)
+    }
+
     /// Returns an `Explanation` for the given document.
     fn explain(&self, reader: &SegmentReader, doc: DocId) -> crate::Result<Explanation>;
 
@@ -78,6 +112,22 @@ pub trait Weight: Send + Sync + 'static {
         Ok(())
     }
 
+    /// Iterates through the documents matched by the DocSet
+    /// `DocSet` and push the scored documents to the collector,
+    /// until `callback` returns true.
+    ///
+    /// This makes it possible for collectors to stop the search
+    /// as soon as they do not need any more documents.
+    fn for_each_until(
+        &self,
+        reader: &SegmentReader,
+        callback: &mut dyn FnMut(DocId, Score) -> bool,
+    ) -> crate::Result<()> {
+        let mut scorer = self.scorer(reader, 1.0f32)?;
+        for_each_until_scorer(scorer.as_mut(), callback);
+        Ok(())
+    }
+
     /// Calls `callback` with all of the `(doc, score)` for which score
     /// is exceeding a given threshold.
     ///
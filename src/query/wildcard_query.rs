@@ -0,0 +1,167 @@
+use crate::error::TantivyError;
+use crate::query::{AutomatonWeight, Query, Weight};
+use crate::schema::Field;
+use crate::Searcher;
+use std::sync::Arc;
+use tantivy_fst::Regex;
+
+/// Translates a wildcard pattern into the equivalent regex pattern.
+fn wildcard_to_regex(wildcard_pattern: &str) -> String {
+    let mut regex_pattern = String::with_capacity(wildcard_pattern.len() * 2);
+    for c in wildcard_pattern.chars() {
+        match c {
+            '*' => regex_pattern.push_str(".*"),
+            '?' => regex_pattern.push('.'),
+            '\\' | '.' | '+' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$' | '#' | '&'
+            | '-' | '~' => {
+                regex_pattern.push('\\');
+                regex_pattern.push(c);
+            }
+            _ => regex_pattern.push(c),
+        }
+    }
+    regex_pattern
+}
+
+/// A Wildcard Query matches all of the documents
+/// containing a term that matches a wildcard pattern.
+///
+/// In the pattern, `*` matches any sequence of characters (including
+/// the empty sequence) and `?` matches exactly one character. All other
+/// characters match themselves.
+///
+/// The pattern is expanded against the term dictionary of each segment.
+/// The number of expanded terms can be capped using
+/// [`set_max_expansions`](#method.set_max_expansions).
+///
+/// ```rust
+/// use tantivy::collector::Count;
+/// use tantivy::query::WildcardQuery;
+/// use tantivy::schema::{Schema, TEXT};
+/// use tantivy::{doc, Index};
+///
+/// # fn test() -> tantivy::Result<()> {
+/// let mut schema_builder = Schema::builder();
+/// let title = schema_builder.add_text_field("title", TEXT);
+/// let schema = schema_builder.build();
+/// let index = Index::create_in_ram(schema);
+/// {
+///     let mut index_writer = index.writer(3_000_000)?;
+///     index_writer.add_document(doc!(
+///         title => "The Name of the Wind",
+///     ));
+///     index_writer.add_document(doc!(
+///         title => "The Diary of Muadib",
+///     ));
+///     index_writer.add_document(doc!(
+///         title => "A Dairy Cow",
+///     ));
+///     index_writer.add_document(doc!(
+///         title => "The Diary of a Young Girl",
+///     ));
+///     index_writer.commit().unwrap();
+/// }
+///
+/// let reader = index.reader()?;
+/// let searcher = reader.searcher();
+///
+/// let query = WildcardQuery::from_pattern("d??ry", title)?;
+/// let count = searcher.search(&query, &Count)?;
+/// assert_eq!(count, 3);
+/// Ok(())
+/// # }
+/// # assert!(test().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WildcardQuery {
+    regex: Arc<Regex>,
+    field: Field,
+    max_expansions: Option<usize>,
+}
+
+impl WildcardQuery {
+    /// Creates a new WildcardQuery from a given pattern
+    pub fn from_pattern(wildcard_pattern: &str, field: Field) -> crate::Result<Self> {
+        let regex = Regex::new(&wildcard_to_regex(wildcard_pattern))
+            .map_err(|_| TantivyError::InvalidArgument(wildcard_pattern.to_string()))?;
+        Ok(WildcardQuery {
+            regex: Arc::new(regex),
+            field,
+            max_expansions: None,
+        })
+    }
+
+    /// Caps the number of terms the pattern may expand to within a segment.
+    ///
+    /// Searching fails with an `InvalidArgument` error if the cap is exceeded.
+    pub fn set_max_expansions(&mut self, max_expansions: usize) {
+        self.max_expansions = Some(max_expansions);
+    }
+
+    fn specialized_weight(&self) -> AutomatonWeight<Regex> {
+        let mut weight = AutomatonWeight::new(self.field, self.regex.clone());
+        if let Some(max_expansions) = self.max_expansions {
+            weight.set_max_expansions(max_expansions);
+        }
+        weight
+    }
+}
+
+impl Query for WildcardQuery {
+    fn weight(
+        &self,
+        _searcher: &Searcher,
+        _scoring_enabled: bool,
+    ) -> crate::Result<Box<dyn Weight>> {
+        Ok(Box::new(self.specialized_weight()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{wildcard_to_regex, WildcardQuery};
+    use crate::collector::Count;
+    use crate::schema::{Schema, STRING};
+    use crate::Index;
+
+    #[test]
+    pub fn test_wildcard_to_regex() {
+        assert_eq!(wildcard_to_regex("ja*n"), "ja.*n");
+        assert_eq!(wildcard_to_regex("ja?an"), "ja.an");
+        assert_eq!(wildcard_to_regex("a.b[c]"), "a\\.b\\[c\\]");
+    }
+
+    #[test]
+    pub fn test_wildcard_query() {
+        let mut schema_builder = Schema::builder();
+        let field = schema_builder.add_text_field("name", STRING);
+        let index = Index::create_in_ram(schema_builder.build());
+        {
+            let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+            index_writer.add_document(doc!(field => "japan"));
+            index_writer.add_document(doc!(field => "jamaica"));
+            index_writer.add_document(doc!(field => "korea"));
+            index_writer.add_document(doc!(field => "j.a"));
+            index_writer.commit().unwrap();
+        }
+        let searcher = index.reader().unwrap().searcher();
+        let count = |pattern: &str| {
+            let query = WildcardQuery::from_pattern(pattern, field).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count("ja*"), 2);
+        assert_eq!(count("*a"), 3);
+        assert_eq!(count("j*a*"), 3);
+        assert_eq!(count("ja?an"), 1);
+        assert_eq!(count("j?a"), 1);
+        assert_eq!(count("j.a"), 1);
+        assert_eq!(count("korea?"), 0);
+        assert_eq!(count("*"), 4);
+
+        let mut query = WildcardQuery::from_pattern("*a", field).unwrap();
+        query.set_max_expansions(3);
+        assert_eq!(searcher.search(&query, &Count).unwrap(), 3);
+        query.set_max_expansions(2);
+        assert!(searcher.search(&query, &Count).is_err());
+    }
+}
@@ -49,7 +49,7 @@ impl<T> Queue<T> {
 /// released automatically back into the pool on `Drop`.
 pub struct Pool<T> {
     queue: Arc<Queue<GenerationItem<T>>>,
-    freshest_generation: AtomicUsize,
+    freshest_generation: Arc<AtomicUsize>,
     next_generation: AtomicUsize,
 }
 
@@ -58,7 +58,7 @@ impl<T> Pool<T> {
         let queue = Arc::new(Queue::new());
         Pool {
             queue,
-            freshest_generation: AtomicUsize::default(),
+            freshest_generation: Arc::default(),
             next_generation: AtomicUsize::default(),
         }
     }
@@ -133,6 +133,7 @@ impl<T> Pool<T> {
                 return LeasedItem {
                     gen_item: Some(gen_item),
                     recycle_queue: Arc::clone(&self.queue),
+                    freshest_generation: Arc::clone(&self.freshest_generation),
                 };
             } else {
                 // this searcher is obsolete,
@@ -145,10 +146,12 @@ impl<T> Pool<T> {
 /// A LeasedItem holds an object borrowed from a Pool.
 ///
 /// Upon drop, the object is automatically returned
-/// into the pool.
+/// into the pool, unless a newer generation has been
+/// published in the meantime, in which case it is dropped.
 pub struct LeasedItem<T> {
     gen_item: Option<GenerationItem<T>>,
     recycle_queue: Arc<Queue<GenerationItem<T>>>,
+    freshest_generation: Arc<AtomicUsize>,
 }
 
 impl<T> Deref for LeasedItem<T> {
@@ -176,7 +179,9 @@ impl<T> DerefMut for LeasedItem<T> {
 impl<T> Drop for LeasedItem<T> {
     fn drop(&mut self) {
         if let Some(gen_item) = self.gen_item.take() {
-            self.recycle_queue.push(gen_item);
+            if gen_item.generation >= self.freshest_generation.load(Ordering::Acquire) {
+                self.recycle_queue.push(gen_item);
+            }
         }
     }
 }
@@ -186,6 +191,7 @@ mod tests {
 
     use super::Pool;
     use super::Queue;
+    use std::sync::Arc;
     use std::{iter, mem};
 
     #[test]
@@ -203,6 +209,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pool_drops_obsolete_leased_item() {
+        let pool = Pool::new();
+        pool.publish_new_generation(vec![Arc::new(10)]);
+        let leased_item = pool.acquire();
+        let item = Arc::clone(&*leased_item);
+        pool.publish_new_generation(vec![Arc::new(11)]);
+        assert_eq!(Arc::strong_count(&item), 2);
+        mem::drop(leased_item);
+        assert_eq!(Arc::strong_count(&item), 1);
+        assert_eq!(**pool.acquire(), 11);
+    }
+
     #[test]
     fn test_queue() {
         let q = Queue::new();
@@ -215,7 +234,6 @@ mod tests {
     #[test]
     fn test_pool_dont_panic_on_empty_pop() {
         // When the object pool is exhausted, it shouldn't panic on pop()
-        use std::sync::Arc;
         use std::thread;
 
         // Wrap the pool in an Arc, same way as its used in `core/index.rs`
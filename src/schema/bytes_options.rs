@@ -0,0 +1,116 @@
+use crate::schema::flags::{FastFlag, SchemaFlagList, StoredFlag};
+use serde::{Deserialize, Serialize};
+use std::ops::BitOr;
+
+/// Define how a bytes field should be handled by tantivy.
+///
+/// Bytes fields are never indexed. Their values can be stored,
+/// and/or be available as a fast field.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BytesOptions {
+    fast: bool,
+    stored: bool,
+}
+
+impl BytesOptions {
+    /// Returns true iff the value is stored.
+    pub fn is_stored(&self) -> bool {
+        self.stored
+    }
+
+    /// Returns true iff the value is a fast field.
+    pub fn is_fast(&self) -> bool {
+        self.fast
+    }
+
+    /// Set the bytes options as stored.
+    ///
+    /// Only the fields that are set as *stored* are
+    /// persisted into the Tantivy's store.
+    pub fn set_stored(mut self) -> BytesOptions {
+        self.stored = true;
+        self
+    }
+
+    /// Set the bytes options as a fast field.
+    ///
+    /// Fast fields are designed for random access.
+    /// If more than one value is associated to a fast field, only the last one is
+    /// kept.
+    pub fn set_fast(mut self) -> BytesOptions {
+        self.fast = true;
+        self
+    }
+}
+
+impl Default for BytesOptions {
+    fn default() -> BytesOptions {
+        BytesOptions {
+            fast: false,
+            stored: false,
+        }
+    }
+}
+
+impl From<()> for BytesOptions {
+    fn from(_: ()) -> BytesOptions {
+        BytesOptions::default()
+    }
+}
+
+impl From<FastFlag> for BytesOptions {
+    fn from(_: FastFlag) -> Self {
+        BytesOptions {
+            fast: true,
+            stored: false,
+        }
+    }
+}
+
+impl From<StoredFlag> for BytesOptions {
+    fn from(_: StoredFlag) -> Self {
+        BytesOptions {
+            fast: false,
+            stored: true,
+        }
+    }
+}
+
+impl<T: Into<BytesOptions>> BitOr<T> for BytesOptions {
+    type Output = BytesOptions;
+
+    fn bitor(self, other: T) -> BytesOptions {
+        let other = other.into();
+        BytesOptions {
+            fast: self.fast | other.fast,
+            stored: self.stored | other.stored,
+        }
+    }
+}
+
+impl<Head, Tail> From<SchemaFlagList<Head, Tail>> for BytesOptions
+where
+    Head: Clone,
+    Tail: Clone,
+    Self: BitOr<Output = Self> + From<Head> + From<Tail>,
+{
+    fn from(head_tail: SchemaFlagList<Head, Tail>) -> Self {
+        Self::from(head_tail.head) | Self::from(head_tail.tail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{BytesOptions, FAST, STORED};
+
+    #[test]
+    fn test_bytes_options() {
+        let options: BytesOptions = (FAST | STORED).into();
+        assert!(options.is_fast());
+        assert!(options.is_stored());
+        let options: BytesOptions = STORED.into();
+        assert!(!options.is_fast());
+        assert!(options.is_stored());
+        assert_eq!(BytesOptions::default().set_fast(), FAST.into());
+    }
+}
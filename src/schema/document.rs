@@ -5,6 +5,7 @@ use crate::tokenizer::PreTokenizedString;
 use crate::DateTime;
 use std::io::{self, Read, Write};
 use std::mem;
+use std::net::IpAddr;
 
 /// Tantivy's Document is the object that can
 /// be indexed and then searched for.
@@ -104,6 +105,25 @@ impl Document {
         self.add(FieldValue::new(field, Value::F64(value)));
     }
 
+    /// Add a bool field
+    pub fn add_bool(&mut self, field: Field, value: bool) {
+        self.add(FieldValue::new(field, Value::Bool(value)));
+    }
+
+    /// Add an ip address field
+    pub fn add_ip_addr(&mut self, field: Field, value: IpAddr) {
+        self.add(FieldValue::new(field, Value::from(value)));
+    }
+
+    /// Add a json object field
+    pub fn add_json_object(
+        &mut self,
+        field: Field,
+        json_object: serde_json::Map<String, serde_json::Value>,
+    ) {
+        self.add(FieldValue::new(field, Value::JsonObject(json_object)));
+    }
+
     /// Add a date field
     pub fn add_date(&mut self, field: Field, value: &DateTime) {
         self.add(FieldValue::new(field, Value::Date(*value)));
@@ -192,6 +212,21 @@ impl Document {
             }
         }
     }
+
+    /// Truncates the date values to the precision of their field.
+    pub(crate) fn truncate_dates(&mut self, schema: &Schema) {
+        for field_value in &mut self.field_values {
+            if let Value::Date(ref date) = field_value.value() {
+                if let FieldType::Date(ref options) =
+                    *schema.get_field_entry(field_value.field()).field_type()
+                {
+                    let truncated_date = options.get_precision().truncate(date);
+                    *field_value =
+                        FieldValue::new(field_value.field(), Value::Date(truncated_date));
+                }
+            }
+        }
+    }
 }
 
 impl BinarySerializable for Document {
@@ -213,6 +248,26 @@ impl BinarySerializable for Document {
     }
 }
 
+impl Document {
+    /// Deserializes a document, only retaining the values of the given fields.
+    ///
+    /// The values of the other fields are skipped without being decoded.
+    pub(crate) fn deserialize_fields(cursor: &mut &[u8], fields: &[Field]) -> io::Result<Self> {
+        let num_field_values = VInt::deserialize(cursor)?.val() as usize;
+        let mut field_values = Vec::new();
+        for _ in 0..num_field_values {
+            let field = Field::deserialize(cursor)?;
+            if fields.contains(&field) {
+                let value = Value::deserialize(cursor)?;
+                field_values.push(FieldValue::new(field, value));
+            } else {
+                Value::skip_serialized(cursor)?;
+            }
+        }
+        Ok(Document::from(field_values))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1,4 +1,8 @@
+use crate::schema::BytesOptions;
 use crate::schema::IntOptions;
+use crate::schema::IpAddrOptions;
+use crate::schema::JsonObjectOptions;
+use crate::schema::TextFieldIndexing;
 use crate::schema::TextOptions;
 
 use crate::schema::FieldType;
@@ -14,10 +18,12 @@ use std::fmt;
 /// - a field name
 /// - a field type, itself wrapping up options describing
 /// how the field should be indexed.
+/// - whether the field is the unique key of the documents.
 #[derive(Clone, Debug, PartialEq)]
 pub struct FieldEntry {
     name: String,
     field_type: FieldType,
+    unique: bool,
 }
 
 impl FieldEntry {
@@ -27,6 +33,7 @@ impl FieldEntry {
         FieldEntry {
             name: field_name,
             field_type: FieldType::Str(text_options),
+            unique: false,
         }
     }
 
@@ -36,6 +43,7 @@ impl FieldEntry {
         FieldEntry {
             name: field_name,
             field_type: FieldType::U64(field_type),
+            unique: false,
         }
     }
 
@@ -45,6 +53,7 @@ impl FieldEntry {
         FieldEntry {
             name: field_name,
             field_type: FieldType::I64(field_type),
+            unique: false,
         }
     }
 
@@ -54,6 +63,7 @@ impl FieldEntry {
         FieldEntry {
             name: field_name,
             field_type: FieldType::F64(field_type),
+            unique: false,
         }
     }
 
@@ -63,6 +73,40 @@ impl FieldEntry {
         FieldEntry {
             name: field_name,
             field_type: FieldType::Date(field_type),
+            unique: false,
+        }
+    }
+
+    /// Creates a new bool field entry in the schema, given
+    /// a name, and some options.
+    pub fn new_bool(field_name: String, field_type: IntOptions) -> FieldEntry {
+        FieldEntry {
+            name: field_name,
+            field_type: FieldType::Bool(field_type),
+            unique: false,
+        }
+    }
+
+    /// Creates a new ip address field entry in the schema, given
+    /// a name, and some options.
+    pub fn new_ip_addr(field_name: String, ip_addr_options: IpAddrOptions) -> FieldEntry {
+        FieldEntry {
+            name: field_name,
+            field_type: FieldType::IpAddr(ip_addr_options),
+            unique: false,
+        }
+    }
+
+    /// Creates a new json object field entry in the schema, given
+    /// a name, and some options.
+    pub fn new_json_object(
+        field_name: String,
+        json_object_options: JsonObjectOptions,
+    ) -> FieldEntry {
+        FieldEntry {
+            name: field_name,
+            field_type: FieldType::JsonObject(json_object_options),
+            unique: false,
         }
     }
 
@@ -71,14 +115,16 @@ impl FieldEntry {
         FieldEntry {
             name: field_name,
             field_type: FieldType::HierarchicalFacet,
+            unique: false,
         }
     }
 
     /// Creates a field entry for a bytes field
-    pub fn new_bytes(field_name: String) -> FieldEntry {
+    pub fn new_bytes(field_name: String, bytes_options: BytesOptions) -> FieldEntry {
         FieldEntry {
             name: field_name,
-            field_type: FieldType::Bytes,
+            field_type: FieldType::Bytes(bytes_options),
+            unique: false,
         }
     }
 
@@ -92,6 +138,17 @@ impl FieldEntry {
         &self.field_type
     }
 
+    /// Returns true iff the field is the unique key of the documents.
+    ///
+    /// See [`SchemaBuilder::set_unique_key`](./struct.SchemaBuilder.html#method.set_unique_key).
+    pub fn is_unique(&self) -> bool {
+        self.unique
+    }
+
+    pub(crate) fn set_unique(&mut self, unique: bool) {
+        self.unique = unique;
+    }
+
     /// Returns true iff the field is indexed
     pub fn is_indexed(&self) -> bool {
         match self.field_type {
@@ -99,9 +156,27 @@ impl FieldEntry {
             FieldType::U64(ref options)
             | FieldType::I64(ref options)
             | FieldType::F64(ref options)
-            | FieldType::Date(ref options) => options.is_indexed(),
+            | FieldType::Date(ref options)
+            | FieldType::Bool(ref options) => options.is_indexed(),
+            FieldType::IpAddr(ref options) => options.is_indexed(),
+            FieldType::JsonObject(ref options) => options.get_indexing_options().is_some(),
             FieldType::HierarchicalFacet => true,
-            FieldType::Bytes => false,
+            FieldType::Bytes(_) => false,
+        }
+    }
+
+    /// Returns true iff field norms are recorded for the field.
+    pub fn has_fieldnorms(&self) -> bool {
+        match self.field_type {
+            FieldType::Str(ref options) => options
+                .get_indexing_options()
+                .map(TextFieldIndexing::fieldnorms)
+                .unwrap_or(false),
+            FieldType::JsonObject(ref options) => options
+                .get_indexing_options()
+                .map(TextFieldIndexing::fieldnorms)
+                .unwrap_or(false),
+            _ => self.is_indexed(),
         }
     }
 
@@ -121,11 +196,14 @@ impl FieldEntry {
             FieldType::U64(ref options)
             | FieldType::I64(ref options)
             | FieldType::F64(ref options)
-            | FieldType::Date(ref options) => options.is_stored(),
+            | FieldType::Date(ref options)
+            | FieldType::Bool(ref options) => options.is_stored(),
             FieldType::Str(ref options) => options.is_stored(),
             // TODO make stored hierarchical facet optional
             FieldType::HierarchicalFacet => true,
-            FieldType::Bytes => false,
+            FieldType::Bytes(ref options) => options.is_stored(),
+            FieldType::IpAddr(ref options) => options.is_stored(),
+            FieldType::JsonObject(ref options) => options.is_stored(),
         }
     }
 }
@@ -135,7 +213,8 @@ impl Serialize for FieldEntry {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("field_entry", 3)?;
+        let num_fields = if self.unique { 4 } else { 3 };
+        let mut s = serializer.serialize_struct("field_entry", num_fields)?;
         s.serialize_field("name", &self.name)?;
 
         match self.field_type {
@@ -159,13 +238,29 @@ impl Serialize for FieldEntry {
                 s.serialize_field("type", "date")?;
                 s.serialize_field("options", options)?;
             }
+            FieldType::Bool(ref options) => {
+                s.serialize_field("type", "bool")?;
+                s.serialize_field("options", options)?;
+            }
+            FieldType::IpAddr(ref options) => {
+                s.serialize_field("type", "ip")?;
+                s.serialize_field("options", options)?;
+            }
+            FieldType::JsonObject(ref options) => {
+                s.serialize_field("type", "json_object")?;
+                s.serialize_field("options", options)?;
+            }
             FieldType::HierarchicalFacet => {
                 s.serialize_field("type", "hierarchical_facet")?;
             }
-            FieldType::Bytes => {
+            FieldType::Bytes(ref options) => {
                 s.serialize_field("type", "bytes")?;
+                s.serialize_field("options", options)?;
             }
         }
+        if self.unique {
+            s.serialize_field("unique", &true)?;
+        }
 
         s.end()
     }
@@ -182,9 +277,10 @@ impl<'de> Deserialize<'de> for FieldEntry {
             Name,
             Type,
             Options,
+            Unique,
         };
 
-        const FIELDS: &[&str] = &["name", "type", "options"];
+        const FIELDS: &[&str] = &["name", "type", "options", "unique"];
 
         struct FieldEntryVisitor;
 
@@ -202,6 +298,7 @@ impl<'de> Deserialize<'de> for FieldEntry {
                 let mut name = None;
                 let mut ty = None;
                 let mut field_type = None;
+                let mut unique = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         Field::Name => {
@@ -220,15 +317,24 @@ impl<'de> Deserialize<'de> for FieldEntry {
                                     field_type = Some(FieldType::HierarchicalFacet);
                                 }
                                 "bytes" => {
-                                    field_type = Some(FieldType::Bytes);
+                                    // Bytes fields used to be fast fields without any option.
+                                    field_type =
+                                        Some(FieldType::Bytes(BytesOptions::default().set_fast()));
                                 }
-                                "text" | "u64" | "i64" | "f64" | "date" => {
+                                "text" | "u64" | "i64" | "f64" | "date" | "bool" | "ip"
+                                | "json_object" => {
                                     // These types require additional options to create a field_type
                                 }
                                 _ => panic!("unhandled type"),
                             }
                             ty = Some(type_string);
                         }
+                        Field::Unique => {
+                            if unique.is_some() {
+                                return Err(de::Error::duplicate_field("unique"));
+                            }
+                            unique = Some(map.next_value()?);
+                        }
                         Field::Options => match ty {
                             None => {
                                 let msg = "The `type` field must be \
@@ -241,6 +347,12 @@ impl<'de> Deserialize<'de> for FieldEntry {
                                 "i64" => field_type = Some(FieldType::I64(map.next_value()?)),
                                 "f64" => field_type = Some(FieldType::F64(map.next_value()?)),
                                 "date" => field_type = Some(FieldType::Date(map.next_value()?)),
+                                "bool" => field_type = Some(FieldType::Bool(map.next_value()?)),
+                                "ip" => field_type = Some(FieldType::IpAddr(map.next_value()?)),
+                                "json_object" => {
+                                    field_type = Some(FieldType::JsonObject(map.next_value()?))
+                                }
+                                "bytes" => field_type = Some(FieldType::Bytes(map.next_value()?)),
                                 _ => {
                                     let msg = format!("Unrecognised type {}", ty);
                                     return Err(de::Error::custom(msg));
@@ -254,7 +366,11 @@ impl<'de> Deserialize<'de> for FieldEntry {
                 ty.ok_or_else(|| de::Error::missing_field("ty"))?;
                 let field_type = field_type.ok_or_else(|| de::Error::missing_field("options"))?;
 
-                Ok(FieldEntry { name, field_type })
+                Ok(FieldEntry {
+                    name,
+                    field_type,
+                    unique: unique.unwrap_or(false),
+                })
             }
         }
 
@@ -265,7 +381,7 @@ impl<'de> Deserialize<'de> for FieldEntry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::TEXT;
+    use crate::schema::{FAST, INDEXED, STORED, TEXT};
     use serde_json;
 
     #[test]
@@ -296,4 +412,107 @@ mod tests {
             _ => panic!("expected FieldType::Str"),
         }
     }
+
+    #[test]
+    fn test_json_serialization_unique() {
+        let mut field_value = FieldEntry::new_u64(String::from("id"), INDEXED.into());
+        field_value.set_unique(true);
+
+        let expected = r#"{
+  "name": "id",
+  "type": "u64",
+  "options": {
+    "indexed": true,
+    "stored": false
+  },
+  "unique": true
+}"#;
+        let field_value_json = serde_json::to_string_pretty(&field_value).unwrap();
+        assert_eq!(expected, &field_value_json);
+
+        let field_value: FieldEntry = serde_json::from_str(expected).unwrap();
+        assert!(field_value.is_unique());
+    }
+
+    #[test]
+    fn test_json_serialization_bool() {
+        let field_value = FieldEntry::new_bool(String::from("is_published"), INDEXED.into());
+        let expected = r#"{
+  "name": "is_published",
+  "type": "bool",
+  "options": {
+    "indexed": true,
+    "stored": false
+  }
+}"#;
+        let field_value_json = serde_json::to_string_pretty(&field_value).unwrap();
+        assert_eq!(expected, &field_value_json);
+        let field_value_deser: FieldEntry = serde_json::from_str(expected).unwrap();
+        assert_eq!(field_value_deser, field_value);
+    }
+
+    #[test]
+    fn test_json_serialization_ip_addr() {
+        let field_value =
+            FieldEntry::new_ip_addr(String::from("client_ip"), (INDEXED | STORED).into());
+        let expected = r#"{
+  "name": "client_ip",
+  "type": "ip",
+  "options": {
+    "indexed": true,
+    "stored": true
+  }
+}"#;
+        let field_value_json = serde_json::to_string_pretty(&field_value).unwrap();
+        assert_eq!(expected, &field_value_json);
+        let field_value_deser: FieldEntry = serde_json::from_str(expected).unwrap();
+        assert_eq!(field_value_deser, field_value);
+    }
+
+    #[test]
+    fn test_json_serialization_json_object() {
+        let field_value =
+            FieldEntry::new_json_object(String::from("attributes"), (STORED | TEXT).into());
+        let expected = r#"{
+  "name": "attributes",
+  "type": "json_object",
+  "options": {
+    "stored": true,
+    "indexing": {
+      "record": "position",
+      "tokenizer": "default"
+    }
+  }
+}"#;
+        let field_value_json = serde_json::to_string_pretty(&field_value).unwrap();
+        assert_eq!(expected, &field_value_json);
+        let field_value_deser: FieldEntry = serde_json::from_str(expected).unwrap();
+        assert_eq!(field_value_deser, field_value);
+    }
+
+    #[test]
+    fn test_json_serialization_bytes() {
+        let field_value = FieldEntry::new_bytes(String::from("payload"), (STORED | FAST).into());
+        let expected = r#"{
+  "name": "payload",
+  "type": "bytes",
+  "options": {
+    "fast": true,
+    "stored": true
+  }
+}"#;
+        let field_value_json = serde_json::to_string_pretty(&field_value).unwrap();
+        assert_eq!(expected, &field_value_json);
+        let field_value_deser: FieldEntry = serde_json::from_str(expected).unwrap();
+        assert_eq!(field_value_deser, field_value);
+        assert!(field_value_deser.is_stored());
+
+        // Bytes fields serialized without options are fast, and not stored.
+        let field_value: FieldEntry =
+            serde_json::from_str(r#"{"name": "payload", "type": "bytes"}"#).unwrap();
+        assert_eq!(
+            field_value.field_type(),
+            &FieldType::Bytes(BytesOptions::default().set_fast())
+        );
+    }
 }
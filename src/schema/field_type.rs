@@ -4,10 +4,12 @@ use crate::schema::Facet;
 use crate::schema::IndexRecordOption;
 use crate::schema::TextFieldIndexing;
 use crate::schema::Value;
-use crate::schema::{IntOptions, TextOptions};
+use crate::schema::{BytesOptions, IntOptions, IpAddrOptions, JsonObjectOptions, TextOptions};
 use crate::tokenizer::PreTokenizedString;
 use chrono::{FixedOffset, Utc};
 use serde_json::Value as JsonValue;
+use std::net::IpAddr;
+use std::str::FromStr;
 
 /// Possible error that may occur while parsing a field value
 /// At this point the JSON is known to be valid.
@@ -44,6 +46,12 @@ pub enum Type {
     HierarchicalFacet,
     /// `Vec<u8>`
     Bytes,
+    /// `bool`
+    Bool,
+    /// `std::net::Ipv6Addr`. Passed as a string in JSON.
+    IpAddr,
+    /// Json object
+    JsonObject,
 }
 
 /// A `FieldType` describes the type (text, u64) of a field as well as
@@ -63,7 +71,13 @@ pub enum FieldType {
     /// Hierachical Facet
     HierarchicalFacet,
     /// Bytes (one per document)
-    Bytes,
+    Bytes(BytesOptions),
+    /// Boolean field type configuration
+    Bool(IntOptions),
+    /// Ip address field type configuration
+    IpAddr(IpAddrOptions),
+    /// Json object field type configuration
+    JsonObject(JsonObjectOptions),
 }
 
 impl FieldType {
@@ -76,7 +90,10 @@ impl FieldType {
             FieldType::F64(_) => Type::F64,
             FieldType::Date(_) => Type::Date,
             FieldType::HierarchicalFacet => Type::HierarchicalFacet,
-            FieldType::Bytes => Type::Bytes,
+            FieldType::Bytes(_) => Type::Bytes,
+            FieldType::Bool(_) => Type::Bool,
+            FieldType::IpAddr(_) => Type::IpAddr,
+            FieldType::JsonObject(_) => Type::JsonObject,
         }
     }
 
@@ -86,10 +103,15 @@ impl FieldType {
             FieldType::Str(ref text_options) => text_options.get_indexing_options().is_some(),
             FieldType::U64(ref int_options)
             | FieldType::I64(ref int_options)
-            | FieldType::F64(ref int_options) => int_options.is_indexed(),
+            | FieldType::F64(ref int_options)
+            | FieldType::Bool(ref int_options) => int_options.is_indexed(),
             FieldType::Date(ref date_options) => date_options.is_indexed(),
+            FieldType::IpAddr(ref ip_addr_options) => ip_addr_options.is_indexed(),
+            FieldType::JsonObject(ref json_object_options) => {
+                json_object_options.get_indexing_options().is_some()
+            }
             FieldType::HierarchicalFacet => true,
-            FieldType::Bytes => false,
+            FieldType::Bytes(_) => false,
         }
     }
 
@@ -102,18 +124,29 @@ impl FieldType {
             FieldType::Str(ref text_options) => text_options
                 .get_indexing_options()
                 .map(TextFieldIndexing::index_option),
+            FieldType::JsonObject(ref json_object_options) => json_object_options
+                .get_indexing_options()
+                .map(TextFieldIndexing::index_option),
             FieldType::U64(ref int_options)
             | FieldType::I64(ref int_options)
             | FieldType::F64(ref int_options)
-            | FieldType::Date(ref int_options) => {
+            | FieldType::Date(ref int_options)
+            | FieldType::Bool(ref int_options) => {
                 if int_options.is_indexed() {
                     Some(IndexRecordOption::Basic)
                 } else {
                     None
                 }
             }
+            FieldType::IpAddr(ref ip_addr_options) => {
+                if ip_addr_options.is_indexed() {
+                    Some(IndexRecordOption::Basic)
+                } else {
+                    None
+                }
+            }
             FieldType::HierarchicalFacet => Some(IndexRecordOption::Basic),
-            FieldType::Bytes => None,
+            FieldType::Bytes(_) => None,
         }
     }
 
@@ -139,8 +172,24 @@ impl FieldType {
                 FieldType::U64(_) | FieldType::I64(_) | FieldType::F64(_) => Err(
                     ValueParsingError::TypeError(format!("Expected an integer, got {:?}", json)),
                 ),
+                FieldType::Bool(_) => Err(ValueParsingError::TypeError(format!(
+                    "Expected a bool, got {:?}",
+                    json
+                ))),
+                FieldType::IpAddr(_) => {
+                    IpAddr::from_str(field_text).map(Value::from).map_err(|_| {
+                        ValueParsingError::TypeError(format!(
+                            "Expected an ip address, got {:?}",
+                            field_text
+                        ))
+                    })
+                }
                 FieldType::HierarchicalFacet => Ok(Value::Facet(Facet::from(field_text))),
-                FieldType::Bytes => decode(field_text).map(Value::Bytes).map_err(|_| {
+                FieldType::JsonObject(_) => Err(ValueParsingError::TypeError(format!(
+                    "Expected a json object, got {:?}",
+                    json
+                ))),
+                FieldType::Bytes(_) => decode(field_text).map(Value::Bytes).map_err(|_| {
                     ValueParsingError::InvalidBase64(format!(
                         "Expected base64 string, got {:?}",
                         field_text
@@ -172,12 +221,34 @@ impl FieldType {
                         Err(ValueParsingError::OverflowError(msg))
                     }
                 }
-                FieldType::Str(_) | FieldType::HierarchicalFacet | FieldType::Bytes => {
+                FieldType::Str(_)
+                | FieldType::HierarchicalFacet
+                | FieldType::Bytes(_)
+                | FieldType::IpAddr(_) => {
                     let msg = format!("Expected a string, got {:?}", json);
                     Err(ValueParsingError::TypeError(msg))
                 }
+                FieldType::Bool(_) => {
+                    let msg = format!("Expected a bool, got {:?}", json);
+                    Err(ValueParsingError::TypeError(msg))
+                }
+                FieldType::JsonObject(_) => {
+                    let msg = format!("Expected a json object, got {:?}", json);
+                    Err(ValueParsingError::TypeError(msg))
+                }
+            },
+            JsonValue::Bool(field_val_bool) => match *self {
+                FieldType::Bool(_) => Ok(Value::Bool(field_val_bool)),
+                _ => {
+                    let msg = format!(
+                        "Json value not supported error {:?}. Expected {:?}",
+                        json, self
+                    );
+                    Err(ValueParsingError::TypeError(msg))
+                }
             },
-            JsonValue::Object(_) => match *self {
+            JsonValue::Object(ref json_object) => match *self {
+                FieldType::JsonObject(_) => Ok(Value::JsonObject(json_object.clone())),
                 FieldType::Str(_) => {
                     if let Ok(tok_str_val) =
                         serde_json::from_value::<PreTokenizedString>(json.clone())
@@ -220,6 +291,7 @@ mod tests {
     use crate::tokenizer::{PreTokenizedString, Token};
     use crate::{DateTime, Document};
     use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use std::net::Ipv6Addr;
 
     #[test]
     fn test_deserialize_json_date() {
@@ -248,24 +320,89 @@ mod tests {
 
     #[test]
     fn test_bytes_value_from_json() {
-        let result = FieldType::Bytes
+        let result = FieldType::Bytes(Default::default())
             .value_from_json(&json!("dGhpcyBpcyBhIHRlc3Q="))
             .unwrap();
         assert_eq!(result, Value::Bytes("this is a test".as_bytes().to_vec()));
 
-        let result = FieldType::Bytes.value_from_json(&json!(521));
+        let result = FieldType::Bytes(Default::default()).value_from_json(&json!(521));
         match result {
             Err(ValueParsingError::TypeError(_)) => {}
             _ => panic!("Expected parse failure for wrong type"),
         }
 
-        let result = FieldType::Bytes.value_from_json(&json!("-"));
+        let result = FieldType::Bytes(Default::default()).value_from_json(&json!("-"));
         match result {
             Err(ValueParsingError::InvalidBase64(_)) => {}
             _ => panic!("Expected parse failure for invalid base64"),
         }
     }
 
+    #[test]
+    fn test_bool_value_from_json() {
+        let result = FieldType::Bool(Default::default())
+            .value_from_json(&json!(true))
+            .unwrap();
+        assert_eq!(result, Value::Bool(true));
+
+        let result = FieldType::Bool(Default::default()).value_from_json(&json!("true"));
+        match result {
+            Err(ValueParsingError::TypeError(_)) => {}
+            _ => panic!("Expected parse failure for wrong type"),
+        }
+
+        let result = FieldType::U64(Default::default()).value_from_json(&json!(false));
+        match result {
+            Err(ValueParsingError::TypeError(_)) => {}
+            _ => panic!("Expected parse failure for wrong type"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_json_bool() {
+        let mut schema_builder = Schema::builder();
+        let bool_field = schema_builder.add_bool_field("is_published", INDEXED);
+        let schema = schema_builder.build();
+        let doc = schema.parse_document(r#"{"is_published": false}"#).unwrap();
+        assert_eq!(doc.get_first(bool_field), Some(&Value::Bool(false)));
+        assert_eq!(schema.to_json(&doc), r#"{"is_published":[false]}"#);
+    }
+
+    #[test]
+    fn test_ip_addr_value_from_json() {
+        let result = FieldType::IpAddr(Default::default())
+            .value_from_json(&json!("127.0.0.1"))
+            .unwrap();
+        assert_eq!(
+            result,
+            Value::IpAddr("::ffff:127.0.0.1".parse::<Ipv6Addr>().unwrap())
+        );
+
+        let result = FieldType::IpAddr(Default::default()).value_from_json(&json!("127.0.0"));
+        match result {
+            Err(ValueParsingError::TypeError(_)) => {}
+            _ => panic!("Expected parse failure for invalid ip address"),
+        }
+    }
+
+    #[test]
+    fn test_json_object_value_from_json() {
+        let json_object_type = FieldType::JsonObject(Default::default());
+        let result = json_object_type
+            .value_from_json(&json!({"status": 200, "tags": ["a", "b"]}))
+            .unwrap();
+        assert_eq!(
+            result.as_json().unwrap(),
+            json!({"status": 200, "tags": ["a", "b"]})
+                .as_object()
+                .unwrap()
+        );
+        match json_object_type.value_from_json(&json!("text")) {
+            Err(ValueParsingError::TypeError(_)) => {}
+            _ => panic!("Expected a type error for a string"),
+        }
+    }
+
     #[test]
     fn test_pre_tok_str_value_from_json() {
         let pre_tokenized_string_json = r#"{
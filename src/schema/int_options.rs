@@ -1,4 +1,6 @@
 use crate::schema::flags::{FastFlag, IndexedFlag, SchemaFlagList, StoredFlag};
+use crate::DateTime;
+use chrono::{TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use std::ops::BitOr;
 
@@ -14,6 +16,51 @@ pub enum Cardinality {
     MultiValues,
 }
 
+/// Precision at which the values of a date field are kept.
+///
+/// Dates are truncated to the given precision before being indexed,
+/// stored and added to the fast field. A coarser precision reduces
+/// the number of distinct terms of the field.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DatePrecision {
+    /// Dates are truncated to the second.
+    #[serde(rename = "seconds")]
+    Seconds,
+    /// Dates are truncated to the minute.
+    #[serde(rename = "minutes")]
+    Minutes,
+    /// Dates are truncated to the hour.
+    #[serde(rename = "hours")]
+    Hours,
+    /// Dates are truncated to the day (UTC).
+    #[serde(rename = "days")]
+    Days,
+}
+
+impl Default for DatePrecision {
+    fn default() -> DatePrecision {
+        DatePrecision::Seconds
+    }
+}
+
+impl DatePrecision {
+    fn is_seconds(&self) -> bool {
+        *self == DatePrecision::Seconds
+    }
+
+    /// Truncates a date to the precision.
+    pub fn truncate(self, date: &DateTime) -> DateTime {
+        let num_secs: i64 = match self {
+            DatePrecision::Seconds => 1,
+            DatePrecision::Minutes => 60,
+            DatePrecision::Hours => 3_600,
+            DatePrecision::Days => 86_400,
+        };
+        let timestamp = date.timestamp();
+        Utc.timestamp(timestamp - timestamp.rem_euclid(num_secs), 0)
+    }
+}
+
 /// Define how an int field should be handled by tantivy.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IntOptions {
@@ -21,6 +68,8 @@ pub struct IntOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     fast: Option<Cardinality>,
     stored: bool,
+    #[serde(default, skip_serializing_if = "DatePrecision::is_seconds")]
+    precision: DatePrecision,
 }
 
 impl IntOptions {
@@ -75,6 +124,19 @@ impl IntOptions {
     pub fn get_fastfield_cardinality(&self) -> Option<Cardinality> {
         self.fast
     }
+
+    /// Sets the precision of the values of a date field.
+    ///
+    /// This option is ignored by the other numeric fields.
+    pub fn set_precision(mut self, precision: DatePrecision) -> IntOptions {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the precision of the values of a date field.
+    pub fn get_precision(&self) -> DatePrecision {
+        self.precision
+    }
 }
 
 impl Default for IntOptions {
@@ -83,6 +145,7 @@ impl Default for IntOptions {
             indexed: false,
             stored: false,
             fast: None,
+            precision: DatePrecision::default(),
         }
     }
 }
@@ -99,6 +162,7 @@ impl From<FastFlag> for IntOptions {
             indexed: false,
             stored: false,
             fast: Some(Cardinality::SingleValue),
+            precision: DatePrecision::default(),
         }
     }
 }
@@ -109,6 +173,7 @@ impl From<StoredFlag> for IntOptions {
             indexed: false,
             stored: true,
             fast: None,
+            precision: DatePrecision::default(),
         }
     }
 }
@@ -119,6 +184,7 @@ impl From<IndexedFlag> for IntOptions {
             indexed: true,
             stored: false,
             fast: None,
+            precision: DatePrecision::default(),
         }
     }
 }
@@ -132,6 +198,11 @@ impl<T: Into<IntOptions>> BitOr<T> for IntOptions {
         res.indexed = self.indexed | other.indexed;
         res.stored = self.stored | other.stored;
         res.fast = self.fast.or(other.fast);
+        res.precision = if self.precision.is_seconds() {
+            other.precision
+        } else {
+            self.precision
+        };
         res
     }
 }
@@ -146,3 +217,49 @@ where
         Self::from(head_tail.head) | Self::from(head_tail.tail)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{DatePrecision, IntOptions};
+    use crate::schema::{FAST, INDEXED};
+    use crate::DateTime;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_date_precision_truncate() {
+        let date = DateTime::from_str("2020-03-15T13:47:21+00:00").unwrap();
+        let truncate = |precision: DatePrecision| precision.truncate(&date).to_rfc3339();
+        assert_eq!(
+            truncate(DatePrecision::Seconds),
+            "2020-03-15T13:47:21+00:00"
+        );
+        assert_eq!(
+            truncate(DatePrecision::Minutes),
+            "2020-03-15T13:47:00+00:00"
+        );
+        assert_eq!(truncate(DatePrecision::Hours), "2020-03-15T13:00:00+00:00");
+        assert_eq!(truncate(DatePrecision::Days), "2020-03-15T00:00:00+00:00");
+        let before_epoch = DateTime::from_str("1969-12-31T23:59:59+00:00").unwrap();
+        assert_eq!(
+            DatePrecision::Days.truncate(&before_epoch).to_rfc3339(),
+            "1969-12-31T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_int_options_precision() {
+        let options = IntOptions::default().set_precision(DatePrecision::Days) | INDEXED | FAST;
+        assert_eq!(options.get_precision(), DatePrecision::Days);
+        assert!(options.is_indexed());
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            json,
+            r#"{"indexed":true,"fast":"single","stored":false,"precision":"days"}"#
+        );
+        let options_deser: IntOptions = serde_json::from_str(&json).unwrap();
+        assert_eq!(options_deser, options);
+        let options_deser: IntOptions =
+            serde_json::from_str(r#"{"indexed":true,"stored":false}"#).unwrap();
+        assert_eq!(options_deser.get_precision(), DatePrecision::Seconds);
+    }
+}
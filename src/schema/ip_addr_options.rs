@@ -0,0 +1,116 @@
+use crate::schema::flags::{IndexedFlag, SchemaFlagList, StoredFlag};
+use serde::{Deserialize, Serialize};
+use std::ops::BitOr;
+
+/// Define how an ip address field should be handled by tantivy.
+///
+/// Ip addresses are indexed as 16 bytes terms, IPv4 addresses being
+/// mapped to IPv6. The terms sort like the addresses, which makes
+/// range and CIDR queries possible.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IpAddrOptions {
+    indexed: bool,
+    stored: bool,
+}
+
+impl IpAddrOptions {
+    /// Returns true iff the value is stored.
+    pub fn is_stored(&self) -> bool {
+        self.stored
+    }
+
+    /// Returns true iff the value is indexed.
+    pub fn is_indexed(&self) -> bool {
+        self.indexed
+    }
+
+    /// Set the ip address options as stored.
+    ///
+    /// Only the fields that are set as *stored* are
+    /// persisted into the Tantivy's store.
+    pub fn set_stored(mut self) -> IpAddrOptions {
+        self.stored = true;
+        self
+    }
+
+    /// Set the ip address options as indexed.
+    ///
+    /// Setting an ip address as indexed will generate
+    /// a posting list for each value taken by the ip address.
+    pub fn set_indexed(mut self) -> IpAddrOptions {
+        self.indexed = true;
+        self
+    }
+}
+
+impl Default for IpAddrOptions {
+    fn default() -> IpAddrOptions {
+        IpAddrOptions {
+            indexed: false,
+            stored: false,
+        }
+    }
+}
+
+impl From<()> for IpAddrOptions {
+    fn from(_: ()) -> IpAddrOptions {
+        IpAddrOptions::default()
+    }
+}
+
+impl From<IndexedFlag> for IpAddrOptions {
+    fn from(_: IndexedFlag) -> Self {
+        IpAddrOptions {
+            indexed: true,
+            stored: false,
+        }
+    }
+}
+
+impl From<StoredFlag> for IpAddrOptions {
+    fn from(_: StoredFlag) -> Self {
+        IpAddrOptions {
+            indexed: false,
+            stored: true,
+        }
+    }
+}
+
+impl<T: Into<IpAddrOptions>> BitOr<T> for IpAddrOptions {
+    type Output = IpAddrOptions;
+
+    fn bitor(self, other: T) -> IpAddrOptions {
+        let other = other.into();
+        IpAddrOptions {
+            indexed: self.indexed | other.indexed,
+            stored: self.stored | other.stored,
+        }
+    }
+}
+
+impl<Head, Tail> From<SchemaFlagList<Head, Tail>> for IpAddrOptions
+where
+    Head: Clone,
+    Tail: Clone,
+    Self: BitOr<Output = Self> + From<Head> + From<Tail>,
+{
+    fn from(head_tail: SchemaFlagList<Head, Tail>) -> Self {
+        Self::from(head_tail.head) | Self::from(head_tail.tail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{IpAddrOptions, INDEXED, STORED};
+
+    #[test]
+    fn test_ip_addr_options() {
+        let options: IpAddrOptions = (INDEXED | STORED).into();
+        assert!(options.is_indexed());
+        assert!(options.is_stored());
+        let options: IpAddrOptions = STORED.into();
+        assert!(!options.is_indexed());
+        assert!(options.is_stored());
+        assert_eq!(IpAddrOptions::default().set_indexed(), INDEXED.into());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::schema::flags::{SchemaFlagList, StoredFlag};
+use crate::schema::{TextFieldIndexing, TextOptions};
+use serde::{Deserialize, Serialize};
+use std::ops::BitOr;
+
+/// Define how a json object field should be handled by tantivy.
+///
+/// The leaves of the json object are indexed as terms prefixed by
+/// their path within the object. Text leaves are tokenized using
+/// the tokenizer of the indexing options.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonObjectOptions {
+    stored: bool,
+    indexing: Option<TextFieldIndexing>,
+}
+
+impl JsonObjectOptions {
+    /// Returns true iff the json object is to be stored.
+    pub fn is_stored(&self) -> bool {
+        self.stored
+    }
+
+    /// Returns the indexing options.
+    ///
+    /// If the json object is not indexed, returns `None`.
+    pub fn get_indexing_options(&self) -> Option<&TextFieldIndexing> {
+        self.indexing.as_ref()
+    }
+
+    /// Sets the field as stored
+    pub fn set_stored(mut self) -> JsonObjectOptions {
+        self.stored = true;
+        self
+    }
+
+    /// Sets the field as indexed, with the specific indexing options.
+    pub fn set_indexing_options(mut self, indexing: TextFieldIndexing) -> JsonObjectOptions {
+        self.indexing = Some(indexing);
+        self
+    }
+}
+
+impl Default for JsonObjectOptions {
+    fn default() -> JsonObjectOptions {
+        JsonObjectOptions {
+            stored: false,
+            indexing: None,
+        }
+    }
+}
+
+impl From<()> for JsonObjectOptions {
+    fn from(_: ()) -> JsonObjectOptions {
+        JsonObjectOptions::default()
+    }
+}
+
+impl From<StoredFlag> for JsonObjectOptions {
+    fn from(_: StoredFlag) -> Self {
+        JsonObjectOptions {
+            stored: true,
+            indexing: None,
+        }
+    }
+}
+
+impl From<TextOptions> for JsonObjectOptions {
+    fn from(text_options: TextOptions) -> Self {
+        JsonObjectOptions {
+            stored: text_options.is_stored(),
+            indexing: text_options.get_indexing_options().cloned(),
+        }
+    }
+}
+
+impl<T: Into<JsonObjectOptions>> BitOr<T> for JsonObjectOptions {
+    type Output = JsonObjectOptions;
+
+    fn bitor(self, other: T) -> JsonObjectOptions {
+        let other = other.into();
+        JsonObjectOptions {
+            stored: self.stored | other.stored,
+            indexing: self.indexing.or(other.indexing),
+        }
+    }
+}
+
+impl<Head, Tail> From<SchemaFlagList<Head, Tail>> for JsonObjectOptions
+where
+    Head: Clone,
+    Tail: Clone,
+    Self: BitOr<Output = Self> + From<Head> + From<Tail>,
+{
+    fn from(head_tail: SchemaFlagList<Head, Tail>) -> Self {
+        Self::from(head_tail.head) | Self::from(head_tail.tail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{JsonObjectOptions, STORED, TEXT};
+
+    #[test]
+    fn test_json_object_options() {
+        let options: JsonObjectOptions = (STORED | TEXT).into();
+        assert!(options.is_stored());
+        assert!(options.get_indexing_options().is_some());
+        let options: JsonObjectOptions = STORED.into();
+        assert!(options.is_stored());
+        assert!(options.get_indexing_options().is_none());
+        let options: JsonObjectOptions = TEXT.into();
+        assert!(!options.is_stored());
+        assert_eq!(
+            options
+                .get_indexing_options()
+                .map(|indexing| indexing.tokenizer()),
+            Some("default")
+        );
+    }
+}
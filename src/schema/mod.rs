@@ -111,9 +111,12 @@ mod field_entry;
 mod field_type;
 mod field_value;
 
+mod bytes_options;
 mod field;
 mod index_record_option;
 mod int_options;
+mod ip_addr_options;
+mod json_object_options;
 mod named_field_document;
 mod text_options;
 mod value;
@@ -136,6 +139,7 @@ pub use self::field_entry::FieldEntry;
 pub use self::field_type::{FieldType, Type};
 pub use self::field_value::FieldValue;
 
+pub use self::bytes_options::BytesOptions;
 pub use self::index_record_option::IndexRecordOption;
 pub use self::text_options::TextFieldIndexing;
 pub use self::text_options::TextOptions;
@@ -144,7 +148,10 @@ pub use self::text_options::TEXT;
 
 pub use self::flags::{FAST, INDEXED, STORED};
 pub use self::int_options::Cardinality;
+pub use self::int_options::DatePrecision;
 pub use self::int_options::IntOptions;
+pub use self::ip_addr_options::IpAddrOptions;
+pub use self::json_object_options::JsonObjectOptions;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
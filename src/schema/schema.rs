@@ -124,6 +124,76 @@ impl SchemaBuilder {
         self.add_field(field_entry)
     }
 
+    /// Adds a new bool field.
+    /// Returns the associated field handle
+    ///
+    /// A bool field is indexed as a single term, and can be
+    /// declared as a fast field.
+    ///
+    /// # Caution
+    ///
+    /// Appending two fields with the same name
+    /// will result in the shadowing of the first
+    /// by the second one.
+    /// The first field will get a field id
+    /// but only the second one will be indexed
+    pub fn add_bool_field<T: Into<IntOptions>>(
+        &mut self,
+        field_name_str: &str,
+        field_options: T,
+    ) -> Field {
+        let field_name = String::from(field_name_str);
+        let field_entry = FieldEntry::new_bool(field_name, field_options.into());
+        self.add_field(field_entry)
+    }
+
+    /// Adds a new ip address field.
+    /// Returns the associated field handle
+    ///
+    /// Both IPv4 and IPv6 addresses are accepted. IPv4 addresses
+    /// are mapped to IPv6.
+    ///
+    /// # Caution
+    ///
+    /// Appending two fields with the same name
+    /// will result in the shadowing of the first
+    /// by the second one.
+    /// The first field will get a field id
+    /// but only the second one will be indexed
+    pub fn add_ip_addr_field<T: Into<IpAddrOptions>>(
+        &mut self,
+        field_name_str: &str,
+        field_options: T,
+    ) -> Field {
+        let field_name = String::from(field_name_str);
+        let field_entry = FieldEntry::new_ip_addr(field_name, field_options.into());
+        self.add_field(field_entry)
+    }
+
+    /// Adds a new json object field.
+    /// Returns the associated field handle
+    ///
+    /// Json object fields accept arbitrary json objects. Their leaves
+    /// are indexed as terms prefixed by their path within the object,
+    /// and can be searched using the query parser (e.g. `attributes.status:200`).
+    ///
+    /// # Caution
+    ///
+    /// Appending two fields with the same name
+    /// will result in the shadowing of the first
+    /// by the second one.
+    /// The first field will get a field id
+    /// but only the second one will be indexed
+    pub fn add_json_object_field<T: Into<JsonObjectOptions>>(
+        &mut self,
+        field_name_str: &str,
+        field_options: T,
+    ) -> Field {
+        let field_name = String::from(field_name_str);
+        let field_entry = FieldEntry::new_json_object(field_name, field_options.into());
+        self.add_field(field_entry)
+    }
+
     /// Adds a new text field.
     /// Returns the associated field handle
     ///
@@ -150,18 +220,23 @@ impl SchemaBuilder {
         self.add_field(field_entry)
     }
 
-    /// Adds a fast bytes field to the schema.
+    /// Adds a bytes field to the schema.
     ///
-    /// Bytes field are not searchable and are only used
-    /// as fast field, to associate any kind of payload
-    /// to a document.
+    /// Bytes field are not searchable. They are used to associate
+    /// any kind of payload to a document, either as a stored field,
+    /// as a fast field, or both.
     ///
     /// For instance, learning-to-rank often requires to access
     /// some document features at scoring time.
-    /// These can be serializing and stored as a bytes field to
+    /// These can be serializing and stored as a fast bytes field to
     /// get access rapidly when scoring each document.
-    pub fn add_bytes_field(&mut self, field_name: &str) -> Field {
-        let field_entry = FieldEntry::new_bytes(field_name.to_string());
+    pub fn add_bytes_field<T: Into<BytesOptions>>(
+        &mut self,
+        field_name: &str,
+        field_options: T,
+    ) -> Field {
+        let field_name = String::from(field_name);
+        let field_entry = FieldEntry::new_bytes(field_name, field_options.into());
         self.add_field(field_entry)
     }
 
@@ -174,6 +249,41 @@ impl SchemaBuilder {
         field
     }
 
+    /// Declares a field as the unique key of the documents.
+    ///
+    /// The unique key is used by `IndexWriter::update_document` to find
+    /// the previous version of a document. It must be an untokenized field,
+    /// like a `STRING` text field or an indexed integer field.
+    ///
+    /// Declaring a new unique key replaces the previous one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is not indexed, or if it is a text field
+    /// whose tokenizer is not `raw`.
+    pub fn set_unique_key(&mut self, field: Field) {
+        let field_entry = &self.fields[field.field_id() as usize];
+        assert!(
+            field_entry.is_indexed(),
+            "The unique key field `{}` must be indexed.",
+            field_entry.name()
+        );
+        if let FieldType::Str(ref text_options) = *field_entry.field_type() {
+            let tokenizer = text_options
+                .get_indexing_options()
+                .map(TextFieldIndexing::tokenizer);
+            assert_eq!(
+                tokenizer,
+                Some("raw"),
+                "The unique key field `{}` must not be tokenized.",
+                field_entry.name()
+            );
+        }
+        for (field_id, field_entry) in self.fields.iter_mut().enumerate() {
+            field_entry.set_unique(field_id == field.field_id() as usize);
+        }
+    }
+
     /// Finalize the creation of a `Schema`
     /// This will consume your `SchemaBuilder`
     pub fn build(self) -> Schema {
@@ -240,6 +350,13 @@ impl Schema {
             .map(|(field_id, field_entry)| (Field::from_field_id(field_id as u32), field_entry))
     }
 
+    /// Returns the field declared as the unique key of the documents, if any.
+    pub fn unique_key(&self) -> Option<Field> {
+        self.fields()
+            .find(|(_, field_entry)| field_entry.is_unique())
+            .map(|(field, _)| field)
+    }
+
     /// Creates a new builder.
     pub fn builder() -> SchemaBuilder {
         SchemaBuilder::default()
@@ -657,7 +774,10 @@ mod tests {
             );
             assert_matches!(
                 json_err,
-                Err(DocParsingError::ValueError(_, ValueParsingError::TypeError(_)))
+                Err(DocParsingError::ValueError(
+                    _,
+                    ValueParsingError::TypeError(_)
+                ))
             );
         }
         {
@@ -672,7 +792,10 @@ mod tests {
             );
             assert_matches!(
                 json_err,
-                Err(DocParsingError::ValueError(_, ValueParsingError::OverflowError(_)))
+                Err(DocParsingError::ValueError(
+                    _,
+                    ValueParsingError::OverflowError(_)
+                ))
             );
         }
         {
@@ -687,7 +810,10 @@ mod tests {
             );
             assert!(!matches!(
                 json_err,
-                Err(DocParsingError::ValueError(_, ValueParsingError::OverflowError(_)))
+                Err(DocParsingError::ValueError(
+                    _,
+                    ValueParsingError::OverflowError(_)
+                ))
             ));
         }
         {
@@ -702,7 +828,10 @@ mod tests {
             );
             assert_matches!(
                 json_err,
-                Err(DocParsingError::ValueError(_, ValueParsingError::OverflowError(_)))
+                Err(DocParsingError::ValueError(
+                    _,
+                    ValueParsingError::OverflowError(_)
+                ))
             );
         }
         {
@@ -806,4 +935,36 @@ mod tests {
 ]"#;
         assert_eq!(schema_json, expected);
     }
+
+    #[test]
+    pub fn test_schema_unique_key() {
+        let mut schema_builder = Schema::builder();
+        let id = schema_builder.add_text_field("id", STRING);
+        let num = schema_builder.add_u64_field("num", INDEXED);
+        schema_builder.set_unique_key(id);
+        schema_builder.set_unique_key(num);
+        let schema = schema_builder.build();
+        assert_eq!(schema.unique_key(), Some(num));
+        assert!(!schema.get_field_entry(id).is_unique());
+        let schema_json = serde_json::to_string(&schema).unwrap();
+        let schema_deser: Schema = serde_json::from_str(&schema_json).unwrap();
+        assert_eq!(schema_deser.unique_key(), Some(num));
+        assert_eq!(Schema::builder().build().unique_key(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_schema_unique_key_not_indexed() {
+        let mut schema_builder = Schema::builder();
+        let id = schema_builder.add_text_field("id", STORED);
+        schema_builder.set_unique_key(id);
+    }
+
+    #[test]
+    #[should_panic(expected = "The unique key field `id` must not be tokenized.")]
+    pub fn test_schema_unique_key_tokenized() {
+        let mut schema_builder = Schema::builder();
+        let id = schema_builder.add_text_field("id", TEXT);
+        schema_builder.set_unique_key(id);
+    }
 }
@@ -2,9 +2,11 @@ use std::fmt;
 
 use super::Field;
 use crate::common;
+use crate::schema::value::ip_addr_to_ipv6;
 use crate::schema::Facet;
 use crate::DateTime;
 use byteorder::{BigEndian, ByteOrder};
+use std::net::IpAddr;
 use std::str;
 
 /// Size (in bytes) of the buffer of a int field.
@@ -43,6 +45,27 @@ impl Term {
         Term::from_field_u64(field, val_u64)
     }
 
+    /// Builds a term given a field, and a bool value
+    ///
+    /// The term has 12 bytes: the field id followed by
+    /// the bool encoded as the u64 value `0` or `1`.
+    pub fn from_field_bool(field: Field, val: bool) -> Term {
+        Term::from_field_u64(field, u64::from(val))
+    }
+
+    /// Builds a term given a field, and an ip address
+    ///
+    /// The term has 20 bytes: the field id followed by
+    /// the 16 bytes of the address, in big endian.
+    /// IPv4 addresses are mapped to IPv6.
+    pub fn from_field_ip_addr(field: Field, ip_addr: IpAddr) -> Term {
+        let buffer = Vec::with_capacity(4 + 16);
+        let mut term = Term(buffer);
+        term.set_field(field);
+        term.set_bytes(&ip_addr_to_ipv6(ip_addr).octets());
+        term
+    }
+
     /// Builds a term given a field, and a DateTime value
     ///
     /// Assuming the term has a field id of 1, and a timestamp i64 value of 3234,
@@ -140,6 +163,16 @@ impl Term {
         term
     }
 
+    /// Truncates the term to its first `len` bytes (including the field).
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    /// Appends some bytes at the end of the term.
+    pub(crate) fn append_bytes(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
     /// Set the texts only, keeping the field untouched.
     pub fn set_text(&mut self, text: &str) {
         self.set_bytes(text.as_bytes());
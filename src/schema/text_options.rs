@@ -1,5 +1,5 @@
 use crate::schema::flags::SchemaFlagList;
-use crate::schema::flags::StoredFlag;
+use crate::schema::flags::{FastFlag, StoredFlag};
 use crate::schema::IndexRecordOption;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -10,6 +10,12 @@ use std::ops::BitOr;
 pub struct TextOptions {
     indexing: Option<TextFieldIndexing>,
     stored: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    fast: bool,
+}
+
+fn is_false(val: &bool) -> bool {
+    !*val
 }
 
 impl TextOptions {
@@ -23,12 +29,34 @@ impl TextOptions {
         self.stored
     }
 
+    /// Returns true iff the term ordinals of the text are to be
+    /// stored as a fast field.
+    ///
+    /// This requires the field to be indexed.
+    pub fn is_fast(&self) -> bool {
+        self.fast && self.indexing.is_some()
+    }
+
     /// Sets the field as stored
     pub fn set_stored(mut self) -> TextOptions {
         self.stored = true;
         self
     }
 
+    /// Sets the field as a fast field.
+    ///
+    /// The ordinals of the terms of each document within the
+    /// term dictionary of the segment are stored as a multivalued
+    /// fast field. This makes it possible to sort or group documents
+    /// by a text field without going through the postings lists.
+    ///
+    /// The fast field is ignored if the field is not indexed.
+    /// It is typically used with the `STRING` flag.
+    pub fn set_fast(mut self) -> TextOptions {
+        self.fast = true;
+        self
+    }
+
     /// Sets the field as indexed, with the specific indexing options.
     pub fn set_indexing_options(mut self, indexing: TextFieldIndexing) -> TextOptions {
         self.indexing = Some(indexing);
@@ -41,6 +69,7 @@ impl Default for TextOptions {
         TextOptions {
             indexing: None,
             stored: false,
+            fast: false,
         }
     }
 }
@@ -51,10 +80,21 @@ impl Default for TextOptions {
 /// - the amount of information that should be stored about the presence of a term in a document.
 /// Essentially, should we store the term frequency and/or the positions (See [`IndexRecordOption`](./enum.IndexRecordOption.html)).
 /// - the name of the `Tokenizer` that should be used to process the field.
+/// - whether field norms should be recorded.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct TextFieldIndexing {
     record: IndexRecordOption,
     tokenizer: Cow<'static, str>,
+    #[serde(default = "default_fieldnorms", skip_serializing_if = "is_true")]
+    fieldnorms: bool,
+}
+
+fn default_fieldnorms() -> bool {
+    true
+}
+
+fn is_true(val: &bool) -> bool {
+    *val
 }
 
 impl Default for TextFieldIndexing {
@@ -62,6 +102,7 @@ impl Default for TextFieldIndexing {
         TextFieldIndexing {
             tokenizer: Cow::Borrowed("default"),
             record: IndexRecordOption::Basic,
+            fieldnorms: true,
         }
     }
 }
@@ -92,6 +133,24 @@ impl TextFieldIndexing {
     pub fn index_option(&self) -> IndexRecordOption {
         self.record
     }
+
+    /// Sets whether field norms should be recorded for this field.
+    ///
+    /// Field norms encode the length of the field in each document and
+    /// are used to score shorter fields higher. They take one byte per
+    /// document. Without them, all documents are scored as if the field
+    /// had the same length.
+    ///
+    /// Field norms are recorded by default.
+    pub fn set_fieldnorms(mut self, fieldnorms: bool) -> TextFieldIndexing {
+        self.fieldnorms = fieldnorms;
+        self
+    }
+
+    /// Returns true iff field norms are recorded for this field.
+    pub fn fieldnorms(&self) -> bool {
+        self.fieldnorms
+    }
 }
 
 /// The field will be untokenized and indexed
@@ -99,8 +158,10 @@ pub const STRING: TextOptions = TextOptions {
     indexing: Some(TextFieldIndexing {
         tokenizer: Cow::Borrowed("raw"),
         record: IndexRecordOption::Basic,
+        fieldnorms: true,
     }),
     stored: false,
+    fast: false,
 };
 
 /// The field will be tokenized and indexed
@@ -108,8 +169,10 @@ pub const TEXT: TextOptions = TextOptions {
     indexing: Some(TextFieldIndexing {
         tokenizer: Cow::Borrowed("default"),
         record: IndexRecordOption::WithFreqsAndPositions,
+        fieldnorms: true,
     }),
     stored: false,
+    fast: false,
 };
 
 impl<T: Into<TextOptions>> BitOr<T> for TextOptions {
@@ -120,6 +183,7 @@ impl<T: Into<TextOptions>> BitOr<T> for TextOptions {
         let mut res = TextOptions::default();
         res.indexing = self.indexing.or(other.indexing);
         res.stored = self.stored | other.stored;
+        res.fast = self.fast | other.fast;
         res
     }
 }
@@ -135,6 +199,17 @@ impl From<StoredFlag> for TextOptions {
         TextOptions {
             indexing: None,
             stored: true,
+            fast: false,
+        }
+    }
+}
+
+impl From<FastFlag> for TextOptions {
+    fn from(_: FastFlag) -> TextOptions {
+        TextOptions {
+            indexing: None,
+            stored: false,
+            fast: true,
         }
     }
 }
@@ -169,6 +244,45 @@ mod tests {
                 if text_options.get_indexing_options().unwrap().tokenizer() == "default"));
     }
 
+    #[test]
+    fn test_text_options_fast() {
+        let options = STRING | FAST;
+        assert!(options.is_fast());
+        assert!(!options.is_stored());
+        assert!(options.get_indexing_options().is_some());
+        assert!(!STRING.is_fast());
+        assert!(!TextOptions::from(STORED | FAST).is_fast());
+        assert_eq!(TextOptions::default().set_fast(), FAST.into());
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            json,
+            r#"{"indexing":{"record":"basic","tokenizer":"raw"},"stored":false,"fast":true}"#
+        );
+        let options_deser: TextOptions = serde_json::from_str(&json).unwrap();
+        assert_eq!(options_deser, options);
+        let options_deser: TextOptions =
+            serde_json::from_str(r#"{"indexing":null,"stored":true}"#).unwrap();
+        assert!(!options_deser.is_fast());
+    }
+
+    #[test]
+    fn test_text_field_indexing_fieldnorms() {
+        let indexing = TextFieldIndexing::default();
+        assert!(indexing.fieldnorms());
+        let indexing = indexing.set_fieldnorms(false);
+        assert!(!indexing.fieldnorms());
+        let json = serde_json::to_string(&indexing).unwrap();
+        assert_eq!(
+            json,
+            r#"{"record":"basic","tokenizer":"default","fieldnorms":false}"#
+        );
+        let indexing_deser: TextFieldIndexing = serde_json::from_str(&json).unwrap();
+        assert_eq!(indexing_deser, indexing);
+        let indexing_deser: TextFieldIndexing =
+            serde_json::from_str(r#"{"record":"basic","tokenizer":"default"}"#).unwrap();
+        assert!(indexing_deser.fieldnorms());
+    }
+
     #[test]
     fn test_cmp_index_record_option() {
         assert!(IndexRecordOption::WithFreqsAndPositions > IndexRecordOption::WithFreqs);
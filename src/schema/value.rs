@@ -1,13 +1,16 @@
 use crate::schema::Facet;
 use crate::tokenizer::PreTokenizedString;
 use crate::DateTime;
+use serde::de::MapAccess;
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Map as JsonObject;
+use std::net::{IpAddr, Ipv6Addr};
 use std::{cmp::Ordering, fmt};
 
 /// Value represents the value of a any field.
 /// It is an enum over all over all of the possible field type.
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     /// The str type is used for any text information.
     Str(String),
@@ -25,9 +28,20 @@ pub enum Value {
     Facet(Facet),
     /// Arbitrarily sized byte array
     Bytes(Vec<u8>),
+    /// Boolean `bool`
+    Bool(bool),
+    /// Ip address. IPv4 addresses are mapped to IPv6.
+    IpAddr(Ipv6Addr),
+    /// Json object value.
+    JsonObject(JsonObject<String, serde_json::Value>),
 }
 
 impl Eq for Value {}
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 impl Ord for Value {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
@@ -38,6 +52,13 @@ impl Ord for Value {
             (Value::Date(l), Value::Date(r)) => l.cmp(r),
             (Value::Facet(l), Value::Facet(r)) => l.cmp(r),
             (Value::Bytes(l), Value::Bytes(r)) => l.cmp(r),
+            (Value::Bool(l), Value::Bool(r)) => l.cmp(r),
+            (Value::IpAddr(l), Value::IpAddr(r)) => l.cmp(r),
+            // Json objects do not have a natural order.
+            // We compare their serialized representation.
+            (Value::JsonObject(l), Value::JsonObject(r)) => serde_json::to_string(l)
+                .ok()
+                .cmp(&serde_json::to_string(r).ok()),
             (Value::F64(l), Value::F64(r)) => {
                 match (l.is_nan(), r.is_nan()) {
                     (false, false) => l.partial_cmp(r).unwrap(), // only fail on NaN
@@ -60,6 +81,12 @@ impl Ord for Value {
             (_, Value::Date(_)) => Ordering::Greater,
             (Value::Facet(_), _) => Ordering::Less,
             (_, Value::Facet(_)) => Ordering::Greater,
+            (Value::Bool(_), _) => Ordering::Less,
+            (_, Value::Bool(_)) => Ordering::Greater,
+            (Value::IpAddr(_), _) => Ordering::Less,
+            (_, Value::IpAddr(_)) => Ordering::Greater,
+            (Value::JsonObject(_), _) => Ordering::Less,
+            (_, Value::JsonObject(_)) => Ordering::Greater,
         }
     }
 }
@@ -78,6 +105,9 @@ impl Serialize for Value {
             Value::Date(ref date) => serializer.serialize_str(&date.to_rfc3339()),
             Value::Facet(ref facet) => facet.serialize(serializer),
             Value::Bytes(ref bytes) => serializer.serialize_bytes(bytes),
+            Value::Bool(b) => serializer.serialize_bool(b),
+            Value::IpAddr(ip_addr) => serializer.serialize_str(&ip_addr_to_string(ip_addr)),
+            Value::JsonObject(ref obj) => obj.serialize(serializer),
         }
     }
 }
@@ -96,6 +126,10 @@ impl<'de> Deserialize<'de> for Value {
                 formatter.write_str("a string or u32")
             }
 
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(Value::Bool(v))
+            }
+
             fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
                 Ok(Value::I64(v))
             }
@@ -115,6 +149,17 @@ impl<'de> Deserialize<'de> for Value {
             fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
                 Ok(Value::Str(v))
             }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut obj = JsonObject::new();
+                while let Some((key, val)) = map.next_entry()? {
+                    obj.insert(key, val);
+                }
+                Ok(Value::JsonObject(obj))
+            }
         }
 
         deserializer.deserialize_any(ValueVisitor)
@@ -183,6 +228,48 @@ impl Value {
             _ => panic!("This is not a date field."),
         }
     }
+
+    /// Returns the bool value, provided the value is of the `Bool` type.
+    ///
+    /// # Panics
+    /// If the value is not of type `Bool`
+    pub fn bool_value(&self) -> bool {
+        match *self {
+            Value::Bool(value) => value,
+            _ => panic!("This is not a bool field."),
+        }
+    }
+
+    /// Returns the ip address value, provided the value is of the `IpAddr` type.
+    ///
+    /// IPv4 addresses are returned as IPv4-mapped IPv6 addresses.
+    ///
+    /// # Panics
+    /// If the value is not of type `IpAddr`
+    pub fn ip_addr_value(&self) -> Ipv6Addr {
+        match *self {
+            Value::IpAddr(value) => value,
+            _ => panic!("This is not an ip address field."),
+        }
+    }
+
+    /// Returns the json object, provided the value is of the `JsonObject` type.
+    /// (Returns None if the value is not of the `JsonObject` type).
+    pub fn as_json(&self) -> Option<&JsonObject<String, serde_json::Value>> {
+        match *self {
+            Value::JsonObject(ref obj) => Some(obj),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes value, provided the value is of the `Bytes` type.
+    /// (Returns None if the value is not of the `Bytes` type).
+    pub fn bytes_value(&self) -> Option<&[u8]> {
+        match *self {
+            Value::Bytes(ref bytes) => Some(bytes),
+            _ => None,
+        }
+    }
 }
 
 impl From<String> for Value {
@@ -209,6 +296,30 @@ impl From<f64> for Value {
     }
 }
 
+impl From<bool> for Value {
+    fn from(v: bool) -> Value {
+        Value::Bool(v)
+    }
+}
+
+impl From<IpAddr> for Value {
+    fn from(ip_addr: IpAddr) -> Value {
+        Value::IpAddr(ip_addr_to_ipv6(ip_addr))
+    }
+}
+
+impl From<Ipv6Addr> for Value {
+    fn from(ip_addr: Ipv6Addr) -> Value {
+        Value::IpAddr(ip_addr)
+    }
+}
+
+impl From<JsonObject<String, serde_json::Value>> for Value {
+    fn from(obj: JsonObject<String, serde_json::Value>) -> Value {
+        Value::JsonObject(obj)
+    }
+}
+
 impl From<crate::DateTime> for Value {
     fn from(date_time: crate::DateTime) -> Value {
         Value::Date(date_time)
@@ -239,13 +350,30 @@ impl From<PreTokenizedString> for Value {
     }
 }
 
+/// Maps IPv4 addresses to IPv6, leaving IPv6 addresses untouched.
+pub(crate) fn ip_addr_to_ipv6(ip_addr: IpAddr) -> Ipv6Addr {
+    match ip_addr {
+        IpAddr::V4(ipv4_addr) => ipv4_addr.to_ipv6_mapped(),
+        IpAddr::V6(ipv6_addr) => ipv6_addr,
+    }
+}
+
+/// Formats IPv4-mapped addresses using the IPv4 notation.
+fn ip_addr_to_string(ip_addr: Ipv6Addr) -> String {
+    match ip_addr.to_ipv4_mapped() {
+        Some(ipv4_addr) => ipv4_addr.to_string(),
+        None => ip_addr.to_string(),
+    }
+}
+
 mod binary_serialize {
     use super::Value;
-    use crate::common::{f64_to_u64, u64_to_f64, BinarySerializable};
+    use crate::common::{f64_to_u64, u64_to_f64, BinarySerializable, VInt};
     use crate::schema::Facet;
     use crate::tokenizer::PreTokenizedString;
     use chrono::{TimeZone, Utc};
     use std::io::{self, Read, Write};
+    use std::net::Ipv6Addr;
 
     const TEXT_CODE: u8 = 0;
     const U64_CODE: u8 = 1;
@@ -255,6 +383,9 @@ mod binary_serialize {
     const DATE_CODE: u8 = 5;
     const F64_CODE: u8 = 6;
     const EXT_CODE: u8 = 7;
+    const BOOL_CODE: u8 = 8;
+    const IP_ADDR_CODE: u8 = 9;
+    const JSON_OBJ_CODE: u8 = 10;
 
     // extended types
 
@@ -303,6 +434,18 @@ mod binary_serialize {
                     BYTES_CODE.serialize(writer)?;
                     bytes.serialize(writer)
                 }
+                Value::Bool(val) => {
+                    BOOL_CODE.serialize(writer)?;
+                    u8::from(val).serialize(writer)
+                }
+                Value::IpAddr(ip_addr) => {
+                    IP_ADDR_CODE.serialize(writer)?;
+                    writer.write_all(&ip_addr.octets())
+                }
+                Value::JsonObject(ref obj) => {
+                    JSON_OBJ_CODE.serialize(writer)?;
+                    serde_json::to_string(obj)?.serialize(writer)
+                }
             }
         }
         fn deserialize<R: Read>(reader: &mut R) -> io::Result<Self> {
@@ -330,6 +473,17 @@ mod binary_serialize {
                 }
                 HIERARCHICAL_FACET_CODE => Ok(Value::Facet(Facet::deserialize(reader)?)),
                 BYTES_CODE => Ok(Value::Bytes(Vec::<u8>::deserialize(reader)?)),
+                BOOL_CODE => Ok(Value::Bool(u8::deserialize(reader)? != 0)),
+                IP_ADDR_CODE => {
+                    let mut octets = [0u8; 16];
+                    reader.read_exact(&mut octets)?;
+                    Ok(Value::IpAddr(Ipv6Addr::from(octets)))
+                }
+                JSON_OBJ_CODE => {
+                    let json_text = String::deserialize(reader)?;
+                    let obj = serde_json::from_str(&json_text)?;
+                    Ok(Value::JsonObject(obj))
+                }
                 EXT_CODE => {
                     let ext_type_code = u8::deserialize(reader)?;
                     match ext_type_code {
@@ -361,6 +515,49 @@ mod binary_serialize {
             }
         }
     }
+
+    impl Value {
+        /// Advances the cursor past a serialized value, without deserializing it.
+        pub(crate) fn skip_serialized(cursor: &mut &[u8]) -> io::Result<()> {
+            let mut type_code = u8::deserialize(cursor)?;
+            if type_code == EXT_CODE {
+                type_code = match u8::deserialize(cursor)? {
+                    TOK_STR_CODE => TEXT_CODE,
+                    ext_type_code => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!(
+                                "No extened field type is associated with code {:?}",
+                                ext_type_code
+                            ),
+                        ));
+                    }
+                };
+            }
+            let num_bytes = match type_code {
+                TEXT_CODE | HIERARCHICAL_FACET_CODE | BYTES_CODE | JSON_OBJ_CODE => {
+                    VInt::deserialize(cursor)?.val() as usize
+                }
+                U64_CODE | I64_CODE | F64_CODE | DATE_CODE => 8,
+                BOOL_CODE => 1,
+                IP_ADDR_CODE => 16,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("No field type is associated with code {:?}", type_code),
+                    ));
+                }
+            };
+            if num_bytes > cursor.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Serialized value is truncated",
+                ));
+            }
+            *cursor = &cursor[num_bytes..];
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -369,10 +566,57 @@ mod tests {
     use crate::DateTime;
     use std::str::FromStr;
 
+    #[test]
+    fn test_skip_serialized() {
+        use crate::common::BinarySerializable;
+        use crate::schema::Facet;
+        let values = vec![
+            Value::Str("hello".to_string()),
+            Value::U64(3u64),
+            Value::I64(-3i64),
+            Value::F64(1.5f64),
+            Value::Date(DateTime::from_str("1996-12-20T00:39:57+00:00").unwrap()),
+            Value::Facet(Facet::from("/a/b")),
+            Value::Bytes(vec![1u8, 2u8, 3u8]),
+            Value::Bool(true),
+            Value::IpAddr("::1".parse().unwrap()),
+            Value::JsonObject(serde_json::from_str(r#"{"a": {"b": [1, "c"]}}"#).unwrap()),
+        ];
+        let mut buffer = Vec::new();
+        for value in &values {
+            value.serialize(&mut buffer).unwrap();
+        }
+        buffer.push(42u8);
+        let mut cursor = &buffer[..];
+        for _ in &values {
+            Value::skip_serialized(&mut cursor).unwrap();
+        }
+        assert_eq!(cursor, &[42u8]);
+    }
+
     #[test]
     fn test_serialize_date() {
         let value = Value::Date(DateTime::from_str("1996-12-20T00:39:57+00:00").unwrap());
         let serialized_value_json = serde_json::to_string_pretty(&value).unwrap();
         assert_eq!(serialized_value_json, r#""1996-12-20T00:39:57+00:00""#);
     }
+
+    #[test]
+    fn test_serialize_ip_addr() {
+        use crate::common::BinarySerializable;
+        use std::net::IpAddr;
+        let ipv4_value = Value::from(IpAddr::from_str("192.168.0.1").unwrap());
+        assert_eq!(
+            serde_json::to_string(&ipv4_value).unwrap(),
+            r#""192.168.0.1""#
+        );
+        let ipv6_value = Value::from(IpAddr::from_str("2001:db8::1").unwrap());
+        assert_eq!(
+            serde_json::to_string(&ipv6_value).unwrap(),
+            r#""2001:db8::1""#
+        );
+        let mut buffer = Vec::new();
+        ipv4_value.serialize(&mut buffer).unwrap();
+        assert_eq!(Value::deserialize(&mut &buffer[..]).unwrap(), ipv4_value);
+    }
 }
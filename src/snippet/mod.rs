@@ -75,20 +75,37 @@ impl FragmentCandidate {
 pub struct Snippet {
     fragments: String,
     highlighted: Vec<HighlightSection>,
+    highlight_prefix: String,
+    highlight_postfix: String,
 }
 
 const HIGHLIGHTEN_PREFIX: &str = "<b>";
 const HIGHLIGHTEN_POSTFIX: &str = "</b>";
 
 impl Snippet {
-    /// Create a new, empty, `Snippet`
-    pub fn empty() -> Snippet {
+    fn new(fragments: String, highlighted: Vec<HighlightSection>) -> Snippet {
         Snippet {
-            fragments: String::new(),
-            highlighted: Vec::new(),
+            fragments,
+            highlighted,
+            highlight_prefix: HIGHLIGHTEN_PREFIX.to_string(),
+            highlight_postfix: HIGHLIGHTEN_POSTFIX.to_string(),
         }
     }
 
+    /// Create a new, empty, `Snippet`
+    pub fn empty() -> Snippet {
+        Snippet::new(String::new(), Vec::new())
+    }
+
+    /// Sets the markup surrounding the highlighted parts in `.to_html()`.
+    ///
+    /// By default, they are surrounded by `<b>` and `</b>`.
+    /// The markup is not escaped.
+    pub fn set_highlight_markup(&mut self, prefix: &str, postfix: &str) {
+        self.highlight_prefix = prefix.to_string();
+        self.highlight_postfix = postfix.to_string();
+    }
+
     /// Returns a hignlightned html from the `Snippet`.
     pub fn to_html(&self) -> String {
         let mut html = String::new();
@@ -96,9 +113,9 @@ impl Snippet {
 
         for item in self.highlighted.iter() {
             html.push_str(&encode_minimal(&self.fragments[start_from..item.start]));
-            html.push_str(HIGHLIGHTEN_PREFIX);
+            html.push_str(&self.highlight_prefix);
             html.push_str(&encode_minimal(&self.fragments[item.start..item.stop]));
-            html.push_str(HIGHLIGHTEN_POSTFIX);
+            html.push_str(&self.highlight_postfix);
             start_from = item.stop;
         }
         html.push_str(&encode_minimal(
@@ -192,17 +209,11 @@ fn select_best_fragment_combination(fragments: &[FragmentCandidate], text: &str)
                 )
             })
             .collect();
-        Snippet {
-            fragments: fragment_text.to_string(),
-            highlighted,
-        }
+        Snippet::new(fragment_text.to_string(), highlighted)
     } else {
         // when there no fragments to chose from,
         // for now create a empty snippet
-        Snippet {
-            fragments: String::new(),
-            highlighted: vec![],
-        }
+        Snippet::empty()
     }
 }
 
@@ -252,6 +263,8 @@ pub struct SnippetGenerator {
     tokenizer: TextAnalyzer,
     field: Field,
     max_num_chars: usize,
+    highlight_prefix: String,
+    highlight_postfix: String,
 }
 
 impl SnippetGenerator {
@@ -282,6 +295,8 @@ impl SnippetGenerator {
             tokenizer,
             field,
             max_num_chars: DEFAULT_MAX_NUM_CHARS,
+            highlight_prefix: HIGHLIGHTEN_PREFIX.to_string(),
+            highlight_postfix: HIGHLIGHTEN_POSTFIX.to_string(),
         })
     }
 
@@ -290,6 +305,15 @@ impl SnippetGenerator {
         self.max_num_chars = max_num_chars;
     }
 
+    /// Sets the markup surrounding the highlighted parts of the snippets
+    /// in `Snippet::to_html()`, e.g. `<em class="hit">` and `</em>`.
+    ///
+    /// By default, they are surrounded by `<b>` and `</b>`.
+    pub fn set_highlight_markup(&mut self, prefix: &str, postfix: &str) {
+        self.highlight_prefix = prefix.to_string();
+        self.highlight_postfix = postfix.to_string();
+    }
+
     #[cfg(test)]
     pub fn terms_text(&self) -> &BTreeMap<String, f32> {
         &self.terms_text
@@ -313,7 +337,9 @@ impl SnippetGenerator {
     pub fn snippet(&self, text: &str) -> Snippet {
         let fragment_candidates =
             search_fragments(&self.tokenizer, &text, &self.terms_text, self.max_num_chars);
-        select_best_fragment_combination(&fragment_candidates[..], &text)
+        let mut snippet = select_best_fragment_combination(&fragment_candidates[..], &text);
+        snippet.set_highlight_markup(&self.highlight_prefix, &self.highlight_postfix);
+        snippet
     }
 }
 
@@ -583,5 +609,12 @@ Survey in 2016, 2017, and 2018."#;
             let snippet = snippet_generator.snippet(TEST_TEXT);
             assert_eq!(snippet.to_html(), "<b>Rust</b> is syntactically similar to C++[according to whom?],\nbut its <b>designers</b> intend it to");
         }
+        {
+            snippet_generator.set_highlight_markup("<em class=\"hit\">", "</em>");
+            let mut snippet = snippet_generator.snippet(TEST_TEXT);
+            assert_eq!(snippet.to_html(), "<em class=\"hit\">Rust</em> is syntactically similar to C++[according to whom?],\nbut its <em class=\"hit\">designers</em> intend it to");
+            snippet.set_highlight_markup("[", "]");
+            assert_eq!(snippet.to_html(), "[Rust] is syntactically similar to C++[according to whom?],\nbut its [designers] intend it to");
+        }
     }
 }
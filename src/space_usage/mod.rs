@@ -364,6 +364,10 @@ mod test {
         // TODO: understand why the following fails
         //        assert_eq!(0, segment.store().total());
         assert_eq!(0, segment.deletes());
+
+        let index_space_usage = index.space_usage().unwrap();
+        assert_eq!(index_space_usage.total(), searcher_space_usage.total());
+        assert_eq!(index_space_usage.segments()[0].num_docs(), 4);
     }
 
     #[test]
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::io;
+
+/// Compressor used to compress the blocks of the doc store.
+///
+/// The compressor used for a given segment is recorded in its
+/// `SegmentMeta`, so that readers pick the right decompressor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Compressor {
+    /// No compression. Useful for payloads that are already compressed.
+    #[serde(rename = "none")]
+    None,
+    /// Snappy compression.
+    #[serde(rename = "snappy")]
+    Snappy,
+    /// LZ4 compression.
+    ///
+    /// Only available if tantivy is compiled with the `lz4-compression` feature.
+    /// Without it, the metas of an index containing LZ4 segments cannot be loaded.
+    #[cfg(feature = "lz4")]
+    #[serde(rename = "lz4")]
+    Lz4,
+    /// Zstd compression, at the given compression level.
+    ///
+    /// Levels range from 1 to 22, and 0 selects zstd's default level.
+    /// Only available if tantivy is compiled with the `zstd-compression` feature.
+    /// Without it, the metas of an index containing Zstd segments cannot be loaded.
+    #[cfg(feature = "zstd")]
+    #[serde(rename = "zstd")]
+    Zstd {
+        /// Compression level.
+        level: i32,
+    },
+}
+
+impl Default for Compressor {
+    #[cfg(feature = "lz4")]
+    fn default() -> Compressor {
+        Compressor::Lz4
+    }
+
+    #[cfg(not(feature = "lz4"))]
+    fn default() -> Compressor {
+        Compressor::Snappy
+    }
+}
+
+impl Compressor {
+    pub(crate) fn compress(self, uncompressed: &[u8], compressed: &mut Vec<u8>) -> io::Result<()> {
+        match self {
+            Compressor::None => {
+                compressed.clear();
+                compressed.extend_from_slice(uncompressed);
+                Ok(())
+            }
+            Compressor::Snappy => super::compression_snap::compress(uncompressed, compressed),
+            #[cfg(feature = "lz4")]
+            Compressor::Lz4 => super::compression_lz4::compress(uncompressed, compressed),
+            #[cfg(feature = "zstd")]
+            Compressor::Zstd { level } => {
+                super::compression_zstd::compress(uncompressed, compressed, level)
+            }
+        }
+    }
+
+    pub(crate) fn decompress(
+        self,
+        compressed: &[u8],
+        decompressed: &mut Vec<u8>,
+    ) -> io::Result<()> {
+        match self {
+            Compressor::None => {
+                decompressed.clear();
+                decompressed.extend_from_slice(compressed);
+                Ok(())
+            }
+            Compressor::Snappy => super::compression_snap::decompress(compressed, decompressed),
+            #[cfg(feature = "lz4")]
+            Compressor::Lz4 => super::compression_lz4::decompress(compressed, decompressed),
+            #[cfg(feature = "zstd")]
+            Compressor::Zstd { .. } => {
+                super::compression_zstd::decompress(compressed, decompressed)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Compressor;
+
+    fn test_compressor_aux(compressor: Compressor) {
+        let uncompressed: Vec<u8> = (0..10_000u32).map(|i| (i % 7) as u8).collect();
+        let mut compressed = Vec::new();
+        compressor.compress(&uncompressed, &mut compressed).unwrap();
+        let mut decompressed = Vec::new();
+        compressor
+            .decompress(&compressed, &mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, uncompressed);
+    }
+
+    #[test]
+    fn test_compressors() {
+        test_compressor_aux(Compressor::None);
+        test_compressor_aux(Compressor::Snappy);
+        #[cfg(feature = "lz4")]
+        test_compressor_aux(Compressor::Lz4);
+        #[cfg(feature = "zstd")]
+        test_compressor_aux(Compressor::Zstd { level: 3 });
+    }
+
+    #[test]
+    fn test_compressor_serialization() {
+        assert_eq!(
+            serde_json::to_string(&Compressor::Snappy).unwrap(),
+            "\"snappy\""
+        );
+        let compressor: Compressor = serde_json::from_str("\"none\"").unwrap();
+        assert_eq!(compressor, Compressor::None);
+        let lz4_compressor: serde_json::Result<Compressor> = serde_json::from_str("\"lz4\"");
+        assert_eq!(lz4_compressor.is_ok(), cfg!(feature = "lz4"));
+        let zstd_compressor: serde_json::Result<Compressor> =
+            serde_json::from_str(r#"{"zstd":{"level":19}}"#);
+        assert_eq!(zstd_compressor.is_ok(), cfg!(feature = "zstd"));
+        #[cfg(feature = "zstd")]
+        assert_eq!(zstd_compressor.unwrap(), Compressor::Zstd { level: 19 });
+    }
+}
@@ -5,7 +5,8 @@ A field needs to be marked as stored in the schema in
 order to be handled in the `Store`.
 
 Internally, documents (or rather their stored fields) are serialized to a buffer.
-When the buffer exceeds 16K, the buffer is compressed using `LZ4`
+When the buffer exceeds 16K, the buffer is compressed using the
+segment's [`Compressor`](./enum.Compressor.html) (snappy by default)
 and the resulting block is written to disk.
 
 One can then request for a specific `DocId`.
@@ -33,9 +34,11 @@ and should rely on either
 
 !*/
 
+mod compressors;
 mod reader;
 mod skiplist;
 mod writer;
+pub use self::compressors::Compressor;
 pub use self::reader::StoreReader;
 pub use self::writer::StoreWriter;
 
@@ -43,15 +46,12 @@ pub use self::writer::StoreWriter;
 mod compression_lz4;
 #[cfg(feature = "lz4")]
 pub use self::compression_lz4::COMPRESSION;
-#[cfg(feature = "lz4")]
-use self::compression_lz4::{compress, decompress};
 
-#[cfg(not(feature = "lz4"))]
 mod compression_snap;
+#[cfg(feature = "zstd")]
+mod compression_zstd;
 #[cfg(not(feature = "lz4"))]
 pub use self::compression_snap::COMPRESSION;
-#[cfg(not(feature = "lz4"))]
-use self::compression_snap::{compress, decompress};
 
 #[cfg(test)]
 pub mod tests {
@@ -65,6 +65,14 @@ pub mod tests {
     use std::path::Path;
 
     pub fn write_lorem_ipsum_store(writer: WritePtr, num_docs: usize) -> Schema {
+        write_lorem_ipsum_store_with_compressor(writer, num_docs, Compressor::default())
+    }
+
+    pub(crate) fn write_lorem_ipsum_store_with_compressor(
+        writer: WritePtr,
+        num_docs: usize,
+        compressor: Compressor,
+    ) -> Schema {
         let mut schema_builder = Schema::builder();
         let field_body = schema_builder.add_text_field("body", TextOptions::default().set_stored());
         let field_title =
@@ -81,7 +89,7 @@ pub mod tests {
              laborum.",
         );
         {
-            let mut store_writer = StoreWriter::new(writer);
+            let mut store_writer = StoreWriter::new(writer, compressor);
             for i in 0..num_docs {
                 let mut fields: Vec<FieldValue> = Vec::new();
                 {
@@ -102,15 +110,14 @@ pub mod tests {
         schema
     }
 
-    #[test]
-    fn test_store() {
+    fn test_store_aux(compressor: Compressor) {
         let path = Path::new("store");
         let mut directory = RAMDirectory::create();
         let store_file = directory.open_write(path).unwrap();
-        let schema = write_lorem_ipsum_store(store_file, 1_000);
+        let schema = write_lorem_ipsum_store_with_compressor(store_file, 1_000, compressor);
         let field_title = schema.get_field("title").unwrap();
         let store_source = directory.open_read(path).unwrap();
-        let store = StoreReader::from_source(store_source);
+        let store = StoreReader::from_source(store_source, compressor);
         for i in 0..1_000 {
             assert_eq!(
                 *store
@@ -124,6 +131,51 @@ pub mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_store() {
+        test_store_aux(Compressor::default());
+    }
+
+    #[test]
+    fn test_store_no_compression() {
+        test_store_aux(Compressor::None);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_store_zstd() {
+        test_store_aux(Compressor::Zstd { level: 3 });
+    }
+
+    #[test]
+    fn test_store_get_fields() {
+        let path = Path::new("store");
+        let mut directory = RAMDirectory::create();
+        let store_file = directory.open_write(path).unwrap();
+        let schema = write_lorem_ipsum_store(store_file, 100);
+        let field_title = schema.get_field("title").unwrap();
+        let field_body = schema.get_field("body").unwrap();
+        let store_source = directory.open_read(path).unwrap();
+        let store = StoreReader::from_source(store_source, Compressor::default());
+        for i in 0..100 {
+            let doc = store.get_fields(i, &[field_title]).unwrap();
+            assert_eq!(doc.field_values().len(), 1);
+            assert_eq!(
+                doc.get_first(field_title).unwrap().text(),
+                Some(format!("Doc {}", i).as_str())
+            );
+            assert!(doc.get_first(field_body).is_none());
+        }
+        assert_eq!(store.get_fields(3, &[]).unwrap().field_values().len(), 0);
+        assert_eq!(
+            store
+                .get_fields(3, &[field_body, field_title])
+                .unwrap()
+                .field_values(),
+            store.get(3).unwrap().field_values()
+        );
+    }
 }
 
 #[cfg(all(test, feature = "unstable"))]
@@ -132,7 +184,7 @@ mod bench {
     use super::tests::write_lorem_ipsum_store;
     use crate::directory::Directory;
     use crate::directory::RAMDirectory;
-    use crate::store::StoreReader;
+    use crate::store::{Compressor, StoreReader};
     use std::path::Path;
     use test::Bencher;
 
@@ -153,7 +205,7 @@ mod bench {
         let path = Path::new("store");
         write_lorem_ipsum_store(directory.open_write(path).unwrap(), 1_000);
         let store_source = directory.open_read(path).unwrap();
-        let store = StoreReader::from_source(store_source);
+        let store = StoreReader::from_source(store_source, Compressor::default());
         b.iter(|| {
             store.get(12).unwrap();
         });
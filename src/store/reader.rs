@@ -1,9 +1,9 @@
-use super::decompress;
 use super::skiplist::SkipList;
+use super::Compressor;
 use crate::common::BinarySerializable;
 use crate::common::VInt;
 use crate::directory::ReadOnlySource;
-use crate::schema::Document;
+use crate::schema::{Document, Field};
 use crate::space_usage::StoreSpaceUsage;
 use crate::DocId;
 use std::cell::RefCell;
@@ -13,6 +13,7 @@ use std::mem::size_of;
 /// Reads document off tantivy's [`Store`](./index.html)
 #[derive(Clone)]
 pub struct StoreReader {
+    compressor: Compressor,
     data: ReadOnlySource,
     offset_index_source: ReadOnlySource,
     current_block_offset: RefCell<usize>,
@@ -22,9 +23,12 @@ pub struct StoreReader {
 
 impl StoreReader {
     /// Opens a store reader
-    pub fn from_source(data: ReadOnlySource) -> StoreReader {
+    ///
+    /// `compressor` must be the compressor the store was written with.
+    pub fn from_source(data: ReadOnlySource, compressor: Compressor) -> StoreReader {
         let (data_source, offset_index_source, max_doc) = split_source(data);
         StoreReader {
+            compressor,
             data: data_source,
             offset_index_source,
             current_block_offset: RefCell::new(usize::max_value()),
@@ -44,6 +48,11 @@ impl StoreReader {
             .unwrap_or((0u32, 0u64))
     }
 
+    /// Returns the compressor used to compress the blocks of this store.
+    pub fn compressor(&self) -> Compressor {
+        self.compressor
+    }
+
     pub(crate) fn block_data(&self) -> &[u8] {
         self.data.as_slice()
     }
@@ -60,7 +69,8 @@ impl StoreReader {
             let mut current_block_mut = self.current_block.borrow_mut();
             current_block_mut.clear();
             let compressed_block = self.compressed_block(block_offset);
-            decompress(compressed_block, &mut current_block_mut)?;
+            self.compressor
+                .decompress(compressed_block, &mut current_block_mut)?;
             *self.current_block_offset.borrow_mut() = block_offset;
         }
         Ok(())
@@ -69,11 +79,32 @@ impl StoreReader {
     /// Reads a given document.
     ///
     /// Calling `.get(doc)` is relatively costly as it requires
-    /// decompressing a LZ4-compressed block.
+    /// decompressing a compressed block.
     ///
     /// It should not be called to score documents
     /// for instance.
     pub fn get(&self, doc_id: DocId) -> crate::Result<Document> {
+        self.read_doc(doc_id, |mut doc_bytes| {
+            Document::deserialize(&mut doc_bytes)
+        })
+    }
+
+    /// Reads a given document, only retaining the values of the given fields.
+    ///
+    /// The block containing the document still needs to be decompressed,
+    /// but the values of the other fields are skipped rather than
+    /// decoded and copied. This is useful when a document holds large
+    /// stored values that are not needed by the caller.
+    pub fn get_fields(&self, doc_id: DocId, fields: &[Field]) -> crate::Result<Document> {
+        self.read_doc(doc_id, |mut doc_bytes| {
+            Document::deserialize_fields(&mut doc_bytes, fields)
+        })
+    }
+
+    fn read_doc<F>(&self, doc_id: DocId, deserialize: F) -> crate::Result<Document>
+    where
+        F: FnOnce(&[u8]) -> io::Result<Document>,
+    {
         let (first_doc_id, block_offset) = self.block_offset(doc_id);
         self.read_block(block_offset as usize)?;
         let current_block_mut = self.current_block.borrow_mut();
@@ -83,8 +114,7 @@ impl StoreReader {
             cursor = &cursor[doc_length..];
         }
         let doc_length = VInt::deserialize(&mut cursor)?.val() as usize;
-        cursor = &cursor[..doc_length];
-        Ok(Document::deserialize(&mut cursor)?)
+        Ok(deserialize(&cursor[..doc_length])?)
     }
 
     /// Summarize total space usage of this store reader.
@@ -1,6 +1,5 @@
-use super::compress;
 use super::skiplist::SkipListBuilder;
-use super::StoreReader;
+use super::{Compressor, StoreReader};
 use crate::common::CountingWriter;
 use crate::common::{BinarySerializable, VInt};
 use crate::directory::TerminatingWrite;
@@ -20,6 +19,7 @@ const BLOCK_SIZE: usize = 16_384;
 /// The skip list index on the other hand, is built in memory.
 ///
 pub struct StoreWriter {
+    compressor: Compressor,
     doc: DocId,
     offset_index_writer: SkipListBuilder<u64>,
     writer: CountingWriter<WritePtr>,
@@ -31,9 +31,10 @@ impl StoreWriter {
     /// Create a store writer.
     ///
     /// The store writer will writes blocks on disc as
-    /// document are added.
-    pub fn new(writer: WritePtr) -> StoreWriter {
+    /// document are added. Blocks are compressed using the given `compressor`.
+    pub fn new(writer: WritePtr, compressor: Compressor) -> StoreWriter {
         StoreWriter {
+            compressor,
             doc: 0,
             offset_index_writer: SkipListBuilder::new(4),
             writer: CountingWriter::wrap(writer),
@@ -42,6 +43,11 @@ impl StoreWriter {
         }
     }
 
+    /// Returns the compressor used to compress the blocks of this store.
+    pub fn compressor(&self) -> Compressor {
+        self.compressor
+    }
+
     /// Store a new document.
     ///
     /// The document id is implicitely the number of times
@@ -65,6 +71,8 @@ impl StoreWriter {
     /// This method is an optimization compared to iterating over the documents
     /// in the store and adding them one by one, as the store's data will
     /// not be decompressed and then recompressed.
+    ///
+    /// The store reader is expected to use the same compressor as this writer.
     pub fn stack(&mut self, store_reader: &StoreReader) -> io::Result<()> {
         if !self.current_block.is_empty() {
             self.write_and_compress_block()?;
@@ -75,6 +83,7 @@ impl StoreWriter {
         let start_offset = self.writer.written_bytes() as u64;
 
         // just bulk write all of the block of the given reader.
+        debug_assert_eq!(store_reader.compressor(), self.compressor);
         self.writer.write_all(store_reader.block_data())?;
 
         // concatenate the index of the `store_reader`, after translating
@@ -89,7 +98,8 @@ impl StoreWriter {
 
     fn write_and_compress_block(&mut self) -> io::Result<()> {
         self.intermediary_buffer.clear();
-        compress(&self.current_block[..], &mut self.intermediary_buffer)?;
+        self.compressor
+            .compress(&self.current_block[..], &mut self.intermediary_buffer)?;
         (self.intermediary_buffer.len() as u32).serialize(&mut self.writer)?;
         self.writer.write_all(&self.intermediary_buffer)?;
         self.offset_index_writer
@@ -0,0 +1,83 @@
+/// Maps the offsets of a text modified by a `CharFilter`
+/// to the offsets of the original text.
+///
+/// The mapping is expressed as a list of correction points `(filtered_offset,
+/// original_offset)`, sorted by offset. An offset is mapped relatively to the last
+/// correction point before it, or to itself if there is no such point.
+#[derive(Clone, Debug, Default)]
+pub struct OffsetMapping {
+    corrections: Vec<(usize, usize)>,
+}
+
+impl OffsetMapping {
+    /// Records that the offset `filtered_offset` of the filtered text corresponds
+    /// to the offset `original_offset` of the original text.
+    ///
+    /// The correction points are expected to be added in increasing order.
+    pub fn add_correction(&mut self, filtered_offset: usize, original_offset: usize) {
+        if let Some(last_correction) = self.corrections.last_mut() {
+            if last_correction.0 == filtered_offset {
+                last_correction.1 = original_offset;
+                return;
+            }
+        }
+        self.corrections.push((filtered_offset, original_offset));
+    }
+
+    /// Returns the offset of the original text corresponding to an offset
+    /// of the filtered text.
+    pub fn original_offset(&self, filtered_offset: usize) -> usize {
+        let num_corrections_before = self
+            .corrections
+            .binary_search_by_key(&filtered_offset, |&(offset, _)| offset)
+            .map(|ord| ord + 1)
+            .unwrap_or_else(|ord| ord);
+        if num_corrections_before == 0 {
+            return filtered_offset;
+        }
+        let (correction_filtered_offset, correction_original_offset) =
+            self.corrections[num_corrections_before - 1];
+        correction_original_offset + filtered_offset - correction_filtered_offset
+    }
+}
+
+/// `CharFilter`s modify the text before it is tokenized,
+/// e.g. to remove markup.
+///
+/// The offsets of the tokens are mapped back to the original text, so that they
+/// can be used for highlighting.
+pub trait CharFilter: 'static + Send + Sync + CharFilterClone {
+    /// Returns the filtered text, and records in `offset_mapping` how
+    /// its offsets map to the offsets of `text`.
+    fn filter(&self, text: &str, offset_mapping: &mut OffsetMapping) -> String;
+}
+
+pub trait CharFilterClone {
+    fn box_clone(&self) -> Box<dyn CharFilter>;
+}
+
+impl<T: CharFilter + Clone> CharFilterClone for T {
+    fn box_clone(&self) -> Box<dyn CharFilter> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OffsetMapping;
+
+    #[test]
+    fn test_offset_mapping() {
+        let mut offset_mapping = OffsetMapping::default();
+        assert_eq!(offset_mapping.original_offset(3), 3);
+        // "ab<i>cd</i>e" filtered as "ab cd e"
+        offset_mapping.add_correction(3, 5);
+        offset_mapping.add_correction(6, 11);
+        assert_eq!(offset_mapping.original_offset(0), 0);
+        assert_eq!(offset_mapping.original_offset(2), 2);
+        assert_eq!(offset_mapping.original_offset(3), 5);
+        assert_eq!(offset_mapping.original_offset(5), 7);
+        assert_eq!(offset_mapping.original_offset(6), 11);
+        assert_eq!(offset_mapping.original_offset(7), 12);
+    }
+}
@@ -0,0 +1,190 @@
+use super::BoxTokenStream;
+use super::{Token, TokenStream, Tokenizer};
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// Tokenize the text by emitting overlapping bigrams of characters
+/// for the runs of Chinese, Japanese or Korean characters, and by
+/// splitting the rest of the text on whitespaces and punctuation.
+///
+/// A run made of a single CJK character is emitted as is.
+/// The tokens are not lowercased.
+///
+/// Example: `東京都 in Japan` would be tokenized as
+///
+/// | Term     | 東京 | 京都 | in  | Japan |
+/// |----------|------|------|-----|-------|
+/// | Position | 0    | 1    | 2   | 3     |
+/// | Offsets  | 0,6  | 3,9  | 10,12 | 13,18 |
+///
+/// Since the bigrams of a CJK phrase follow each other, searching
+/// for a CJK word of more than two characters requires positions to be indexed.
+#[derive(Clone)]
+pub struct CjkBigramTokenizer;
+
+/// Returns true if the char belongs to the Han, Hiragana, Katakana or Hangul scripts.
+fn is_cjk(c: char) -> bool {
+    match c as u32 {
+        // CJK unified ideographs, and their extensions A and B.
+        0x4E00..=0x9FFF | 0x3400..=0x4DBF | 0x20000..=0x2A6DF => true,
+        // CJK compatibility ideographs.
+        0xF900..=0xFAFF => true,
+        // Hiragana, Katakana, Katakana phonetic extensions and halfwidth Katakana.
+        0x3040..=0x309F | 0x30A0..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => true,
+        // Hangul syllables, Hangul jamo and Hangul compatibility jamo.
+        0xAC00..=0xD7AF | 0x1100..=0x11FF | 0x3130..=0x318F => true,
+        _ => false,
+    }
+}
+
+pub struct CjkBigramTokenStream<'a> {
+    text: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    // true if the previous char was a CJK char, that started a bigram.
+    in_cjk_run: bool,
+    token: Token,
+}
+
+impl Tokenizer for CjkBigramTokenizer {
+    fn token_stream<'a>(&self, text: &'a str) -> BoxTokenStream<'a> {
+        BoxTokenStream::from(CjkBigramTokenStream {
+            text,
+            chars: text.char_indices().peekable(),
+            in_cjk_run: false,
+            token: Token::default(),
+        })
+    }
+}
+
+impl<'a> CjkBigramTokenStream<'a> {
+    // search for the end of the current non-CJK word.
+    fn search_word_end(&mut self) -> usize {
+        while let Some(&(offset, c)) = self.chars.peek() {
+            if !c.is_alphanumeric() || is_cjk(c) {
+                return offset;
+            }
+            self.chars.next();
+        }
+        self.text.len()
+    }
+
+    fn emit(&mut self, offset_from: usize, offset_to: usize) {
+        self.token.text.clear();
+        self.token.position = self.token.position.wrapping_add(1);
+        self.token.offset_from = offset_from;
+        self.token.offset_to = offset_to;
+        self.token.text.push_str(&self.text[offset_from..offset_to]);
+    }
+}
+
+impl<'a> TokenStream for CjkBigramTokenStream<'a> {
+    fn advance(&mut self) -> bool {
+        while let Some((offset_from, c)) = self.chars.next() {
+            if is_cjk(c) {
+                match self.chars.peek() {
+                    Some(&(next_offset, next_c)) if is_cjk(next_c) => {
+                        self.in_cjk_run = true;
+                        self.emit(offset_from, next_offset + next_c.len_utf8());
+                        return true;
+                    }
+                    _ => {
+                        let is_single_char_run = !self.in_cjk_run;
+                        self.in_cjk_run = false;
+                        if is_single_char_run {
+                            self.emit(offset_from, offset_from + c.len_utf8());
+                            return true;
+                        }
+                    }
+                }
+            } else if c.is_alphanumeric() {
+                let offset_to = self.search_word_end();
+                self.emit(offset_from, offset_to);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CjkBigramTokenizer;
+    use crate::collector::Count;
+    use crate::query::QueryParser;
+    use crate::schema::{IndexRecordOption, Schema, TextFieldIndexing, TextOptions};
+    use crate::tokenizer::tests::assert_token;
+    use crate::tokenizer::{TextAnalyzer, Token};
+    use crate::Index;
+
+    fn tokens(text: &str) -> Vec<Token> {
+        let mut tokens = vec![];
+        TextAnalyzer::from(CjkBigramTokenizer)
+            .token_stream(text)
+            .process(&mut |token: &Token| tokens.push(token.clone()));
+        tokens
+    }
+
+    #[test]
+    fn test_cjk_bigram_tokenizer() {
+        let tokens = tokens("東京都 in Japan");
+        assert_eq!(tokens.len(), 4);
+        assert_token(&tokens[0], 0, "東京", 0, 6);
+        assert_token(&tokens[1], 1, "京都", 3, 9);
+        assert_token(&tokens[2], 2, "in", 10, 12);
+        assert_token(&tokens[3], 3, "Japan", 13, 18);
+    }
+
+    #[test]
+    fn test_cjk_bigram_tokenizer_mixed_scripts() {
+        let tokens = tokens("Rustは速い。한국어,中");
+        let texts: Vec<&str> = tokens.iter().map(|token| token.text.as_str()).collect();
+        assert_eq!(texts, vec!["Rust", "は速", "速い", "한국", "국어", "中"]);
+        assert_token(&tokens[1], 1, "は速", 4, 10);
+        assert_token(&tokens[5], 5, "中", 26, 29);
+    }
+
+    #[test]
+    fn test_cjk_bigram_tokenizer_single_chars() {
+        let texts: Vec<String> = tokens("a 日 b 本")
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(texts, vec!["a", "日", "b", "本"]);
+        assert!(tokens("。、 ").is_empty());
+    }
+
+    #[test]
+    fn test_cjk_search() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text_options = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("cjk")
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        );
+        let text = schema_builder.add_text_field("text", text_options);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(text => "東京都に行きます"));
+        index_writer.add_document(doc!(text => "京都 and Osaka"));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let query_parser = QueryParser::for_index(&index, vec![text]);
+        let count = |query: &str| {
+            let query = query_parser.parse_query(query).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        assert_eq!(count("京都"), 2);
+        assert_eq!(count("東京都"), 1);
+        assert_eq!(count("osaka"), 1);
+        assert_eq!(count("大阪"), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+//! # Example
+//! ```rust
+//! use tantivy::tokenizer::*;
+//!
+//! let tokenizer = TextAnalyzer::from(SimpleTokenizer)
+//!   .char_filter(HtmlStripCharFilter);
+//!
+//! let text = "<p>Fish &amp; <b>chips</b></p>";
+//! let mut stream = tokenizer.token_stream(text);
+//! assert_eq!(stream.next().unwrap().text, "Fish");
+//! let token = stream.next().unwrap();
+//! assert_eq!(token.text, "chips");
+//! assert_eq!(&text[token.offset_from..token.offset_to], "chips");
+//! assert!(stream.next().is_none());
+//! ```
+use super::{CharFilter, OffsetMapping};
+
+/// `CharFilter` removing the HTML or XML markup from the text.
+///
+/// - Tags and comments are replaced by a whitespace, so that they separate words.
+/// - The content of the `script` and `style` elements is removed.
+/// - Character references (e.g. `&amp;`, `&#233;` or `&#xE9;`) are decoded.
+///   Only the most common named references are supported, the other ones
+///   are left as is.
+#[derive(Clone)]
+pub struct HtmlStripCharFilter;
+
+/// Returns the length of the markup starting at the beginning of `text`,
+/// which is expected to start with a `<`.
+fn markup_len(text: &str) -> usize {
+    if text.starts_with("<!--") {
+        return text
+            .find("-->")
+            .map(|comment_end| comment_end + 3)
+            .unwrap_or_else(|| text.len());
+    }
+    let tag_len = text
+        .find('>')
+        .map(|tag_end| tag_end + 1)
+        .unwrap_or_else(|| text.len());
+    let tag_name: String = text[1..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if tag_name == "script" || tag_name == "style" {
+        let closing_tag = format!("</{}", tag_name);
+        let element_end = text[tag_len..]
+            .to_ascii_lowercase()
+            .find(&closing_tag)
+            .map(|closing_tag_start| {
+                let closing_tag_start = tag_len + closing_tag_start;
+                text[closing_tag_start..]
+                    .find('>')
+                    .map(|closing_tag_end| closing_tag_start + closing_tag_end + 1)
+                    .unwrap_or_else(|| text.len())
+            })
+            .unwrap_or_else(|| text.len());
+        return element_end;
+    }
+    tag_len
+}
+
+/// Decodes the character reference starting at the beginning of `text`,
+/// which is expected to start with a `&`.
+///
+/// Returns the decoded char and the length of the reference.
+fn decode_char_reference(text: &str) -> Option<(char, usize)> {
+    let reference_len = text.find(';')? + 1;
+    if reference_len > 12 {
+        return None;
+    }
+    let reference = &text[1..reference_len - 1];
+    let decoded_char = if reference.starts_with("#x") || reference.starts_with("#X") {
+        std::char::from_u32(u32::from_str_radix(&reference[2..], 16).ok()?)?
+    } else if reference.starts_with('#') {
+        std::char::from_u32(reference[1..].parse::<u32>().ok()?)?
+    } else {
+        match reference {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{a0}',
+            _ => return None,
+        }
+    };
+    Some((decoded_char, reference_len))
+}
+
+impl CharFilter for HtmlStripCharFilter {
+    fn filter(&self, text: &str, offset_mapping: &mut OffsetMapping) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut offset = 0;
+        while let Some(special_char_pos) = text[offset..].find(|c| c == '<' || c == '&') {
+            let special_char_offset = offset + special_char_pos;
+            output.push_str(&text[offset..special_char_offset]);
+            let remaining = &text[special_char_offset..];
+            if remaining.starts_with('<') {
+                offset = special_char_offset + markup_len(remaining);
+                output.push(' ');
+            } else if let Some((decoded_char, reference_len)) = decode_char_reference(remaining) {
+                offset = special_char_offset + reference_len;
+                output.push(decoded_char);
+            } else {
+                offset = special_char_offset + 1;
+                output.push('&');
+                continue;
+            }
+            offset_mapping.add_correction(output.len(), offset);
+        }
+        output.push_str(&text[offset..]);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HtmlStripCharFilter;
+    use crate::tokenizer::{
+        CharFilter, OffsetMapping, SimpleTokenizer, TextAnalyzer, Token, WhitespaceTokenizer,
+    };
+
+    fn strip(text: &str) -> String {
+        HtmlStripCharFilter.filter(text, &mut OffsetMapping::default())
+    }
+
+    #[test]
+    fn test_html_strip_char_filter() {
+        assert_eq!(strip("no markup"), "no markup");
+        assert_eq!(strip("<p>a</p><p>b</p>"), " a  b ");
+        assert_eq!(strip("a<!-- <b>comment</b> -->b"), "a b");
+        assert_eq!(
+            strip("a<script type=\"text/javascript\">if (x < 3) {}</script>b"),
+            "a b"
+        );
+        assert_eq!(strip("a<STYLE>p { }</Style>b"), "a b");
+        assert_eq!(
+            strip("caf&eacute; caf&#233; caf&#xE9;"),
+            "caf&eacute; café café"
+        );
+        assert_eq!(strip("fish &amp; chips & co &lt;3"), "fish & chips & co <3");
+        assert_eq!(strip("unclosed <b"), "unclosed  ");
+    }
+
+    #[test]
+    fn test_html_strip_char_filter_offsets() {
+        let text = "<p>Caf&#233; <i>au lait</i></p>";
+        let mut tokens: Vec<Token> = vec![];
+        TextAnalyzer::from(SimpleTokenizer)
+            .char_filter(HtmlStripCharFilter)
+            .token_stream(text)
+            .process(&mut |token: &Token| tokens.push(token.clone()));
+        let token_texts: Vec<(&str, &str, usize)> = tokens
+            .iter()
+            .map(|token| {
+                (
+                    token.text.as_str(),
+                    &text[token.offset_from..token.offset_to],
+                    token.position,
+                )
+            })
+            .collect();
+        assert_eq!(
+            token_texts,
+            vec![
+                ("Café", "Caf&#233;", 0),
+                ("au", "au", 1),
+                ("lait", "lait", 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html_strip_char_filter_multiple_values() {
+        let texts = ["<b>a</b>", "<i>b</i>"];
+        let mut tokens: Vec<Token> = vec![];
+        TextAnalyzer::from(WhitespaceTokenizer)
+            .char_filter(HtmlStripCharFilter)
+            .token_stream_texts(&texts)
+            .process(&mut |token: &Token| tokens.push(token.clone()));
+        assert_eq!(tokens.len(), 2);
+        assert_eq!((tokens[0].offset_from, tokens[0].offset_to), (3, 4));
+        assert_eq!((tokens[1].offset_from, tokens[1].offset_to), (11, 12));
+    }
+}
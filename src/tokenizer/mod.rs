@@ -40,6 +40,18 @@
 //! assign a specific tokenizer to your text field.
 //! It will chop your text on punctuation and whitespaces,
 //! removes tokens that are longer than 40 chars, and lowercase your text.
+//! Any character that is not alphanumeric, as defined by Unicode, is
+//! considered as a separator, so that non-English text is split sensibly too.
+//!
+//! ## `whitespace`
+//!
+//! Chops your text on whitespaces only. The tokens are not lowercased.
+//!
+//! ## `cjk`
+//!
+//! Emits overlapping bigrams of characters for the runs of Chinese, Japanese
+//! and Korean characters, and splits the rest of the text like `default` does.
+//! Removes tokens that are longer than 40 chars, and lowercase your text.
 //!
 //! ## `raw`
 //! Does not actual tokenizer your text. It keeps it entirely unprocessed.
@@ -119,7 +131,10 @@
 //!
 mod alphanum_only;
 mod ascii_folding_filter;
+mod char_filter;
+mod cjk_bigram_tokenizer;
 mod facet_tokenizer;
+mod html_strip_char_filter;
 mod lower_caser;
 mod ngram_tokenizer;
 mod raw_tokenizer;
@@ -127,14 +142,20 @@ mod remove_long;
 mod simple_tokenizer;
 mod stemmer;
 mod stop_word_filter;
+mod stop_words;
+mod synonym_filter;
 mod token_stream_chain;
 mod tokenized_string;
 mod tokenizer;
 mod tokenizer_manager;
+mod whitespace_tokenizer;
 
 pub use self::alphanum_only::AlphaNumOnlyFilter;
 pub use self::ascii_folding_filter::AsciiFoldingFilter;
+pub use self::char_filter::{CharFilter, OffsetMapping};
+pub use self::cjk_bigram_tokenizer::CjkBigramTokenizer;
 pub use self::facet_tokenizer::FacetTokenizer;
+pub use self::html_strip_char_filter::HtmlStripCharFilter;
 pub use self::lower_caser::LowerCaser;
 pub use self::ngram_tokenizer::NgramTokenizer;
 pub use self::raw_tokenizer::RawTokenizer;
@@ -142,6 +163,7 @@ pub use self::remove_long::RemoveLongFilter;
 pub use self::simple_tokenizer::SimpleTokenizer;
 pub use self::stemmer::{Language, Stemmer};
 pub use self::stop_word_filter::StopWordFilter;
+pub use self::synonym_filter::SynonymFilter;
 pub(crate) use self::token_stream_chain::TokenStreamChain;
 
 pub use self::tokenized_string::{PreTokenizedStream, PreTokenizedString};
@@ -150,6 +172,7 @@ pub use self::tokenizer::{
 };
 
 pub use self::tokenizer_manager::TokenizerManager;
+pub use self::whitespace_tokenizer::WhitespaceTokenizer;
 
 /// Maximum authorized len (in bytes) for a token.
 ///
@@ -162,6 +185,7 @@ pub const MAX_TOKEN_LEN: usize = u16::max_value() as usize - 4;
 pub mod tests {
     use super::{
         Language, LowerCaser, RemoveLongFilter, SimpleTokenizer, Stemmer, Token, TokenizerManager,
+        WhitespaceTokenizer,
     };
     use crate::tokenizer::TextAnalyzer;
 
@@ -252,6 +276,42 @@ pub mod tests {
         assert_token(&tokens[2], 2, "φορολογουμεν", 37, 63);
     }
 
+    #[test]
+    fn test_simple_tokenizer_unicode() {
+        let tokenizer = TextAnalyzer::from(SimpleTokenizer);
+        let mut tokens: Vec<Token> = vec![];
+        tokenizer
+            .token_stream("Grüße,ça\u{a0}va—Привет мир")
+            .process(&mut |token: &Token| tokens.push(token.clone()));
+        assert_eq!(tokens.len(), 5);
+        assert_token(&tokens[0], 0, "Grüße", 0, 7);
+        assert_token(&tokens[1], 1, "ça", 8, 11);
+        assert_token(&tokens[2], 2, "va", 13, 15);
+        assert_token(&tokens[3], 3, "Привет", 18, 30);
+        assert_token(&tokens[4], 4, "мир", 31, 37);
+    }
+
+    #[test]
+    fn test_whitespace_tokenizer() {
+        let tokenizer_manager = TokenizerManager::default();
+        let whitespace_tokenizer = tokenizer_manager.get("whitespace").unwrap();
+        let mut tokens: Vec<Token> = vec![];
+        whitespace_tokenizer
+            .token_stream(" Hello,  happy\ttax-payer!\u{3000}Ça va ")
+            .process(&mut |token: &Token| tokens.push(token.clone()));
+        assert_eq!(tokens.len(), 5);
+        assert_token(&tokens[0], 0, "Hello,", 1, 7);
+        assert_token(&tokens[1], 1, "happy", 9, 14);
+        assert_token(&tokens[2], 2, "tax-payer!", 15, 25);
+        assert_token(&tokens[3], 3, "Ça", 28, 31);
+        assert_token(&tokens[4], 4, "va", 32, 34);
+        let mut tokens: Vec<Token> = vec![];
+        TextAnalyzer::from(WhitespaceTokenizer)
+            .token_stream(" \n ")
+            .process(&mut |token: &Token| tokens.push(token.clone()));
+        assert!(tokens.is_empty());
+    }
+
     #[test]
     fn test_tokenizer_empty() {
         let tokenizer_manager = TokenizerManager::default();
@@ -3,6 +3,8 @@ use super::{Token, TokenStream, Tokenizer};
 use std::str::CharIndices;
 
 /// Tokenize the text by splitting on whitespaces and punctuation.
+///
+/// Any character that is not alphanumeric, as defined by Unicode, is a separator.
 #[derive(Clone)]
 pub struct SimpleTokenizer;
 
@@ -10,7 +10,8 @@
 //! assert_eq!(stream.next().unwrap().text, "crafty");
 //! assert!(stream.next().is_none());
 //! ```
-use super::{Token, TokenFilter, TokenStream};
+use super::stop_words;
+use super::{Language, Token, TokenFilter, TokenStream};
 use crate::tokenizer::BoxTokenStream;
 use fnv::FnvHasher;
 use std::collections::HashSet;
@@ -38,14 +39,39 @@ impl StopWordFilter {
         StopWordFilter { words: set }
     }
 
-    fn english() -> StopWordFilter {
-        let words: [&'static str; 33] = [
-            "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into",
-            "is", "it", "no", "not", "of", "on", "or", "such", "that", "the", "their", "then",
-            "there", "these", "they", "this", "to", "was", "will", "with",
-        ];
+    /// Creates a `StopWordFilter` removing the stop words of `language`,
+    /// from the lists bundled with tantivy.
+    ///
+    /// Returns `None` if no list is bundled for this language.
+    /// The words of the lists are lowercased, so that the filter is expected to
+    /// come after a `LowerCaser`.
+    pub fn new(language: Language) -> Option<StopWordFilter> {
+        let words: &[&str] = match language {
+            Language::Danish => stop_words::DANISH,
+            Language::Dutch => stop_words::DUTCH,
+            Language::English => stop_words::ENGLISH,
+            Language::French => stop_words::FRENCH,
+            Language::German => stop_words::GERMAN,
+            Language::Italian => stop_words::ITALIAN,
+            Language::Norwegian => stop_words::NORWEGIAN,
+            Language::Portuguese => stop_words::PORTUGUESE,
+            Language::Russian => stop_words::RUSSIAN,
+            Language::Spanish => stop_words::SPANISH,
+            Language::Swedish => stop_words::SWEDISH,
+            _ => return None,
+        };
+        Some(StopWordFilter::remove(
+            words.iter().map(|&word| word.to_string()).collect(),
+        ))
+    }
 
-        StopWordFilter::remove(words.iter().map(|&s| s.to_string()).collect())
+    fn english() -> StopWordFilter {
+        StopWordFilter::remove(
+            stop_words::ENGLISH
+                .iter()
+                .map(|&word| word.to_string())
+                .collect(),
+        )
     }
 }
 
@@ -93,3 +119,45 @@ impl Default for StopWordFilter {
         StopWordFilter::english()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tokenizer::{Language, LowerCaser, SimpleTokenizer, StopWordFilter, TextAnalyzer};
+
+    fn remove_stop_words(language: Language, text: &str) -> Vec<String> {
+        let mut token_stream = TextAnalyzer::from(SimpleTokenizer)
+            .filter(LowerCaser)
+            .filter(StopWordFilter::new(language).unwrap())
+            .token_stream(text);
+        let mut tokens = vec![];
+        while token_stream.advance() {
+            tokens.push(token_stream.token().text.clone());
+        }
+        tokens
+    }
+
+    #[test]
+    fn test_stop_word_filter_languages() {
+        assert_eq!(
+            remove_stop_words(Language::English, "The fox is in the box"),
+            vec!["fox", "box"]
+        );
+        assert_eq!(
+            remove_stop_words(Language::French, "Le chat est sur la table"),
+            vec!["chat", "table"]
+        );
+        assert_eq!(
+            remove_stop_words(Language::German, "Der Hund und die Katze"),
+            vec!["hund", "katze"]
+        );
+        assert_eq!(
+            remove_stop_words(Language::Spanish, "El perro y el gato"),
+            vec!["perro", "gato"]
+        );
+        assert_eq!(
+            remove_stop_words(Language::Russian, "Кошка и собака"),
+            vec!["кошка", "собака"]
+        );
+        assert!(StopWordFilter::new(Language::Tamil).is_none());
+    }
+}
@@ -0,0 +1,253 @@
+//! # Example
+//! ```rust
+//! use tantivy::tokenizer::*;
+//!
+//! let tokenizer = TextAnalyzer::from(SimpleTokenizer)
+//!   .filter(LowerCaser)
+//!   .filter(SynonymFilter::default().add_synonyms("quick", &["fast"]));
+//!
+//! let mut stream = tokenizer.token_stream("Quick fox");
+//! let token = stream.next().unwrap();
+//! assert_eq!((token.text.as_str(), token.position), ("quick", 0));
+//! let token = stream.next().unwrap();
+//! assert_eq!((token.text.as_str(), token.position), ("fast", 0));
+//! let token = stream.next().unwrap();
+//! assert_eq!((token.text.as_str(), token.position), ("fox", 1));
+//! assert!(stream.next().is_none());
+//! ```
+use super::{Token, TokenFilter, TokenStream};
+use crate::tokenizer::BoxTokenStream;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+/// `SynonymFilter` emits the synonyms of the tokens found in its dictionary,
+/// at the position of the original token.
+///
+/// A synonym may be made of several words, separated by whitespaces. Its words are then
+/// emitted at consecutive positions, and the positions of the tokens that follow are
+/// shifted accordingly, so that phrase queries work with either form. The original token
+/// gets a `position_length` spanning the words of its longest synonym.
+///
+/// By default, the original token is kept. With `.replacing()`, only its synonyms
+/// are emitted: mapping all of the synonyms of a word to a single canonical form then
+/// normalizes the text.
+///
+/// The same filter is expected to be applied at indexing and at query time,
+/// which is the case when it is part of the tokenizer of the field.
+#[derive(Clone, Default)]
+pub struct SynonymFilter {
+    synonyms: Arc<HashMap<String, Vec<String>>>,
+    replace_original: bool,
+}
+
+impl SynonymFilter {
+    /// Adds `synonyms` to the synonyms of `word`.
+    ///
+    /// The lookup is exact, so that the words are expected to be normalized as
+    /// the tokens reaching the filter (e.g. lowercased).
+    pub fn add_synonyms(mut self, word: &str, synonyms: &[&str]) -> SynonymFilter {
+        let word_synonyms = Arc::make_mut(&mut self.synonyms)
+            .entry(word.to_string())
+            .or_insert_with(Vec::new);
+        for &synonym in synonyms {
+            if synonym != word && !word_synonyms.iter().any(|existing| existing == synonym) {
+                word_synonyms.push(synonym.to_string());
+            }
+        }
+        self
+    }
+
+    /// Replaces the tokens having synonyms by their synonyms,
+    /// instead of emitting the synonyms next to them.
+    pub fn replacing(self) -> SynonymFilter {
+        SynonymFilter {
+            replace_original: true,
+            ..self
+        }
+    }
+}
+
+impl TokenFilter for SynonymFilter {
+    fn transform<'a>(&self, token_stream: BoxTokenStream<'a>) -> BoxTokenStream<'a> {
+        BoxTokenStream::from(SynonymFilterStream {
+            synonyms: self.synonyms.clone(),
+            replace_original: self.replace_original,
+            tail: token_stream,
+            pending_tokens: VecDeque::new(),
+            position_shift: 0,
+            token: Token::default(),
+        })
+    }
+}
+
+pub struct SynonymFilterStream<'a> {
+    synonyms: Arc<HashMap<String, Vec<String>>>,
+    replace_original: bool,
+    tail: BoxTokenStream<'a>,
+    pending_tokens: VecDeque<Token>,
+    // Number of positions added so far by multi-word synonyms.
+    position_shift: usize,
+    token: Token,
+}
+
+impl<'a> SynonymFilterStream<'a> {
+    fn push_synonyms(&mut self, mut token: Token, synonyms: &[String]) {
+        let mut max_num_words = 1;
+        let mut synonym_tokens = Vec::new();
+        for synonym in synonyms {
+            let mut num_words = 0;
+            for (word_ord, word) in synonym.split_whitespace().enumerate() {
+                synonym_tokens.push(Token {
+                    offset_from: token.offset_from,
+                    offset_to: token.offset_to,
+                    position: token.position + word_ord,
+                    text: word.to_string(),
+                    position_length: 1,
+                });
+                num_words += 1;
+            }
+            max_num_words = max_num_words.max(num_words);
+        }
+        if !self.replace_original {
+            token.position_length = max_num_words;
+            self.pending_tokens.push_back(token);
+        }
+        self.pending_tokens.extend(synonym_tokens);
+        self.position_shift += max_num_words - 1;
+    }
+}
+
+impl<'a> TokenStream for SynonymFilterStream<'a> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.pending_tokens.pop_front() {
+                self.token = token;
+                return true;
+            }
+            if !self.tail.advance() {
+                return false;
+            }
+            let mut token = self.tail.token().clone();
+            token.position += self.position_shift;
+            let synonyms = self.synonyms.clone();
+            match synonyms.get(&token.text) {
+                Some(word_synonyms) if !word_synonyms.is_empty() => {
+                    self.push_synonyms(token, word_synonyms);
+                }
+                _ => {
+                    self.token = token;
+                    return true;
+                }
+            }
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SynonymFilter;
+    use crate::collector::Count;
+    use crate::query::QueryParser;
+    use crate::schema::{IndexRecordOption, Schema, TextFieldIndexing, TextOptions};
+    use crate::tokenizer::{LowerCaser, SimpleTokenizer, TextAnalyzer, Token};
+    use crate::Index;
+
+    fn tokens(filter: SynonymFilter, text: &str) -> Vec<(String, usize, usize)> {
+        let mut tokens = vec![];
+        TextAnalyzer::from(SimpleTokenizer)
+            .filter(LowerCaser)
+            .filter(filter)
+            .token_stream(text)
+            .process(&mut |token: &Token| {
+                tokens.push((token.text.clone(), token.position, token.position_length))
+            });
+        tokens
+    }
+
+    fn token(text: &str, position: usize, position_length: usize) -> (String, usize, usize) {
+        (text.to_string(), position, position_length)
+    }
+
+    #[test]
+    fn test_synonym_filter_expand() {
+        let filter = SynonymFilter::default()
+            .add_synonyms("ny", &["new york", "nyc"])
+            .add_synonyms("big", &["large", "big"]);
+        assert_eq!(
+            tokens(filter, "Big NY city"),
+            vec![
+                token("big", 0, 1),
+                token("large", 0, 1),
+                token("ny", 1, 2),
+                token("new", 1, 1),
+                token("york", 2, 1),
+                token("nyc", 1, 1),
+                token("city", 3, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_synonym_filter_replace() {
+        let filter = SynonymFilter::default()
+            .add_synonyms("fast", &["quick"])
+            .add_synonyms("nyc", &["new york"])
+            .replacing();
+        assert_eq!(
+            tokens(filter, "fast trains to NYC today"),
+            vec![
+                token("quick", 0, 1),
+                token("trains", 1, 1),
+                token("to", 2, 1),
+                token("new", 3, 1),
+                token("york", 4, 1),
+                token("today", 5, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_synonym_filter_search() -> crate::Result<()> {
+        let mut schema_builder = Schema::builder();
+        let text_options = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("synonyms")
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        );
+        let text = schema_builder.add_text_field("text", text_options);
+        let index = Index::create_in_ram(schema_builder.build());
+        index.tokenizers().register(
+            "synonyms",
+            TextAnalyzer::from(SimpleTokenizer)
+                .filter(LowerCaser)
+                .filter(SynonymFilter::default().add_synonyms("ny", &["new york"])),
+        );
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000)?;
+        index_writer.add_document(doc!(text => "flights to NY tomorrow"));
+        index_writer.add_document(doc!(text => "flights to New York tomorrow"));
+        index_writer.add_document(doc!(text => "a new shop in York"));
+        index_writer.commit()?;
+        let searcher = index.reader()?.searcher();
+        let query_parser = QueryParser::for_index(&index, vec![text]);
+        let count = |query: &str| {
+            let query = query_parser.parse_query(query).unwrap();
+            searcher.search(&query, &Count).unwrap()
+        };
+        // "ny" is indexed as "new york" too.
+        assert_eq!(count("\"new york\""), 2);
+        assert_eq!(count("\"to new york tomorrow\""), 2);
+        assert_eq!(count("\"new shop\""), 1);
+        assert_eq!(count("york"), 3);
+        // Synonyms only go one way: "new york" is not indexed as "ny".
+        assert_eq!(count("\"ny tomorrow\""), 1);
+        Ok(())
+    }
+}
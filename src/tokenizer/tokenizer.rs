@@ -1,4 +1,5 @@
 use crate::tokenizer::TokenStreamChain;
+use crate::tokenizer::{CharFilter, OffsetMapping, PreTokenizedStream, PreTokenizedString};
 use serde::{Deserialize, Serialize};
 /// The tokenizer module contains all of the tools used to process
 /// text in `tantivy`.
@@ -38,7 +39,9 @@ impl Default for Token {
 /// `TextAnalyzer` tokenizes an input text into tokens and modifies the resulting `TokenStream`.
 ///
 /// It simply wraps a `Tokenizer` and a list of `TokenFilter` that are applied sequentially.
+/// `CharFilter`s can also be applied to the text before it is tokenized.
 pub struct TextAnalyzer {
+    char_filters: Vec<Box<dyn CharFilter>>,
     tokenizer: Box<dyn Tokenizer>,
     token_filters: Vec<BoxTokenFilter>,
 }
@@ -56,6 +59,7 @@ impl TextAnalyzer {
     /// `TextAnalyzer::from(tokenizer)`.
     pub fn new<T: Tokenizer>(tokenizer: T, token_filters: Vec<BoxTokenFilter>) -> TextAnalyzer {
         TextAnalyzer {
+            char_filters: Vec::new(),
             tokenizer: Box::new(tokenizer),
             token_filters,
         }
@@ -82,6 +86,25 @@ impl TextAnalyzer {
         self
     }
 
+    /// Appends a char filter, applied to the text before it is tokenized.
+    ///
+    /// The offsets of the tokens are those of the original text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tantivy::tokenizer::*;
+    ///
+    /// let html_tokenizer = TextAnalyzer::from(SimpleTokenizer)
+    ///     .char_filter(HtmlStripCharFilter)
+    ///     .filter(LowerCaser);
+    /// ```
+    ///
+    pub fn char_filter<F: CharFilter>(mut self, char_filter: F) -> Self {
+        self.char_filters.push(Box::new(char_filter));
+        self
+    }
+
     /// Tokenize an array`&str`
     ///
     /// The resulting `BoxTokenStream` is equivalent to what would be obtained if the &str were
@@ -109,17 +132,60 @@ impl TextAnalyzer {
 
     /// Creates a token stream for a given `str`.
     pub fn token_stream<'a>(&self, text: &'a str) -> BoxTokenStream<'a> {
+        if !self.char_filters.is_empty() {
+            return self.token_stream_with_char_filters(text);
+        }
+        self.token_stream_without_char_filters(text)
+    }
+
+    fn token_stream_without_char_filters<'a>(&self, text: &'a str) -> BoxTokenStream<'a> {
         let mut token_stream = self.tokenizer.token_stream(text);
         for token_filter in &self.token_filters {
             token_stream = token_filter.transform(token_stream);
         }
         token_stream
     }
+
+    // The filtered text is owned by the analyzer, so that the tokens are
+    // collected upfront, and their offsets mapped back to the original text.
+    fn token_stream_with_char_filters<'a>(&self, text: &'a str) -> BoxTokenStream<'a> {
+        let mut filtered_text = text.to_string();
+        let mut offset_mappings = Vec::with_capacity(self.char_filters.len());
+        for char_filter in &self.char_filters {
+            let mut offset_mapping = OffsetMapping::default();
+            filtered_text = char_filter.filter(&filtered_text, &mut offset_mapping);
+            offset_mappings.push(offset_mapping);
+        }
+        let original_offset = |mut offset: usize| {
+            for offset_mapping in offset_mappings.iter().rev() {
+                offset = offset_mapping.original_offset(offset);
+            }
+            offset
+        };
+        let mut tokens = Vec::new();
+        self.token_stream_without_char_filters(&filtered_text)
+            .process(&mut |token: &Token| {
+                let mut token = token.clone();
+                token.offset_from = original_offset(token.offset_from);
+                token.offset_to = original_offset(token.offset_to);
+                tokens.push(token);
+            });
+        PreTokenizedStream::from(PreTokenizedString {
+            text: filtered_text,
+            tokens,
+        })
+        .into()
+    }
 }
 
 impl Clone for TextAnalyzer {
     fn clone(&self) -> Self {
         TextAnalyzer {
+            char_filters: self
+                .char_filters
+                .iter()
+                .map(|char_filter| char_filter.box_clone())
+                .collect(),
             tokenizer: self.tokenizer.box_clone(),
             token_filters: self
                 .token_filters
@@ -1,10 +1,12 @@
 use crate::tokenizer::stemmer::Language;
 use crate::tokenizer::tokenizer::TextAnalyzer;
+use crate::tokenizer::CjkBigramTokenizer;
 use crate::tokenizer::LowerCaser;
 use crate::tokenizer::RawTokenizer;
 use crate::tokenizer::RemoveLongFilter;
 use crate::tokenizer::SimpleTokenizer;
 use crate::tokenizer::Stemmer;
+use crate::tokenizer::WhitespaceTokenizer;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -51,20 +53,29 @@ impl TokenizerManager {
 impl Default for TokenizerManager {
     /// Creates an `TokenizerManager` prepopulated with
     /// the default pre-configured tokenizers of `tantivy`.
-    /// - simple
+    /// - raw
+    /// - whitespace
+    /// - default
+    /// - cjk
     /// - en_stem
-    /// - ja
     fn default() -> TokenizerManager {
         let manager = TokenizerManager {
             tokenizers: Arc::new(RwLock::new(HashMap::new())),
         };
         manager.register("raw", RawTokenizer);
+        manager.register("whitespace", WhitespaceTokenizer);
         manager.register(
             "default",
             TextAnalyzer::from(SimpleTokenizer)
                 .filter(RemoveLongFilter::limit(40))
                 .filter(LowerCaser),
         );
+        manager.register(
+            "cjk",
+            TextAnalyzer::from(CjkBigramTokenizer)
+                .filter(RemoveLongFilter::limit(40))
+                .filter(LowerCaser),
+        );
         manager.register(
             "en_stem",
             TextAnalyzer::from(SimpleTokenizer)
@@ -1,9 +1,13 @@
 use fail;
-use std::path::Path;
+use futures::executor::block_on;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
 use tantivy::directory::{Directory, ManagedDirectory, RAMDirectory, TerminatingWrite};
 use tantivy::doc;
 use tantivy::schema::{Schema, TEXT};
 use tantivy::{Index, Term};
+use tempfile::TempDir;
 
 #[test]
 fn test_failpoints_managed_directory_gc_if_delete_fails() {
@@ -64,3 +68,82 @@ fn test_write_commit_fails() {
     assert_eq!(num_docs_containing("a"), 100);
     assert_eq!(num_docs_containing("b"), 0);
 }
+
+/// Simulates a crash at the given failpoint while committing a second batch
+/// of documents to an index stored on disk, and checks that reopening the
+/// index afterwards yields a consistent index.
+///
+/// Returns the number of documents of the second batch that survived the crash.
+fn crash_during_commit(failpoint: &str) -> u64 {
+    let _fail_scenario_guard = fail::FailScenario::setup();
+    let tempdir = TempDir::new().unwrap();
+    let mut schema_builder = Schema::builder();
+    let text_field = schema_builder.add_text_field("text", TEXT);
+    let schema = schema_builder.build();
+    {
+        let index = Index::create_in_dir(tempdir.path(), schema).unwrap();
+        let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+        for _ in 0..100 {
+            index_writer.add_document(doc!(text_field => "a"));
+        }
+        index_writer.commit().unwrap();
+        fail::cfg(failpoint, "return(crash)").unwrap();
+        for _ in 0..100 {
+            index_writer.add_document(doc!(text_field => "b"));
+        }
+        assert!(index_writer.commit().is_err());
+    }
+    fail::remove(failpoint);
+
+    let index = Index::open_in_dir(tempdir.path()).unwrap();
+    let num_docs_containing = |s: &str| {
+        let term = Term::from_field_text(text_field, s);
+        index.reader().unwrap().searcher().doc_freq(&term)
+    };
+    assert_eq!(num_docs_containing("a"), 100);
+    let num_docs_after_crash = num_docs_containing("b");
+
+    // The index remains writable, and the files left over by the
+    // interrupted commit are eventually garbage collected.
+    let mut index_writer = index.writer_with_num_threads(1, 3_000_000).unwrap();
+    for _ in 0..100 {
+        index_writer.add_document(doc!(text_field => "c"));
+    }
+    index_writer.commit().unwrap();
+    block_on(index_writer.garbage_collect_files()).unwrap();
+    assert_eq!(num_docs_containing("a"), 100);
+    assert_eq!(num_docs_containing("b"), num_docs_after_crash);
+    assert_eq!(num_docs_containing("c"), 100);
+    let living_files = index.searchable_segment_metas().unwrap().iter().fold(
+        HashSet::new(),
+        |mut files, segment_meta| {
+            files.extend(segment_meta.list_files());
+            files
+        },
+    );
+    for entry in fs::read_dir(tempdir.path()).unwrap() {
+        let path = PathBuf::from(entry.unwrap().file_name());
+        if path.extension().map(|ext| ext != "json" && ext != "lock") == Some(true) {
+            assert!(living_files.contains(&path), "{:?} was not removed", path);
+        }
+    }
+    num_docs_after_crash
+}
+
+#[test]
+fn test_crash_while_serializing_segment() {
+    assert_eq!(crash_during_commit("SegmentSerializer::close"), 0);
+}
+
+#[test]
+fn test_crash_before_saving_metas() {
+    assert_eq!(crash_during_commit("save_metas"), 0);
+}
+
+#[test]
+fn test_crash_after_saving_metas() {
+    assert_eq!(
+        crash_during_commit("SegmentUpdater::commit#after_save_metas"),
+        100
+    );
+}